@@ -63,6 +63,7 @@ fn default_config() -> cbindgen::Config {
                 ("VoidArg".into(), "void".into()),
                 ("KeyEventArg".into(), "KeyEvent".into()),
                 ("PointerEventArg".into(), "PointerEvent".into()),
+                ("DropEventArg".into(), "DropEvent".into()),
                 ("PointArg".into(), "Point".into()),
                 ("FloatArg".into(), "float".into()),
                 ("Coord".into(), "float".into()),
@@ -121,7 +122,12 @@ fn gen_corelib(
         "ClippedImage",
         "TouchArea",
         "FocusScope",
+        "Shortcut",
         "Flickable",
+        "DragArea",
+        "DropArea",
+        "PinchGestureHandler",
+        "SwipeGestureHandler",
         "Text",
         "Path",
         "WindowItem",
@@ -152,6 +158,7 @@ fn gen_corelib(
         "PointerEventKind",
         "PointerEventButton",
         "PointerEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
     ]
@@ -192,11 +199,13 @@ fn gen_corelib(
         "slint_property_listener_scope_evaluate",
         "slint_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "WindowAdapterRc",
         "VoidArg",
         "KeyEventArg",
         "PointerEventArg",
+        "DropEventArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -231,6 +240,7 @@ fn gen_corelib(
         .with_config(string_config)
         .with_src(crate_dir.join("string.rs"))
         .with_src(crate_dir.join("slice.rs"))
+        .with_src(crate_dir.join("translations.rs"))
         .with_after_include("namespace slint { struct SharedString; }")
         .generate()
         .context("Unable to generate bindings for slint_string_internal.h")?
@@ -319,6 +329,8 @@ fn gen_corelib(
             "slint_windowrc_set_focus_item",
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
+            "slint_windowrc_on_popup_close_requested",
+            "slint_windowrc_close_popup",
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
@@ -329,8 +341,19 @@ fn gen_corelib(
             "slint_windowrc_set_logical_size",
             "slint_windowrc_set_physical_size",
             "slint_windowrc_dark_color_scheme",
+            "slint_windowrc_is_fullscreen",
+            "slint_windowrc_set_fullscreen",
+            "slint_windowrc_is_maximized",
+            "slint_windowrc_set_maximized",
+            "slint_windowrc_is_minimized",
+            "slint_windowrc_set_minimized",
+            "slint_windowrc_is_resizable",
+            "slint_windowrc_set_resizable",
+            "slint_windowrc_is_always_on_top",
+            "slint_windowrc_set_always_on_top",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
+            "slint_windowrc_dispatch_touch_event",
             "slint_new_path_elements",
             "slint_new_path_events",
             "slint_color_brighter",
@@ -455,6 +478,15 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config.export.body.insert(
+        "PinchGestureHandler".to_owned(),
+        "    inline PinchGestureHandler(); inline ~PinchGestureHandler();".into(),
+    );
+    config.export.body.insert(
+        "SwipeGestureHandler".to_owned(),
+        "    inline SwipeGestureHandler(); inline ~SwipeGestureHandler();".into(),
+    );
+    config.export.pre_body.insert("GestureDataBox".to_owned(), "struct GestureData;".into());
     config.export.include.push("TableColumn".into());
     cbindgen::Builder::new()
         .with_config(config)
@@ -479,7 +511,7 @@ fn gen_corelib(
     namespace cbindgen_private {
         using slint::private_api::WindowAdapterRc;
         using namespace vtable;
-        struct KeyEvent; struct PointerEvent;
+        struct KeyEvent; struct PointerEvent; struct DropEvent;
         using private_api::Property;
         using private_api::PathData;
         using private_api::Point;
@@ -17,6 +17,7 @@ function(SLINT_TARGET_SOURCES target)
                 COMMAND Slint::slint-compiler ${_SLINT_ABSOLUTE}
                     -o ${_SLINT_BASE_NAME_REL}.h  --depfile ${_SLINT_BASE_NAME_REL}.d
                     --style ${_SLINT_STYLE}
+                    --translation-domain ${target}
                 DEPENDS Slint::slint-compiler ${_SLINT_ABSOLUTE}
                 COMMENT "Generating ${_SLINT_BASE_NAME}.h"
                 DEPFILE ${CMAKE_CURRENT_BINARY_DIR}/${_SLINT_BASE_NAME}.d
@@ -30,6 +31,7 @@ function(SLINT_TARGET_SOURCES target)
                 COMMAND Slint::slint-compiler ${_SLINT_ABSOLUTE}
                     -o ${CMAKE_CURRENT_BINARY_DIR}/${_SLINT_BASE_NAME}.h
                     --style ${_SLINT_STYLE}
+                    --translation-domain ${target}
                 DEPENDS Slint::slint-compiler ${_SLINT_ABSOLUTE} ${ALL_SLINTS}
                 COMMENT "Generating ${_SLINT_BASE_NAME}.h"
             )
@@ -62,6 +62,7 @@ using ItemArray = slint::cbindgen_private::Slice<ItemArrayEntry>;
 using cbindgen_private::KeyboardModifiers;
 using cbindgen_private::KeyEvent;
 using cbindgen_private::PointerEvent;
+using cbindgen_private::DropEvent;
 using cbindgen_private::TableColumn;
 
 /// Internal function that checks that the API that must be called from the main
@@ -117,6 +118,17 @@ public:
 
     bool dark_color_scheme() const { return slint_windowrc_dark_color_scheme(&inner); }
 
+    bool is_fullscreen() const { return slint_windowrc_is_fullscreen(&inner); }
+    void set_fullscreen(bool value) const { slint_windowrc_set_fullscreen(&inner, value); }
+    bool is_maximized() const { return slint_windowrc_is_maximized(&inner); }
+    void set_maximized(bool value) const { slint_windowrc_set_maximized(&inner, value); }
+    bool is_minimized() const { return slint_windowrc_is_minimized(&inner); }
+    void set_minimized(bool value) const { slint_windowrc_set_minimized(&inner, value); }
+    bool is_resizable() const { return slint_windowrc_is_resizable(&inner); }
+    void set_resizable(bool value) const { slint_windowrc_set_resizable(&inner, value); }
+    bool is_always_on_top() const { return slint_windowrc_is_always_on_top(&inner); }
+    void set_always_on_top(bool value) const { slint_windowrc_set_always_on_top(&inner, value); }
+
     template<typename Component, typename ItemArray>
     void unregister_component(Component *c, ItemArray items) const
     {
@@ -144,12 +156,23 @@ public:
         slint_windowrc_set_component(&inner, &self_rc);
     }
 
-    template<typename Component, typename Parent>
-    void show_popup(const Parent *parent_component, cbindgen_private::Point p,
-                    cbindgen_private::ItemRc parent_item) const
+    template<typename Component, typename Parent, typename F>
+    void show_popup(const Parent *parent_component, cbindgen_private::Point p, bool close_on_click,
+                    cbindgen_private::ItemRc parent_item, uintptr_t popup_index,
+                    F close_requested) const
     {
         auto popup = Component::create(parent_component).into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, &parent_item);
+        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click, &parent_item,
+                                                    popup_index);
+        auto actual_cb = [](void *data) { (*reinterpret_cast<F *>(data))(); };
+        cbindgen_private::slint_windowrc_on_popup_close_requested(
+                &inner, actual_cb, [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
+                new F(std::move(close_requested)));
+    }
+
+    void close_popup(cbindgen_private::ItemRc parent_item, uintptr_t popup_index) const
+    {
+        cbindgen_private::slint_windowrc_close_popup(&inner, &parent_item, popup_index);
     }
 
     template<typename F>
@@ -457,6 +480,37 @@ public:
     /// a window frame (if present).
     void set_size(const slint::PhysicalSize &size) { inner.set_physical_size(size); }
 
+    /// Returns true if the window covers the whole screen.
+    bool is_fullscreen() const { return inner.is_fullscreen(); }
+    /// Makes the window cover the whole screen, or restores it to its normal size.
+    /// This sets the `full-screen` property of the `Window` element.
+    void set_fullscreen(bool fullscreen) { inner.set_fullscreen(fullscreen); }
+
+    /// Returns true if the window is maximized.
+    bool is_maximized() const { return inner.is_maximized(); }
+    /// Maximizes the window, or restores it to its normal size.
+    /// This sets the `maximized` property of the `Window` element.
+    void set_maximized(bool maximized) { inner.set_maximized(maximized); }
+
+    /// Returns true if the window is minimized.
+    bool is_minimized() const { return inner.is_minimized(); }
+    /// Minimizes the window, or restores it.
+    /// This sets the `minimized` property of the `Window` element.
+    void set_minimized(bool minimized) { inner.set_minimized(minimized); }
+
+    /// Returns true if the user can resize the window.
+    bool is_resizable() const { return inner.is_resizable(); }
+    /// Sets whether the user can resize the window. When not resizable, the window keeps its
+    /// current size.
+    /// This sets the `resizable` property of the `Window` element.
+    void set_resizable(bool resizable) { inner.set_resizable(resizable); }
+
+    /// Returns true if the window stays on top of other windows.
+    bool is_always_on_top() const { return inner.is_always_on_top(); }
+    /// Sets whether the window stays on top of other windows.
+    /// This sets the `always-on-top` property of the `Window` element.
+    void set_always_on_top(bool always_on_top) { inner.set_always_on_top(always_on_top); }
+
     /// \private
     private_api::WindowAdapterRc &window_handle() { return inner; }
     /// \private
@@ -551,10 +605,13 @@ inline SharedVector<float> solve_box_layout(const cbindgen_private::BoxLayoutDat
     return result;
 }
 
-inline SharedVector<float> solve_grid_layout(const cbindgen_private::GridLayoutData &data)
+inline SharedVector<float> solve_grid_layout(const cbindgen_private::GridLayoutData &data,
+                                             cbindgen_private::Slice<int> repeater_indexes)
 {
     SharedVector<float> result;
-    cbindgen_private::slint_solve_grid_layout(&data, &result);
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_grid_layout(&data, ri, &result);
     return result;
 }
 
@@ -1416,6 +1473,24 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::PinchGestureHandler::PinchGestureHandler()
+{
+    slint_gesture_data_init(&data);
+}
+cbindgen_private::PinchGestureHandler::~PinchGestureHandler()
+{
+    slint_gesture_data_free(&data);
+}
+
+cbindgen_private::SwipeGestureHandler::SwipeGestureHandler()
+{
+    slint_gesture_data_init(&data);
+}
+cbindgen_private::SwipeGestureHandler::~SwipeGestureHandler()
+{
+    slint_gesture_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
@@ -1455,6 +1530,25 @@ inline void quit_event_loop()
     cbindgen_private::slint_quit_event_loop();
 }
 
+/// Changes the language used for the strings marked with `@tr(...)` in `.slint` files.
+///
+/// All the bindings that use translated strings are re-evaluated, so the user interface is
+/// updated in the new language without having to re-create the components.
+///
+/// The language is a locale name such as `"fr"` or `"de_CH"`, and an empty string resets it to
+/// the language of the environment. When translations are looked up with gettext (the
+/// `SLINT_FEATURE_GETTEXT` option), the language is selected by setting the `LANGUAGE`
+/// environment variable of the process, so no locale needs to be installed for that language.
+/// However, gettext doesn't translate anything in the "C" locale: if the locale of the environment
+/// is "C", the `LC_MESSAGES` locale is set to `C.UTF-8`.
+///
+/// Returns false if that locale isn't installed either. The language is not changed in that case.
+inline bool set_language(std::string_view language)
+{
+    SharedString lang(language);
+    return cbindgen_private::slint_translations_set_language(&lang);
+}
+
 /// Adds the specified functor to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 /// This function is thread-safe and can be called from any thread, including the one
@@ -142,6 +142,16 @@ public:
         }
     }
 
+    /// Send a touch event to this window. Each touch point is identified by its id.
+    // Note: in rust, this is on the Window. FIXME: use a public event type
+    void dispatch_touch_event(const cbindgen_private::TouchEvent &event)
+    {
+        private_api::assert_main_thread();
+        if (was_initialized) {
+            cbindgen_private::slint_windowrc_dispatch_touch_event(&self, event);
+        }
+    }
+
     /// Send a key event to this window
     // Note: in rust, this is on the Window. FIXME: use a public event type
     void dispatch_key_event(const cbindgen_private::KeyInputEvent &event)
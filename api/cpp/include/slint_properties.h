@@ -4,6 +4,8 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <optional>
+#include <type_traits>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -292,4 +294,55 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// ChangeTracker calls a functor when the value returned by another functor changes.
+/// The notification is not done while the bindings are evaluated, but later from the event loop.
+struct ChangeTracker
+{
+    /// Constructs a new change tracker instance. It doesn't do anything until init() is called.
+    ChangeTracker() { cbindgen_private::slint_change_tracker_construct(&inner); }
+    /// Destroys the change tracker.
+    ~ChangeTracker() { cbindgen_private::slint_change_tracker_drop(&inner); }
+    /// The copy constructor is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker(const ChangeTracker &) = delete;
+    /// The assignment operator is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Starts tracking the value returned by \a fn_eval, called with \a data.
+    /// \a fn_notify is called with \a data and the new value each time that value changes.
+    /// It is not called for the initial value.
+    template<typename Data, typename FnEval, typename FnNotify>
+    void init(Data data, FnEval fn_eval, FnNotify fn_notify)
+    {
+        using Value = std::invoke_result_t<FnEval, const Data &>;
+        struct Inner
+        {
+            Data data;
+            FnEval fn_eval;
+            FnNotify fn_notify;
+            std::optional<Value> value;
+        };
+        cbindgen_private::slint_change_tracker_init(
+                &inner,
+                new Inner { std::move(data), std::move(fn_eval), std::move(fn_notify), {} },
+                [](void *user_data) {
+                    auto inner = reinterpret_cast<Inner *>(user_data);
+                    auto new_value = inner->fn_eval(inner->data);
+                    bool is_initial_value = !inner->value.has_value();
+                    if (!is_initial_value && *inner->value == new_value) {
+                        return false;
+                    }
+                    inner->value = std::move(new_value);
+                    return !is_initial_value;
+                },
+                [](void *user_data) {
+                    auto inner = reinterpret_cast<Inner *>(user_data);
+                    inner->fn_notify(inner->data, *inner->value);
+                },
+                [](void *user_data) { delete reinterpret_cast<Inner *>(user_data); });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace slint::private_api
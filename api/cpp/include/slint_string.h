@@ -223,6 +223,25 @@ inline cbindgen_private::Slice<uint8_t> string_to_slice(std::string_view str)
         const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(str.data())), str.size()
     };
 }
+
+inline SharedString translate(const SharedString &original, const SharedString &context,
+                              const SharedString &domain,
+                              cbindgen_private::Slice<SharedString> arguments, int n,
+                              const SharedString &plural)
+{
+    SharedString result = original;
+    cbindgen_private::slint_translate(&result, &context, &domain, arguments, n, &plural);
+    return result;
+}
+
+inline SharedString format_number(double value, int width, int precision, bool zero_pad,
+                                  bool grouping, bool localized)
+{
+    SharedString result;
+    cbindgen_private::slint_format_number(&result, value, width, precision, zero_pad, grouping,
+                                          localized);
+    return result;
+}
 }
 
 }
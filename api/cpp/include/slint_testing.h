@@ -23,6 +23,14 @@ inline void send_mouse_click(const Component *component, float x, float y)
     cbindgen_private::slint_send_mouse_click(&crc, x, y, &component->m_window.window_handle());
 }
 
+template<typename Component>
+inline void send_mouse_drag(const Component *component, float from_x, float from_y, float to_x,
+                            float to_y)
+{
+    cbindgen_private::slint_send_mouse_drag(from_x, from_y, to_x, to_y,
+                                            &component->m_window.window_handle());
+}
+
 template<typename Component>
 inline void send_keyboard_char(const Component *component, const slint::SharedString &str,
                                bool pressed)
@@ -49,6 +49,10 @@ class Component {
         this.comp.send_mouse_click(x, y)
     }
 
+    send_mouse_drag(from_x: number, from_y: number, to_x: number, to_y: number) {
+        this.comp.send_mouse_drag(from_x, from_y, to_x, to_y)
+    }
+
     send_keyboard_string_sequence(s: String) {
         this.comp.send_keyboard_string_sequence(s)
     }
@@ -468,6 +468,21 @@ fn to_js_value<'cx>(
             Ok(JsUndefined::new().as_value(&mut cx))
         }
 
+        method send_mouse_drag(mut cx) {
+            let from_x = cx.argument::<JsNumber>(0)?.value() as f32;
+            let from_y = cx.argument::<JsNumber>(1)?.value() as f32;
+            let to_x = cx.argument::<JsNumber>(2)?.value() as f32;
+            let to_y = cx.argument::<JsNumber>(3)?.value() as f32;
+            let this = cx.this();
+            let component = cx.borrow(&this, |x| x.0.as_ref().map(|c| c.clone_strong()));
+            let component = component.ok_or(()).or_else(|()| cx.throw_error("Invalid type"))?;
+            run_scoped(&mut cx,this.downcast().unwrap(), || {
+                slint_interpreter::testing::send_mouse_drag(&component, from_x, from_y, to_x, to_y);
+                Ok(())
+            })?;
+            Ok(JsUndefined::new().as_value(&mut cx))
+        }
+
         method send_keyboard_string_sequence(mut cx) {
             let sequence = cx.argument::<JsString>(0)?.value();
             let this = cx.this();
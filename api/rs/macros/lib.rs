@@ -170,24 +170,24 @@ fn fill_token_vec(stream: impl Iterator<Item = TokenTree>, vec: &mut Vec<parser:
                         SyntaxKind::AndAnd
                     }
                     '|' => {
-                        // Since the '|' alone does not exist or cannot be part of any other token that ||
-                        // just consider it as '||' and skip the joint ones.  FIXME. do that properly
                         if let Some(last) = vec.last_mut() {
-                            if last.kind == SyntaxKind::OrOr && prev_spacing == Spacing::Joint {
+                            if last.kind == SyntaxKind::Pipe && prev_spacing == Spacing::Joint {
+                                last.kind = SyntaxKind::OrOr;
+                                last.text = "||".into();
                                 continue;
                             }
                         }
-                        SyntaxKind::OrOr
+                        SyntaxKind::Pipe
                     }
                     '%' => {
-                        // % can only exist after number literal
+                        // % after a number literal is part of the number (percentage)
                         if let Some(last) = vec.last_mut() {
                             if last.kind == SyntaxKind::NumberLiteral {
                                 last.text = format!("{}%", last.text).into();
                                 continue;
                             }
                         }
-                        SyntaxKind::Error
+                        SyntaxKind::Percent
                     }
                     '$' => SyntaxKind::Dollar,
                     '@' => SyntaxKind::At,
@@ -205,7 +205,8 @@ fn fill_token_vec(stream: impl Iterator<Item = TokenTree>, vec: &mut Vec<parser:
                 let s = l.to_string();
                 // Why can't the rust API give me the type of the literal
                 let f = s.chars().next().unwrap();
-                let kind = if f == '"' {
+                let is_raw_string = f == 'r' && matches!(s.as_bytes().get(1), Some(b'"' | b'#'));
+                let kind = if f == '"' || is_raw_string {
                     SyntaxKind::StringLiteral
                 } else if f.is_digit(10) {
                     if let Some(last) = vec.last_mut() {
@@ -364,6 +365,7 @@ pub fn slint(stream: TokenStream) -> TokenStream {
     }
 
     compiler_config.include_paths = include_paths;
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
     let (root_component, diag) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
     //println!("{:#?}", tree);
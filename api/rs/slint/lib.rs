@@ -283,6 +283,34 @@ macro_rules! include_modules {
     };
 }
 
+/// Initialize translations when using the `gettext` feature.
+///
+/// Call this in your main function with the path where translations are located.
+/// This macro internally calls the [`bindtextdomain`](https://man7.org/linux/man-pages/man3/bindtextdomain.3.html) function from gettext.
+///
+/// The first argument of the macro must be an expression that implements `Into<std::path::PathBuf>`.
+/// It specifies the directory in which gettext should search for translations.
+///
+/// Translations are expected to be found at `<dirname>/<locale>/LC_MESSAGES/<crate>.mo`,
+/// where `dirname` is the directory passed as an argument to this macro,
+/// `locale` is a locale name (e.g., `en`, `en_GB`, `fr`), and
+/// `crate` is the package name obtained from the `CARGO_PKG_NAME` environment variable.
+///
+/// ### Example
+/// ```rust,no_run
+/// fn main() {
+///    slint::init_translations!(concat!(env!("CARGO_MANIFEST_DIR"), "/translations/"));
+///    // ...
+/// }
+/// ```
+#[cfg(feature = "gettext")]
+#[macro_export]
+macro_rules! init_translations {
+    ($dirname:expr) => {
+        $crate::private_unstable_api::init_translations(env!("CARGO_PKG_NAME"), $dirname);
+    };
+}
+
 /// This module contains items that you need to use or implement if you want use Slint in an environment without
 /// one of the supplied platform backends such as qt or winit.
 ///
@@ -126,6 +126,23 @@ pub fn set_callback_handler<
     })
 }
 
+pub fn set_change_handler<T: PartialEq + 'static, StrongRef: StrongComponentRef + 'static>(
+    tracker: &ChangeTracker,
+    component_strong: &StrongRef,
+    eval: fn(StrongRef) -> T,
+    notify: fn(StrongRef, &T),
+) {
+    tracker.init(
+        component_strong.to_weak(),
+        move |weak| eval(<StrongRef as StrongComponentRef>::from_weak(weak).unwrap()),
+        move |weak, value| {
+            if let Some(strong) = <StrongRef as StrongComponentRef>::from_weak(weak) {
+                notify(strong, value)
+            }
+        },
+    )
+}
+
 pub fn debug(s: SharedString) {
     #[cfg(feature = "log")]
     log::debug!("{s}");
@@ -138,6 +155,13 @@ pub fn debug(s: SharedString) {
     }
 }
 
+pub use i_slint_core::translations::{format_number, translate};
+
+#[cfg(feature = "gettext")]
+pub fn init_translations(domain: &str, dirname: impl Into<std::path::PathBuf>) {
+    i_slint_core::translations::gettext_bindtextdomain(domain, dirname.into()).unwrap()
+}
+
 /// Creates a new window to render components in.
 pub fn create_window_adapter() -> alloc::rc::Rc<dyn i_slint_core::window::WindowAdapter> {
     i_slint_backend_selector::with_platform(|b| b.create_window_adapter())
@@ -153,7 +177,9 @@ pub mod re_exports {
     pub use const_field_offset::{self, FieldOffsets, PinnedDrop};
     pub use core::iter::FromIterator;
     pub use i_slint_backend_selector::native_widgets::*;
-    pub use i_slint_core::accessibility::AccessibleStringProperty;
+    pub use i_slint_core::accessibility::{
+        AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+    };
     pub use i_slint_core::animations::{animation_tick, EasingCurve};
     pub use i_slint_core::callbacks::Callback;
     pub use i_slint_core::component::{
@@ -171,9 +197,11 @@ pub mod re_exports {
     };
     pub use i_slint_core::items::*;
     pub use i_slint_core::layout::*;
-    pub use i_slint_core::lengths::LogicalLength;
+    pub use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize};
     pub use i_slint_core::model::*;
-    pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
+    pub use i_slint_core::properties::{
+        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+    };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::window::{WindowAdapter, WindowInner};
     pub use i_slint_core::Color;
@@ -339,6 +339,13 @@ pub fn upgrade(&self) -> Option<VRc<VTable, X>> {
     pub fn ptr_eq(this: &Self, other: &Self) -> bool {
         this.inner == other.inner
     }
+
+    /// Returns a pointer to the allocation, or a null pointer if this VWeak was default constructed.
+    /// The allocation is not freed while this VWeak exists, so the pointer can be used as a key
+    /// along with [`Self::ptr_eq`], for example to hash the VWeak.
+    pub fn as_ptr(this: &Self) -> *const u8 {
+        this.inner.map_or(core::ptr::null(), |inner| inner.as_ptr() as *const u8)
+    }
 }
 
 impl<VTable: VTableMetaDropInPlace + 'static, X: HasStaticVTable<VTable> + 'static>
@@ -5,7 +5,9 @@
 
 use crate::accessible_generated::*;
 
-use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+};
 use i_slint_core::item_tree::{ItemRc, ItemWeak};
 use i_slint_core::properties::{PropertyDirtyHandler, PropertyTracker};
 use i_slint_core::window::WindowInner;
@@ -29,6 +31,11 @@
 const VALUE_STEP: u32 = VALUE_MAXIMUM + 1;
 const CHECKABLE: u32 = VALUE_STEP + 1;
 
+// KEEP IN SYNC WITH CONSTANTS IN C++
+const ACTION_DEFAULT: u32 = SupportedAccessibilityAction::DEFAULT.0;
+const ACTION_DECREMENT: u32 = SupportedAccessibilityAction::DECREMENT.0;
+const ACTION_INCREMENT: u32 = SupportedAccessibilityAction::INCREMENT.0;
+
 pub struct AccessibleItemPropertiesTracker {
     obj: *mut c_void,
 }
@@ -311,7 +318,13 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
                     i_slint_core::items::AccessibleRole::Slider => QAccessible_Role_Slider,
                     i_slint_core::items::AccessibleRole::Spinbox => QAccessible_Role_SpinBox,
                     i_slint_core::items::AccessibleRole::Tab => QAccessible_Role_PageTab,
+                    i_slint_core::items::AccessibleRole::TabList => QAccessible_Role_PageTabList,
                     i_slint_core::items::AccessibleRole::Text => QAccessible_Role_StaticText,
+                    i_slint_core::items::AccessibleRole::List => QAccessible_Role_List,
+                    i_slint_core::items::AccessibleRole::ListItem => QAccessible_Role_ListItem,
+                    i_slint_core::items::AccessibleRole::Table => QAccessible_Role_Table,
+                    i_slint_core::items::AccessibleRole::ProgressIndicator => QAccessible_Role_ProgressBar,
+                    i_slint_core::items::AccessibleRole::Image => QAccessible_Role_Graphic,
                 }
             });
         }
@@ -359,6 +372,35 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
         });
     }
 
+    uint32_t item_supported_actions(void *data) {
+        return rust!(item_supported_actions_
+            [data: &SlintAccessibleItemData as "void*"] -> u32 as "uint32_t" {
+            data.item.upgrade().map_or(0, |item| item.supported_accessibility_actions().0)
+        });
+    }
+
+    void item_do_action(void *data, uint32_t action) {
+        rust!(item_do_action_ [data: &SlintAccessibleItemData as "void*", action: u32 as "uint32_t"] {
+            let action = match action {
+                ACTION_DEFAULT => AccessibilityAction::Default,
+                ACTION_DECREMENT => AccessibilityAction::Decrement,
+                ACTION_INCREMENT => AccessibilityAction::Increment,
+                _ => return,
+            };
+            if let Some(item) = data.item.upgrade() {
+                item.accessibility_action(&action);
+            }
+        });
+    }
+
+    void item_set_value(void *data, const QString &value) {
+        rust!(item_set_value_ [data: &SlintAccessibleItemData as "void*", value: &QString as "const QString &"] {
+            if let Some(item) = data.item.upgrade() {
+                item.accessibility_action(&AccessibilityAction::SetValue(String::from(value).into()));
+            }
+        });
+    }
+
     // ------------------------------------------------------------------------------
     // Slint_accessible:
     // ------------------------------------------------------------------------------
@@ -537,7 +579,7 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
     // Slint_accessible_item:
     // ------------------------------------------------------------------------------
 
-    class Slint_accessible_item : public Slint_accessible, public QAccessibleValueInterface {
+    class Slint_accessible_item : public Slint_accessible, public QAccessibleValueInterface, public QAccessibleActionInterface {
     public:
         Slint_accessible_item(void *item, QObject *obj, QAccessible::Role role, QAccessibleInterface *parent) :
             Slint_accessible(role, parent), m_object(obj)
@@ -615,6 +657,9 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
             if (t == QAccessible::ValueInterface && !item_string_property(m_data, QAccessible::Value).isEmpty()) {
                 return static_cast<QAccessibleValueInterface*>(this);
             }
+            if (t == QAccessible::ActionInterface && item_supported_actions(m_data) != 0) {
+                return static_cast<QAccessibleActionInterface*>(this);
+            }
             return QAccessibleInterface::interface_cast(t);
         }
 
@@ -624,8 +669,7 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
         }
 
         void setCurrentValue(const QVariant &value) override {
-            // FIXME: Implement this?
-            Q_UNUSED(value);
+            item_set_value(m_data, value.toString());
         }
 
         QVariant maximumValue() const override {
@@ -640,6 +684,41 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
             return item_string_property(m_data, VALUE_STEP);
         }
 
+        // AccessibleActionInterface:
+        // KEEP IN SYNC WITH CONSTANTS IN RUST
+        static constexpr uint32_t ACTION_DEFAULT = 1 << 0;
+        static constexpr uint32_t ACTION_DECREMENT = 1 << 1;
+        static constexpr uint32_t ACTION_INCREMENT = 1 << 2;
+
+        QStringList actionNames() const override {
+            auto supported = item_supported_actions(m_data);
+            QStringList names;
+            if (supported & ACTION_DEFAULT) {
+                names << pressAction();
+            }
+            if (supported & ACTION_DECREMENT) {
+                names << decreaseAction();
+            }
+            if (supported & ACTION_INCREMENT) {
+                names << increaseAction();
+            }
+            return names;
+        }
+
+        void doAction(const QString &actionName) override {
+            if (actionName == pressAction()) {
+                item_do_action(m_data, ACTION_DEFAULT);
+            } else if (actionName == decreaseAction()) {
+                item_do_action(m_data, ACTION_DECREMENT);
+            } else if (actionName == increaseAction()) {
+                item_do_action(m_data, ACTION_INCREMENT);
+            }
+        }
+
+        QStringList keyBindingsForAction(const QString &) const override {
+            return QStringList();
+        }
+
 
     private:
         QObject *m_object = nullptr;
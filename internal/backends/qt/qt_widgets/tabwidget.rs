@@ -337,6 +337,9 @@ pub struct NativeTab {
     pub icon: Property<i_slint_core::graphics::Image>,
     pub enabled: Property<bool>,
     pub pressed: Property<bool>,
+    pub closable: Property<bool>,
+    pub close_pressed: Property<bool>,
+    pub close_clicked: Callback<VoidArg>,
     pub current: Property<i32>,
     pub current_focused: Property<i32>,
     pub tab_index: Property<i32>,
@@ -344,6 +347,32 @@ pub struct NativeTab {
     pub cached_rendering_data: CachedRenderingData,
 }
 
+impl NativeTab {
+    /// The geometry of the close button, relative to the tab, if the tab is closable
+    fn close_button_rect(self: Pin<&Self>) -> Option<LogicalRect> {
+        if !self.closable() {
+            return None;
+        }
+        let size =
+            qttypes::QSizeF { width: self.width().get() as _, height: self.height().get() as _ };
+        let rect = cpp!(unsafe [size as "QSizeF"] -> qttypes::QRectF as "QRectF" {
+            ensure_initialized();
+            QStyleOptionTab option;
+            option.rect = QRect(QPoint(), size.toSize());
+            option.shape = QTabBar::RoundedNorth;
+            auto style = qApp->style();
+            option.rightButtonSize = QSize(
+                style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, &option, nullptr),
+                style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, &option, nullptr));
+            return QRectF(style->subElementRect(QStyle::SE_TabBarTabRightButton, &option, nullptr));
+        });
+        Some(LogicalRect::new(
+            LogicalPoint::new(rect.x as _, rect.y as _),
+            LogicalSize::new(rect.width as _, rect.height as _),
+        ))
+    }
+}
+
 impl Item for NativeTab {
     fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
 
@@ -364,11 +393,13 @@ fn layout_info(
             crate::qt_window::image_to_pixmap((&self.icon()).into(), None).unwrap_or_default();
         let tab_index: i32 = self.tab_index();
         let num_tabs: i32 = self.num_tabs();
+        let closable: bool = self.closable();
         let size = cpp!(unsafe [
             text as "QString",
             icon as "QPixmap",
             tab_index as "int",
-            num_tabs as "int"
+            num_tabs as "int",
+            closable as "bool"
         ] -> qttypes::QSize as "QSize" {
             ensure_initialized();
             QStyleOptionTab option;
@@ -387,6 +418,13 @@ fn layout_info(
             int textWidth = option.fontMetrics.size(Qt::TextShowMnemonic, text).width();
             auto iconSize = icon.isNull() ? 0 : style->pixelMetric(QStyle::PM_TabBarIconSize, nullptr, nullptr);
             QSize csz = QSize(textWidth + iconSize + hframe + padding, qMax(option.fontMetrics.height(), iconSize) + vframe);
+            if (closable) {
+                option.rightButtonSize = QSize(
+                    style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, &option, nullptr),
+                    style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, &option, nullptr));
+                csz.rwidth() += option.rightButtonSize.width() + hframe / 2;
+                csz.rheight() = qMax(csz.height(), option.rightButtonSize.height() + vframe);
+            }
             return style->sizeFromContents(QStyle::CT_TabBarTab, &option, csz, nullptr);
         });
         LayoutInfo {
@@ -423,6 +461,27 @@ fn input_event(
             return InputEventResult::EventIgnored;
         }
 
+        let on_close_button =
+            |position| self.close_button_rect().map_or(false, |rect| rect.contains(position));
+        match event {
+            MouseEvent::Pressed { position, .. } if on_close_button(position) => {
+                Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(true);
+                return InputEventResult::GrabMouse;
+            }
+            MouseEvent::Released { position, .. } if self.close_pressed() => {
+                Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(false);
+                if on_close_button(position) {
+                    Self::FIELD_OFFSETS.close_clicked.apply_pin(self).call(&());
+                }
+                return InputEventResult::EventAccepted;
+            }
+            MouseEvent::Moved { .. } if self.close_pressed() => {
+                return InputEventResult::GrabMouse;
+            }
+            MouseEvent::Exit => Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(false),
+            _ => {}
+        }
+
         Self::FIELD_OFFSETS.pressed.apply_pin(self).set(match event {
             MouseEvent::Pressed { .. } => true,
             MouseEvent::Exit | MouseEvent::Released { .. } => false,
@@ -480,6 +539,8 @@ fn focus_event(
         let current_focused: i32 = this.current_focused();
         let tab_index: i32 = this.tab_index();
         let num_tabs: i32 = this.num_tabs();
+        let closable: bool = this.closable();
+        let close_pressed: bool = this.close_pressed();
 
         cpp!(unsafe [
             painter as "QPainterPtr*",
@@ -494,6 +555,8 @@ fn focus_event(
             current as "int",
             current_focused as "int",
             num_tabs as "int",
+            closable as "bool",
+            close_pressed as "bool",
             initial_state as "int"
         ] {
             ensure_initialized();
@@ -525,7 +588,26 @@ fn focus_event(
                 option.state |= QStyle::State_HasFocus | QStyle::State_KeyboardFocusChange | QStyle::State_Item;
             }
             option.features |= QStyleOptionTab::HasFrame;
-            qApp->style()->drawControl(QStyle::CE_TabBarTab, &option, painter->get(), widget);
+            auto style = qApp->style();
+            if (closable) {
+                option.rightButtonSize = QSize(
+                    style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, &option, widget),
+                    style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, &option, widget));
+            }
+            style->drawControl(QStyle::CE_TabBarTab, &option, painter->get(), widget);
+            if (closable) {
+                // Same as what QTabBar's close button does
+                QStyleOption close_option;
+                close_option.rect = style->subElementRect(QStyle::SE_TabBarTabRightButton, &option, widget);
+                close_option.state = QStyle::State_AutoRaise;
+                if (enabled)
+                    close_option.state |= QStyle::State_Enabled;
+                if (close_pressed)
+                    close_option.state |= QStyle::State_Sunken;
+                if (current == tab_index)
+                    close_option.state |= QStyle::State_Selected;
+                style->drawPrimitive(QStyle::PE_IndicatorTabClose, &close_option, painter->get(), widget);
+            }
         });
     }
 }
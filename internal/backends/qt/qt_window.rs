@@ -90,6 +90,20 @@ struct SlintWidget : QWidget {
             setFocusPolicy(Qt::StrongFocus);
         }
 
+        // Called on a popup when the user clicks outside of it
+        static void requestPopupClose(const SlintWidget *parent) {
+            void *parent_window = parent->rust_window;
+            rust!(Slint_requestPopupClose [parent_window: &QtWindow as "void*"] {
+                // Closing the popup destroys its widget, which must not happen while it handles an event
+                let parent_weak = parent_window.self_weak.clone();
+                i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+                    if let Some(parent_window) = parent_weak.upgrade() {
+                        WindowInner::from_pub(&parent_window.window).request_popup_close();
+                    }
+                });
+            });
+        }
+
         void paintEvent(QPaintEvent *) override {
             auto painter = std::unique_ptr<QPainter>(new QPainter(this));
             painter->setClipRect(rect());
@@ -108,8 +122,15 @@ struct SlintWidget : QWidget {
         }
 
         void mousePressEvent(QMouseEvent *event) override {
-            isMouseButtonDown = true;
             QPoint pos = event->pos();
+            if (auto p = dynamic_cast<const SlintWidget*>(parent())) {
+                if (!rect().contains(pos)) {
+                    // A press outside of the popup requests to close it
+                    requestPopupClose(p);
+                    return;
+                }
+            }
+            isMouseButtonDown = true;
             int button = event->button();
             rust!(Slint_mousePressEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", button: u32 as "int" ] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
@@ -144,7 +165,9 @@ struct SlintWidget : QWidget {
                 // FIXME: better way to close the popup
                 void *parent_window = p->rust_window;
                 rust!(Slint_mouseReleaseEventPopup [parent_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint"] {
-                    parent_window.close_popup();
+                    if WindowInner::from_pub(&parent_window.window).active_popup_closes_on_click() {
+                        parent_window.close_popup();
+                    }
                 });
             }
         }
@@ -210,11 +233,37 @@ struct SlintWidget : QWidget {
                         ds.as_ref().set(dark_color_scheme);
                     }
                 });
+            } else if (event->type() == QEvent::WindowStateChange) {
+                auto state = windowState();
+                bool full_screen = state & Qt::WindowFullScreen;
+                bool maximized = state & Qt::WindowMaximized;
+                bool minimized = state & Qt::WindowMinimized;
+                rust!(Slint_updateWindowState [rust_window: &QtWindow as "void*", full_screen: bool as "bool", maximized: bool as "bool", minimized: bool as "bool"] {
+                    if let Some(window_item) = WindowInner::from_pub(&rust_window.window).window_item() {
+                        let window_item = window_item.as_pin_ref();
+                        if window_item.full_screen() != full_screen {
+                            window_item.full_screen.set(full_screen);
+                        }
+                        if window_item.maximized() != maximized {
+                            window_item.maximized.set(maximized);
+                        }
+                        if window_item.minimized() != minimized {
+                            window_item.minimized.set(minimized);
+                        }
+                    }
+                });
             }
             QWidget::changeEvent(event);
         }
 
         void closeEvent(QCloseEvent *event) override {
+            if (auto p = dynamic_cast<const SlintWidget*>(parent())) {
+                // Qt closes the popup when the user clicks outside of it, but it may have
+                // close-on-click set to false: let the popup decide.
+                event->ignore();
+                requestPopupClose(p);
+                return;
+            }
             bool accepted = rust!(Slint_requestClose [rust_window: &QtWindow as "void*"] -> bool as "bool" {
                 return WindowInner::from_pub(&rust_window.window).request_close();
             });
@@ -1532,6 +1581,10 @@ fn apply_window_properties(&self, window_item: Pin<&items::WindowItem>) {
         let widget_ptr = self.widget_ptr();
         let title: qttypes::QString = window_item.title().as_str().into();
         let no_frame = window_item.no_frame();
+        let full_screen = window_item.full_screen();
+        let maximized = window_item.maximized();
+        let minimized = window_item.minimized();
+        let always_on_top = window_item.always_on_top();
         let mut size = qttypes::QSize {
             width: window_item.width().get().ceil() as _,
             height: window_item.height().get().ceil() as _,
@@ -1564,11 +1617,22 @@ fn apply_window_properties(&self, window_item: Pin<&items::WindowItem>) {
             }
         };
 
-        cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool"] {
+        cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool",
+                full_screen as "bool", maximized as "bool", minimized as "bool", always_on_top as "bool"] {
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
             }
             widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
+            widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            Qt::WindowStates state = widget_ptr->windowState() & ~(Qt::WindowFullScreen | Qt::WindowMaximized | Qt::WindowMinimized);
+            if (full_screen)
+                state |= Qt::WindowFullScreen;
+            if (maximized)
+                state |= Qt::WindowMaximized;
+            if (minimized)
+                state |= Qt::WindowMinimized;
+            if (state != widget_ptr->windowState())
+                widget_ptr->setWindowState(state);
             widget_ptr->setWindowTitle(title);
             auto pal = widget_ptr->palette();
 
@@ -0,0 +1,463 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! This module contains the integration with [AccessKit](https://github.com/AccessKit/accesskit),
+//! which exposes the accessible items of a window to the screen readers of the platform.
+
+// cspell:ignore accesskit descendents
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+
+use accesskit::{CheckedState, Node, NodeId, Rect, Role, Tree, TreeUpdate};
+use i_slint_core::accessibility::{
+    accessible_descendents, AccessibilityAction, AccessibleStringProperty,
+    SupportedAccessibilityAction,
+};
+use i_slint_core::items::{AccessibleRole, ItemRc, ItemWeak};
+use i_slint_core::properties::{PropertyDirtyHandler, PropertyTracker};
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use i_slint_core::SharedString;
+
+/// The AccessKit adapter of a window.
+///
+/// It builds the tree of AccessKit nodes from the accessible items of the window, and keeps track
+/// of the properties that were used to build each node. When any of them changes, only the nodes
+/// that depend on it are re-built and sent to AccessKit.
+pub struct AccessKitAdapter {
+    inner: accesskit_winit::Adapter,
+    window_adapter_weak: Weak<dyn WindowAdapter>,
+    nodes: RefCell<NodeCache>,
+    /// The item that has the keyboard focus
+    focus_item: RefCell<ItemWeak>,
+    global_property_tracker: Pin<Box<PropertyTracker<AccessibilityPropertyTracker>>>,
+}
+
+impl AccessKitAdapter {
+    pub fn new(
+        window_adapter_weak: Weak<dyn WindowAdapter>,
+        winit_window: &winit::window::Window,
+    ) -> Rc<Self> {
+        Rc::new_cyclic(|self_weak| {
+            let global_property_tracker =
+                Box::pin(PropertyTracker::new_with_dirty_handler(AccessibilityPropertyTracker {
+                    adapter_weak: self_weak.clone(),
+                }));
+
+            let mut nodes = NodeCache::default();
+            let mut initial_nodes = Vec::new();
+            if let Some(window_adapter) = window_adapter_weak.upgrade() {
+                let root_item =
+                    ItemRc::new(WindowInner::from_pub(window_adapter.window()).component(), 0);
+                nodes.find_or_insert(&root_item);
+                initial_nodes = global_property_tracker
+                    .as_ref()
+                    .evaluate_as_dependency_root(|| nodes.update_dirty_nodes(&window_adapter));
+            }
+            let root = nodes.root_id();
+
+            let event_loop_proxy = crate::event_loop::with_window_target(|event_loop| {
+                event_loop.event_loop_proxy().clone()
+            });
+            let inner = accesskit_winit::Adapter::new(
+                winit_window,
+                move || TreeUpdate {
+                    nodes: initial_nodes,
+                    tree: Some(Tree::new(root)),
+                    focus: None,
+                },
+                event_loop_proxy,
+            );
+
+            Self {
+                inner,
+                window_adapter_weak,
+                nodes: RefCell::new(nodes),
+                focus_item: Default::default(),
+                global_property_tracker,
+            }
+        })
+    }
+
+    /// Forward the window event to AccessKit. This must be called for every event of the window.
+    pub fn process_event(
+        &self,
+        winit_window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.inner.on_event(winit_window, event);
+    }
+
+    /// Called when the focus item of the window changed.
+    pub fn handle_focus_change(&self, new: Option<ItemRc>) {
+        *self.focus_item.borrow_mut() = new.map(|item| item.downgrade()).unwrap_or_default();
+        self.send_changes();
+    }
+
+    /// Handle the request of an assistive technology.
+    pub fn handle_action_request(&self, request: accesskit::ActionRequest) {
+        let item = match self.nodes.borrow().item_by_id(request.target) {
+            Some(item) => item,
+            None => return,
+        };
+        let window_adapter = match self.window_adapter_weak.upgrade() {
+            Some(window_adapter) => window_adapter,
+            None => return,
+        };
+        match request.action {
+            accesskit::Action::Focus => {
+                WindowInner::from_pub(window_adapter.window()).set_focus_item(&item)
+            }
+            accesskit::Action::Default => item.accessibility_action(&AccessibilityAction::Default),
+            accesskit::Action::Decrement => {
+                item.accessibility_action(&AccessibilityAction::Decrement)
+            }
+            accesskit::Action::Increment => {
+                item.accessibility_action(&AccessibilityAction::Increment)
+            }
+            accesskit::Action::SetValue => {
+                let value = match request.data {
+                    Some(accesskit::ActionData::Value(value)) => SharedString::from(&*value),
+                    Some(accesskit::ActionData::NumericValue(value)) => value.to_string().into(),
+                    _ => return,
+                };
+                item.accessibility_action(&AccessibilityAction::SetValue(value))
+            }
+            _ => {}
+        }
+    }
+
+    /// Re-build the nodes that changed since the last update, and send them to AccessKit along
+    /// with the current focus.
+    fn send_changes(&self) {
+        let window_adapter = match self.window_adapter_weak.upgrade() {
+            Some(window_adapter) => window_adapter,
+            None => return,
+        };
+        let nodes = self.global_property_tracker.as_ref().evaluate_as_dependency_root(|| {
+            self.nodes.borrow_mut().update_dirty_nodes(&window_adapter)
+        });
+        let focus = self
+            .focus_item
+            .borrow()
+            .upgrade()
+            .and_then(|item| self.nodes.borrow().find_accessible_ancestor(item));
+        self.inner.update(TreeUpdate { nodes, tree: None, focus });
+    }
+}
+
+/// Called when any of the properties used to build the nodes changes: schedule an update
+struct AccessibilityPropertyTracker {
+    adapter_weak: Weak<AccessKitAdapter>,
+}
+
+impl PropertyDirtyHandler for AccessibilityPropertyTracker {
+    fn notify(&self) {
+        // The properties are still locked during the notification, so the update must happen later
+        let adapter_weak = self.adapter_weak.clone();
+        i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+            if let Some(adapter) = adapter_weak.upgrade() {
+                adapter.send_changes();
+            }
+        })
+    }
+}
+
+struct CachedNode {
+    id: NodeId,
+    item: ItemWeak,
+    /// Tracks the properties that were accessed to build the node
+    tracker: Pin<Box<PropertyTracker>>,
+}
+
+/// The mapping between the accessible items and the AccessKit node ids.
+#[derive(Default)]
+struct NodeCache {
+    nodes: Vec<CachedNode>,
+    /// Index in `nodes` of the node of each item
+    index_by_item: HashMap<ItemWeak, usize>,
+    /// Index in `nodes` of each node id
+    index_by_id: HashMap<NodeId, usize>,
+    next_id: u128,
+}
+
+impl NodeCache {
+    fn root_id(&self) -> NodeId {
+        self.nodes.first().map_or_else(|| node_id(1), |root| root.id)
+    }
+
+    fn find(&self, item: &ItemRc) -> Option<NodeId> {
+        self.index_by_item.get(&item.downgrade()).map(|index| self.nodes[*index].id)
+    }
+
+    fn find_or_insert(&mut self, item: &ItemRc) -> NodeId {
+        if let Some(id) = self.find(item) {
+            return id;
+        }
+        self.next_id += 1;
+        let id = node_id(self.next_id);
+        let item = item.downgrade();
+        self.index_by_item.insert(item.clone(), self.nodes.len());
+        self.index_by_id.insert(id, self.nodes.len());
+        // A new tracker is dirty, so the node will be built in the next call to update_dirty_nodes
+        self.nodes.push(CachedNode { id, item, tracker: Box::pin(PropertyTracker::default()) });
+        id
+    }
+
+    /// Return the node of the item, or of its closest accessible ancestor
+    fn find_accessible_ancestor(&self, mut item: ItemRc) -> Option<NodeId> {
+        loop {
+            if let Some(id) = self.find(&item) {
+                return Some(id);
+            }
+            item = item.parent_item()?;
+        }
+    }
+
+    fn item_by_id(&self, id: NodeId) -> Option<ItemRc> {
+        self.index_by_id.get(&id).and_then(|index| self.nodes[*index].item.upgrade())
+    }
+
+    /// Build the nodes whose properties changed since they were last built, as well as the nodes
+    /// of the accessible items that were added since then.
+    ///
+    /// This must be called from the evaluation of a property tracker, so that it is notified
+    /// when any of the nodes needs to be re-built.
+    fn update_dirty_nodes(
+        &mut self,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> Vec<(NodeId, Arc<Node>)> {
+        let mut updated_nodes = Vec::new();
+        // New children are appended to `self.nodes` while iterating, so they are visited as well
+        let mut index = 0;
+        while index < self.nodes.len() {
+            if let Some(item) = self.nodes[index].item.upgrade() {
+                let built = self.nodes[index].tracker.as_ref().evaluate_if_dirty(|| {
+                    let mut children = Vec::new();
+                    accessible_descendents(&item, &mut children);
+                    let scale_factor = window_adapter.window().scale_factor();
+                    (build_node_without_children(&item, scale_factor), children)
+                });
+                if let Some((mut node, children)) = built {
+                    node.children =
+                        children.iter().map(|child| self.find_or_insert(child)).collect();
+                    updated_nodes.push((self.nodes[index].id, Arc::new(node)));
+                }
+            }
+            index += 1;
+        }
+        let len = self.nodes.len();
+        self.nodes.retain(|node| node.item.upgrade().is_some());
+        if self.nodes.len() != len {
+            self.index_by_item =
+                self.nodes.iter().enumerate().map(|(i, node)| (node.item.clone(), i)).collect();
+            self.index_by_id =
+                self.nodes.iter().enumerate().map(|(i, node)| (node.id, i)).collect();
+        }
+        updated_nodes
+    }
+}
+
+fn node_id(id: u128) -> NodeId {
+    NodeId(std::num::NonZeroU128::new(id).unwrap())
+}
+
+fn build_node_without_children(item: &ItemRc, scale_factor: f32) -> Node {
+    let geometry = item.geometry();
+    let origin = item.map_to_window(geometry.origin);
+    let scale_factor = scale_factor as f64;
+    let bounds = Rect {
+        x0: origin.x as f64 * scale_factor,
+        y0: origin.y as f64 * scale_factor,
+        x1: (origin.x + geometry.width()) as f64 * scale_factor,
+        y1: (origin.y + geometry.height()) as f64 * scale_factor,
+    };
+
+    if let Some(window_item) = item.downcast::<i_slint_core::items::WindowItem>() {
+        let title = window_item.as_pin_ref().title();
+        return Node {
+            role: Role::Window,
+            bounds: Some(bounds),
+            name: (!title.is_empty()).then(|| title.as_str().into()),
+            ..Default::default()
+        };
+    }
+
+    let mut node = Node { bounds: Some(bounds), ..Default::default() };
+    apply_accessible_properties(&mut node, item.accessible_role(), |what| {
+        item.accessible_string_property(what)
+    });
+    if item.supported_accessibility_actions().contains(SupportedAccessibilityAction::DEFAULT) {
+        node.default_action_verb = Some(accesskit::DefaultActionVerb::Click);
+    }
+    node
+}
+
+fn accesskit_role(role: AccessibleRole) -> Role {
+    match role {
+        AccessibleRole::None => Role::Unknown,
+        AccessibleRole::Button => Role::Button,
+        AccessibleRole::Checkbox => Role::CheckBox,
+        AccessibleRole::Combobox => Role::ComboBoxGrouping,
+        AccessibleRole::Slider => Role::Slider,
+        AccessibleRole::Spinbox => Role::SpinButton,
+        AccessibleRole::Tab => Role::Tab,
+        AccessibleRole::TabList => Role::TabList,
+        AccessibleRole::Text => Role::StaticText,
+        AccessibleRole::List => Role::List,
+        AccessibleRole::ListItem => Role::ListItem,
+        AccessibleRole::Table => Role::Table,
+        AccessibleRole::ProgressIndicator => Role::ProgressIndicator,
+        AccessibleRole::Image => Role::Image,
+    }
+}
+
+/// Set the role and the properties of the node from the `accessible-` properties of the item,
+/// queried with `property`.
+fn apply_accessible_properties(
+    node: &mut Node,
+    role: AccessibleRole,
+    property: impl Fn(AccessibleStringProperty) -> SharedString,
+) {
+    let non_empty = |what| Some(property(what)).filter(|s| !s.is_empty());
+    let number = |what| non_empty(what).and_then(|s| s.parse::<f64>().ok());
+
+    node.role = accesskit_role(role);
+    node.focusable = !matches!(
+        role,
+        AccessibleRole::None
+            | AccessibleRole::Text
+            | AccessibleRole::Image
+            | AccessibleRole::ProgressIndicator
+    );
+    node.name = non_empty(AccessibleStringProperty::Label).map(|s| s.as_str().into());
+    node.description = non_empty(AccessibleStringProperty::Description).map(|s| s.as_str().into());
+
+    if property(AccessibleStringProperty::Checkable) == "true" {
+        node.checked_state = Some(if property(AccessibleStringProperty::Checked) == "true" {
+            CheckedState::True
+        } else {
+            CheckedState::False
+        });
+    }
+
+    node.numeric_value = number(AccessibleStringProperty::Value);
+    if node.numeric_value.is_none() {
+        node.value = non_empty(AccessibleStringProperty::Value).map(|s| s.as_str().into());
+    }
+    node.min_numeric_value = number(AccessibleStringProperty::ValueMinimum);
+    node.max_numeric_value = number(AccessibleStringProperty::ValueMaximum);
+    node.numeric_value_step = number(AccessibleStringProperty::ValueStep);
+}
+
+#[test]
+fn accessible_properties_to_node() {
+    let mut node = Node::default();
+    apply_accessible_properties(&mut node, AccessibleRole::Slider, |what| match what {
+        AccessibleStringProperty::Label => "Volume".into(),
+        AccessibleStringProperty::Value => "42".into(),
+        AccessibleStringProperty::ValueMinimum => "0".into(),
+        AccessibleStringProperty::ValueMaximum => "100".into(),
+        _ => Default::default(),
+    });
+    assert_eq!(node.role, Role::Slider);
+    assert!(node.focusable);
+    assert_eq!(node.name.as_deref(), Some("Volume"));
+    assert_eq!(node.description, None);
+    assert_eq!(node.value, None);
+    assert_eq!(node.numeric_value, Some(42.));
+    assert_eq!(node.min_numeric_value, Some(0.));
+    assert_eq!(node.max_numeric_value, Some(100.));
+    assert_eq!(node.numeric_value_step, None);
+    assert_eq!(node.checked_state, None);
+
+    let mut node = Node::default();
+    apply_accessible_properties(&mut node, AccessibleRole::Checkbox, |what| match what {
+        AccessibleStringProperty::Label => "Enabled".into(),
+        AccessibleStringProperty::Description => "Enable the feature".into(),
+        AccessibleStringProperty::Checkable => "true".into(),
+        AccessibleStringProperty::Checked => "true".into(),
+        _ => Default::default(),
+    });
+    assert_eq!(node.role, Role::CheckBox);
+    assert_eq!(node.description.as_deref(), Some("Enable the feature"));
+    assert_eq!(node.checked_state, Some(CheckedState::True));
+
+    let mut node = Node::default();
+    apply_accessible_properties(&mut node, AccessibleRole::Text, |what| match what {
+        AccessibleStringProperty::Label => "Hello".into(),
+        AccessibleStringProperty::Value => "not a number".into(),
+        _ => Default::default(),
+    });
+    assert_eq!(node.role, Role::StaticText);
+    assert!(!node.focusable);
+    assert_eq!(node.value.as_deref(), Some("not a number"));
+    assert_eq!(node.numeric_value, None);
+
+    let mut node = Node::default();
+    apply_accessible_properties(&mut node, AccessibleRole::ProgressIndicator, |what| match what {
+        AccessibleStringProperty::Label => "Downloading".into(),
+        AccessibleStringProperty::Value => "0.5".into(),
+        _ => Default::default(),
+    });
+    assert_eq!(node.role, Role::ProgressIndicator);
+    assert!(!node.focusable);
+    assert_eq!(node.numeric_value, Some(0.5));
+}
+
+#[test]
+fn incremental_tree_update() {
+    i_slint_backend_testing::init();
+    slint::slint! {
+        App := Window {
+            property <string> label: "Play";
+            Rectangle {
+                accessible-role: button;
+                accessible-label: root.label;
+            }
+            Rectangle {
+                accessible-role: text;
+                accessible-label: "Now playing";
+            }
+        }
+    }
+    let instance = App::new();
+    let window_adapter = WindowInner::from_pub(instance.window()).window_adapter();
+    let root_item = ItemRc::new(WindowInner::from_pub(instance.window()).component(), 0);
+
+    let tracker = Box::pin(PropertyTracker::default());
+    let mut nodes = NodeCache::default();
+    nodes.find_or_insert(&root_item);
+    let initial_nodes =
+        tracker.as_ref().evaluate_as_dependency_root(|| nodes.update_dirty_nodes(&window_adapter));
+    assert_eq!(initial_nodes.len(), 3);
+    let (root_id, root_node) = &initial_nodes[0];
+    assert_eq!(*root_id, nodes.root_id());
+    assert_eq!(root_node.role, Role::Window);
+    assert_eq!(root_node.children.len(), 2);
+    let button_id = root_node.children[0];
+    let button_item = nodes.item_by_id(button_id).unwrap();
+    assert_eq!(nodes.find(&button_item), Some(button_id));
+    assert!(!tracker.is_dirty());
+
+    let updated_nodes =
+        tracker.as_ref().evaluate_as_dependency_root(|| nodes.update_dirty_nodes(&window_adapter));
+    assert!(updated_nodes.is_empty());
+
+    instance.set_label("Pause".into());
+    assert!(tracker.is_dirty());
+    let update = TreeUpdate {
+        nodes: tracker
+            .as_ref()
+            .evaluate_as_dependency_root(|| nodes.update_dirty_nodes(&window_adapter)),
+        tree: None,
+        focus: None,
+    };
+    assert_eq!(update.nodes.len(), 1);
+    assert_eq!(update.nodes[0].0, button_id);
+    assert_eq!(update.nodes[0].1.role, Role::Button);
+    assert_eq!(update.nodes[0].1.name.as_deref(), Some("Pause"));
+}
@@ -15,7 +15,7 @@
 
 use corelib::api::EventLoopError;
 use corelib::graphics::euclid;
-use corelib::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use corelib::input::{KeyEventType, KeyInputEvent, MouseEvent, TouchEvent};
 use corelib::window::*;
 use corelib::Coord;
 use std::cell::{Cell, RefCell, RefMut};
@@ -49,6 +49,14 @@ fn input_method_focused(&self) -> bool {
     /// Returns true if request_redraw() was called since the last event loop iteration
     /// and resets the state back to false.
     fn take_pending_redraw(&self) -> bool;
+
+    /// Forward the window event to the AccessKit adapter of the window
+    #[cfg(enable_accesskit)]
+    fn accesskit_process_event(&self, _event: &WindowEvent) {}
+
+    /// Called when an assistive technology requests an action on the window
+    #[cfg(enable_accesskit)]
+    fn accesskit_action_request(&self, _request: accesskit::ActionRequest) {}
 }
 
 struct NotRunningEventLoop {
@@ -201,6 +209,16 @@ pub enum CustomEvent {
     /// Called from `GLWindow::hide` so that we can check if we should quit the event loop
     WindowHidden,
     Exit,
+    /// An action requested by an assistive technology through AccessKit
+    #[cfg(enable_accesskit)]
+    Accesskit(accesskit_winit::ActionRequestEvent),
+}
+
+#[cfg(enable_accesskit)]
+impl From<accesskit_winit::ActionRequestEvent> for CustomEvent {
+    fn from(event: accesskit_winit::ActionRequestEvent) -> Self {
+        Self::Accesskit(event)
+    }
 }
 
 impl std::fmt::Debug for CustomEvent {
@@ -212,6 +230,8 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::UserEvent(_) => write!(f, "UserEvent"),
             Self::WindowHidden => write!(f, "WindowHidden"),
             Self::Exit => write!(f, "Exit"),
+            #[cfg(enable_accesskit)]
+            Self::Accesskit(a) => write!(f, "AccessKit ActionRequest {:?}", a),
         }
     }
 }
@@ -238,6 +258,8 @@ fn process_window_event(
     pressed: &mut bool,
 ) {
     let runtime_window = WindowInner::from_pub(window.window());
+    #[cfg(enable_accesskit)]
+    window.accesskit_process_event(&event);
     match event {
         WindowEvent::Resized(size) => {
             window.resize_event(size);
@@ -279,6 +301,14 @@ fn process_window_event(
                 runtime_window.set_active(have_focus);
                 runtime_window.set_focus(have_focus);
             }
+            // A window that receives focus was restored by the user
+            if have_focus {
+                if let Some(window_item) = runtime_window.window_item() {
+                    if window_item.as_pin_ref().minimized() {
+                        window_item.minimized.set(false);
+                    }
+                }
+            }
         }
         WindowEvent::KeyboardInput { ref input, .. } => {
             // For now: Match Qt's behavior of mapping command to control and control to meta (LWin/RWin).
@@ -375,18 +405,48 @@ fn process_window_event(
         WindowEvent::Touch(touch) => {
             let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
             let position = euclid::point2(location.x, location.y);
+            let id = touch.id;
             let ev = match touch.phase {
                 winit::event::TouchPhase::Started => {
                     *pressed = true;
-                    MouseEvent::Pressed { position, button: PointerEventButton::Left }
+                    TouchEvent::Pressed { id, position }
                 }
                 winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
                     *pressed = false;
-                    MouseEvent::Released { position, button: PointerEventButton::Left }
+                    TouchEvent::Released { id, position }
                 }
-                winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
+                winit::event::TouchPhase::Moved => TouchEvent::Moved { id, position },
             };
-            runtime_window.process_mouse_input(ev);
+            runtime_window.process_touch_input(ev);
+        }
+        // FIXME: winit doesn't report the position of the dragged files, and doesn't send
+        // CursorMoved while they are dragged over the window, so cursor_pos is the last
+        // position of the mouse before the drag entered the window.
+        WindowEvent::HoveredFile(path) => {
+            let file =
+                window.window().register_dragged_file(path.to_string_lossy().as_ref().into());
+            window.window().dispatch_event(corelib::platform::WindowEvent::FileHovered {
+                file,
+                position: corelib::api::LogicalPosition::new(
+                    cursor_pos.x as f32,
+                    cursor_pos.y as f32,
+                ),
+            });
+        }
+        WindowEvent::DroppedFile(path) => {
+            // The files are registered again: winit doesn't send HoveredFile on all platforms.
+            let file =
+                window.window().register_dragged_file(path.to_string_lossy().as_ref().into());
+            window.window().dispatch_event(corelib::platform::WindowEvent::FileDropped {
+                file,
+                position: corelib::api::LogicalPosition::new(
+                    cursor_pos.x as f32,
+                    cursor_pos.y as f32,
+                ),
+            });
+        }
+        WindowEvent::HoveredFileCancelled => {
+            window.window().dispatch_event(corelib::platform::WindowEvent::FileHoverCancelled);
         }
         WindowEvent::ScaleFactorChanged { scale_factor, new_inner_size: size } => {
             if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -490,6 +550,16 @@ pub fn run() {
             user();
         }
 
+        #[cfg(enable_accesskit)]
+        Event::UserEvent(CustomEvent::Accesskit(accesskit_winit::ActionRequestEvent {
+            window_id,
+            request,
+        })) => {
+            if let Some(window) = window_by_id(window_id) {
+                window.accesskit_action_request(request);
+            }
+        }
+
         #[cfg(target_arch = "wasm32")]
         Event::UserEvent(CustomEvent::WakeEventLoopWorkaround) => {
             *control_flow = ControlFlow::Poll;
@@ -181,6 +181,21 @@ fn take_pending_redraw(&self) -> bool {
         self.pending_redraw.take()
     }
 
+    #[cfg(enable_accesskit)]
+    fn accesskit_process_event(&self, event: &winit::event::WindowEvent) {
+        if let Some(mapped_window) = self.borrow_mapped_window() {
+            mapped_window.accesskit_adapter.process_event(&mapped_window.winit_window, event);
+        }
+    }
+
+    #[cfg(enable_accesskit)]
+    fn accesskit_action_request(&self, request: accesskit::ActionRequest) {
+        let adapter = self.borrow_mapped_window().map(|w| w.accesskit_adapter.clone());
+        if let Some(adapter) = adapter {
+            adapter.handle_action_request(request);
+        }
+    }
+
     fn currently_pressed_key_code(&self) -> &Cell<Option<winit::event::VirtualKeyCode>> {
         &self.currently_pressed_key_code
     }
@@ -242,6 +257,21 @@ fn resize_event(&self, size: winit::dpi::PhysicalSize<u32>) {
         let physical_size = physical_size_to_slint(&size);
         self.window.set_size(physical_size);
         self.renderer.resize_event(physical_size);
+
+        // Keep the window state properties in sync with changes made by the user or the window manager
+        if let Some(window_item) = WindowInner::from_pub(&self.window).window_item() {
+            let window_item = window_item.as_pin_ref();
+            self.with_window_handle(&mut |winit_window| {
+                let full_screen = winit_window.fullscreen().is_some();
+                if window_item.full_screen() != full_screen {
+                    window_item.full_screen.set(full_screen);
+                }
+                let maximized = winit_window.is_maximized();
+                if window_item.maximized() != maximized {
+                    window_item.maximized.set(maximized);
+                }
+            });
+        }
     }
 }
 
@@ -285,6 +315,24 @@ fn apply_window_properties(&self, window_item: Pin<&i_slint_core::items::WindowI
         self.with_window_handle(&mut |winit_window| {
             winit_window.set_window_icon(icon_to_winit(window_item.icon()));
             winit_window.set_title(&window_item.title());
+
+            let full_screen = window_item.full_screen();
+            if full_screen != winit_window.fullscreen().is_some() {
+                winit_window.set_fullscreen(
+                    full_screen.then(|| winit::window::Fullscreen::Borderless(None)),
+                );
+            }
+            let maximized = window_item.maximized();
+            if maximized != winit_window.is_maximized() {
+                winit_window.set_maximized(maximized);
+            }
+            // winit can't query the minimized state, so remember what we last requested
+            let minimized = window_item.minimized();
+            if minimized != self.borrow_mapped_window().unwrap().minimized.replace(minimized) {
+                winit_window.set_minimized(minimized);
+            }
+            winit_window.set_always_on_top(window_item.always_on_top());
+
             winit_window
                 .set_decorations(!window_item.no_frame() || winit_window.fullscreen().is_some());
 
@@ -402,9 +450,12 @@ fn show(&self) {
                 window_builder
                     .with_title(window_item.title().to_string())
                     .with_resizable(
-                        window_item.height() <= LogicalLength::zero()
-                            || window_item.width() <= LogicalLength::zero(),
+                        window_item.resizable()
+                            && (window_item.height() <= LogicalLength::zero()
+                                || window_item.width() <= LogicalLength::zero()),
                     )
+                    .with_maximized(window_item.maximized())
+                    .with_always_on_top(window_item.always_on_top())
                     .with_decorations(!window_item.no_frame())
                     .with_window_icon(icon_to_winit(window_item.icon()))
             } else {
@@ -472,7 +523,18 @@ fn show(&self) {
                 }
             }
 
-            let window_builder = if std::env::var("SLINT_FULLSCREEN").is_ok() {
+            let full_screen = if std::env::var("SLINT_FULLSCREEN").is_ok() {
+                if let Some(window_item) = runtime_window.window_item() {
+                    window_item.full_screen.set(true);
+                }
+                true
+            } else {
+                runtime_window.window_item().map_or(false, |w| w.as_pin_ref().full_screen())
+            };
+            let minimized =
+                runtime_window.window_item().map_or(false, |w| w.as_pin_ref().minimized());
+
+            let window_builder = if full_screen {
                 window_builder.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)))
             } else {
                 if layout_info_h.min >= 1. || layout_info_v.min >= 1. {
@@ -533,10 +595,22 @@ fn show(&self) {
                 window_builder.with_canvas(Some(canvas.clone()))
             };
 
+            // AccessKit must be initialized before the window is shown for the first time
+            #[cfg(enable_accesskit)]
+            let window_builder = window_builder.with_visible(false);
+
             let winit_window = Rc::new(crate::event_loop::with_window_target(|event_loop| {
                 window_builder.build(event_loop.event_loop_target()).unwrap()
             }));
 
+            #[cfg(enable_accesskit)]
+            let accesskit_adapter = crate::accesskit::AccessKitAdapter::new(
+                self_.self_weak.clone() as _,
+                &winit_window,
+            );
+            #[cfg(enable_accesskit)]
+            winit_window.set_visible(true);
+
             self_.renderer.show(
                 &winit_window,
                 #[cfg(target_arch = "wasm32")]
@@ -551,11 +625,17 @@ fn show(&self) {
             if s.width > 0 as Coord && s.height > 0 as Coord {
                 winit_window.set_inner_size(s);
             }
+            if minimized {
+                winit_window.set_minimized(true);
+            }
             let id = winit_window.id();
 
             self_.map_state.replace(GraphicsWindowBackendState::Mapped(MappedWindow {
                 constraints: Default::default(),
+                minimized: Cell::new(minimized),
                 winit_window,
+                #[cfg(enable_accesskit)]
+                accesskit_adapter,
             }));
 
             crate::event_loop::register_window(id, self_.self_weak.upgrade().unwrap());
@@ -700,6 +780,18 @@ fn set_size(&self, size: corelib::api::WindowSize) {
         }
     }
 
+    #[cfg(enable_accesskit)]
+    fn handle_focus_change(
+        &self,
+        _old: Option<corelib::items::ItemRc>,
+        new: Option<corelib::items::ItemRc>,
+    ) {
+        let adapter = self.borrow_mapped_window().map(|w| w.accesskit_adapter.clone());
+        if let Some(adapter) = adapter {
+            adapter.handle_focus_change(new);
+        }
+    }
+
     fn dark_color_scheme(&self) -> bool {
         dark_light::detect() == dark_light::Mode::Dark
     }
@@ -721,7 +813,10 @@ fn drop(&mut self) {
 
 struct MappedWindow {
     constraints: Cell<(corelib::layout::LayoutInfo, corelib::layout::LayoutInfo)>,
+    minimized: Cell<bool>,
     winit_window: Rc<winit::window::Window>,
+    #[cfg(enable_accesskit)]
+    accesskit_adapter: Rc<crate::accesskit::AccessKitAdapter>,
 }
 
 enum GraphicsWindowBackendState {
@@ -15,6 +15,9 @@
 use glwindow::*;
 pub(crate) mod event_loop;
 
+#[cfg(enable_accesskit)]
+mod accesskit;
+
 mod renderer {
     use std::rc::{Rc, Weak};
 
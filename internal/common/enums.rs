@@ -262,8 +262,20 @@ enum AccessibleRole {
                 Spinbox,
                 /// The element is a Tab or behaves like one.
                 Tab,
+                /// The element is similar to the tab bar in a `TabWidget`.
+                TabList,
                 /// The role for a Text element. It is automatically applied.
                 Text,
+                /// The element is a list, such as a `ListView` or a `StandardListView`.
+                List,
+                /// The element is an item of a list.
+                ListItem,
+                /// The element is a table, such as a `StandardTableView`.
+                Table,
+                /// The element shows the progress of an operation, such as a progress bar or a spinner.
+                ProgressIndicator,
+                /// The role for an Image element. It is automatically applied.
+                Image,
             }
 
             /// This enum represents the different values of the `sort-order` property.
@@ -39,6 +39,13 @@ pub enum BuiltinFunction {
     Pow,
     SetFocusItem,
     ShowPopupWindow,
+    ClosePopupWindow,
+    /// `flickable.scroll-to(x, y)`: the arguments are the Flickable, and the position
+    FlickableScrollTo,
+    /// `flickable.ensure-visible(x, y, width, height)`: the arguments are the Flickable, and the rectangle
+    FlickableEnsureVisible,
+    /// `flickable.ensure-item-visible(index)`: the arguments are the Flickable, and the index in the repeater
+    FlickableEnsureItemVisible,
     /// the "42".to_float()
     StringToFloat,
     /// the "42".is_float()
@@ -53,6 +60,13 @@ pub enum BuiltinFunction {
     RegisterCustomFontByPath,
     RegisterCustomFontByMemory,
     RegisterBitmapFont,
+    /// `@tr(...)`: the arguments are the original string, the context, the domain, the array of
+    /// the arguments of the format string, the count `n` and the plural form
+    Translate,
+    /// A placeholder with a format specification in a string template (`"\{value:spec}"`).
+    /// The arguments are the value, the width, the precision (negative if not specified), and
+    /// whether to pad with zeros, to group the thousands, and to use the separators of the locale
+    FormatNumber,
 }
 
 #[derive(Debug, Clone)]
@@ -116,9 +130,29 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
             },
-            BuiltinFunction::ShowPopupWindow => Type::Function {
+            BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => {
+                Type::Function {
+                    return_type: Box::new(Type::Void),
+                    args: vec![Type::ElementReference],
+                }
+            }
+            BuiltinFunction::FlickableScrollTo => Type::Function {
                 return_type: Box::new(Type::Void),
-                args: vec![Type::ElementReference],
+                args: vec![Type::ElementReference, Type::LogicalLength, Type::LogicalLength],
+            },
+            BuiltinFunction::FlickableEnsureVisible => Type::Function {
+                return_type: Box::new(Type::Void),
+                args: vec![
+                    Type::ElementReference,
+                    Type::LogicalLength,
+                    Type::LogicalLength,
+                    Type::LogicalLength,
+                    Type::LogicalLength,
+                ],
+            },
+            BuiltinFunction::FlickableEnsureItemVisible => Type::Function {
+                return_type: Box::new(Type::Void),
+                args: vec![Type::ElementReference, Type::Int32],
             },
             BuiltinFunction::StringToFloat => {
                 Type::Function { return_type: Box::new(Type::Float32), args: vec![Type::String] }
@@ -169,6 +203,28 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::RegisterBitmapFont => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Int32] }
             }
+            BuiltinFunction::Translate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![
+                    Type::String,
+                    Type::String,
+                    Type::String,
+                    Type::Array(Type::String.into()),
+                    Type::Int32,
+                    Type::String,
+                ],
+            },
+            BuiltinFunction::FormatNumber => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![
+                    Type::Float32,
+                    Type::Int32,
+                    Type::Int32,
+                    Type::Bool,
+                    Type::Bool,
+                    Type::Bool,
+                ],
+            },
         }
     }
 
@@ -196,7 +252,10 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::Pow
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
-            BuiltinFunction::ShowPopupWindow => false,
+            BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::FlickableScrollTo
+            | BuiltinFunction::FlickableEnsureVisible
+            | BuiltinFunction::FlickableEnsureItemVisible => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
             BuiltinFunction::ColorBrighter | BuiltinFunction::ColorDarker => true,
             // ImageSize is pure, except when loading images via the network. Then the initial size will be 0/0 and
@@ -213,6 +272,10 @@ fn is_const(&self) -> bool {
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
+            // The translation depends on the current language
+            BuiltinFunction::Translate => false,
+            // The separators depend on the current language
+            BuiltinFunction::FormatNumber => false,
         }
     }
 
@@ -240,7 +303,10 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::Pow
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
-            BuiltinFunction::ShowPopupWindow => false,
+            BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::FlickableScrollTo
+            | BuiltinFunction::FlickableEnsureVisible
+            | BuiltinFunction::FlickableEnsureItemVisible => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
             BuiltinFunction::ColorBrighter | BuiltinFunction::ColorDarker => true,
             BuiltinFunction::ImageSize => true,
@@ -250,6 +316,8 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
+            BuiltinFunction::Translate => true,
+            BuiltinFunction::FormatNumber => true,
         }
     }
 }
@@ -747,6 +815,9 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::PathData(data) => match data {
                 Path::Elements(elements) => {
                     for element in elements {
+                        if let Some(repeated) = &element.repeated {
+                            visitor(&repeated.model)
+                        }
                         element.bindings.values().for_each(|binding| visitor(&binding.borrow()))
                     }
                 }
@@ -844,6 +915,9 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::PathData(data) => match data {
                 Path::Elements(elements) => {
                     for element in elements {
+                        if let Some(repeated) = &mut element.repeated {
+                            visitor(&mut repeated.model)
+                        }
                         element
                             .bindings
                             .values_mut()
@@ -930,9 +1004,10 @@ pub fn is_constant(&self) -> bool {
             Expression::Array { values, .. } => values.iter().all(Expression::is_constant),
             Expression::Struct { values, .. } => values.iter().all(|(_, v)| v.is_constant()),
             Expression::PathData(data) => match data {
-                Path::Elements(elements) => elements
-                    .iter()
-                    .all(|element| element.bindings.values().all(|v| v.borrow().is_constant())),
+                Path::Elements(elements) => elements.iter().all(|element| {
+                    element.repeated.is_none()
+                        && element.bindings.values().all(|v| v.borrow().is_constant())
+                }),
                 Path::Events(_, _) => true,
                 Path::Commands(_) => false,
             },
@@ -1380,6 +1455,22 @@ pub enum Path {
 pub struct PathElement {
     pub element_type: Rc<BuiltinElement>,
     pub bindings: BindingsMap,
+    /// Set when the element is repeated with `for` or `if`
+    pub repeated: Option<RepeatedPathElement>,
+}
+
+/// The model of a path element that is repeated with `for` or `if`.
+///
+/// The bindings of the element are evaluated for each row of the model, and they read the model data
+/// and the index with [`Expression::ReadLocalVariable`]
+#[derive(Debug, Clone)]
+pub struct RepeatedPathElement {
+    /// The model, or a boolean for `if`
+    pub model: Expression,
+    /// Name of the local variable that contains the model data
+    pub model_data_variable: String,
+    /// Name of the local variable that contains the index
+    pub index_variable: String,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -1126,6 +1126,38 @@ fn generate_item_tree(
         }),
     ));
 
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "accessibility_action".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ComponentRef component, uintptr_t index, const slint::cbindgen_private::AccessibilityAction *action) -> void"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "reinterpret_cast<const {}*>(component.instance)->accessibility_action(index, *action);",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "supported_accessibility_actions".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ComponentRef component, uintptr_t index) -> uint32_t"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "return reinterpret_cast<const {}*>(component.instance)->supported_accessibility_actions(index);",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Public,
         Declaration::Var(Var {
@@ -1141,8 +1173,8 @@ fn generate_item_tree(
         init: Some(format!(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree_component, \
                 get_item_tree, parent_node, subtree_index, layout_info, \
-                accessible_role, accessible_string_property, \
-                slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
+                accessible_role, accessible_string_property, accessibility_action, \
+                supported_accessibility_actions, slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
             item_tree_class_name
         )),
         ..Default::default()
@@ -1536,6 +1568,29 @@ fn generate_sub_component(
 
     init.extend(properties_init_code);
 
+    for (idx, (prop, expr)) in component.change_callbacks.iter().enumerate() {
+        let change_tracker = format!("change_tracker{}", idx);
+        user_init.push(format!(
+            "self->{change_tracker}.init(self,
+                [](auto self) {{
+                    return {prop}.get();
+                }},
+                []([[maybe_unused]] auto self, auto) {{
+                    {code};
+                }});",
+            prop = access_member(prop, &ctx),
+            code = compile_expression_wrap_return(&expr.borrow(), &ctx)
+        ));
+        target_struct.members.push((
+            field_access,
+            Declaration::Var(Var {
+                ty: "slint::private_api::ChangeTracker".into(),
+                name: change_tracker,
+                ..Default::default()
+            }),
+        ));
+    }
+
     user_init.extend(component.init_code.iter().map(|e| {
         let mut expr_str = compile_expression(&e.borrow(), &ctx);
         expr_str.push(';');
@@ -1587,6 +1642,7 @@ fn generate_sub_component(
     let mut accessible_function = |name: &str,
                                    signature: &str,
                                    forward_args: &str,
+                                   default_return: &str,
                                    code: Vec<String>| {
         let mut code = ["[[maybe_unused]] auto self = this;".into()]
             .into_iter()
@@ -1612,7 +1668,7 @@ fn generate_sub_component(
             }
             else_ = "} else ";
         }
-        code.push(format!("{else_}return {{}};"));
+        code.push(format!("{else_}return {default_return};"));
         target_struct.members.push((
             field_access,
             Declaration::Function(Function {
@@ -1626,29 +1682,81 @@ fn generate_sub_component(
 
     let mut accessible_role_cases = vec!["switch (index) {".into()];
     let mut accessible_string_cases = vec!["switch ((index << 8) | uintptr_t(what)) {".into()];
+    let mut accessibility_action_cases =
+        vec!["switch ((index << 8) | uintptr_t(action.tag)) {".into()];
+    let mut supported_accessibility_actions = BTreeMap::<usize, Vec<_>>::new();
     for ((index, what), expr) in &component.accessible_prop {
+        let has_args = matches!(&*expr.borrow(), llr::Expression::CallBackCall { arguments, .. } if !arguments.is_empty());
         let expr = compile_expression(&expr.borrow(), &ctx);
         if what == "Role" {
             accessible_role_cases.push(format!("    case {index}: return {expr};"));
+        } else if let Some(what) = what.strip_prefix("Action") {
+            let arg = if has_args {
+                // Only SetValue has an argument. cbindgen generates a union member in snake_case
+                assert_eq!(what, "SetValue");
+                "[[maybe_unused]] auto arg_0 = action.set_value._0; ".to_string()
+            } else {
+                String::new()
+            };
+            accessibility_action_cases.push(format!("    case ({index} << 8) | uintptr_t(slint::cbindgen_private::AccessibilityAction::Tag::{what}): {{ {arg}{expr}; return; }}"));
+            // KEEP IN SYNC with the constants of SupportedAccessibilityAction
+            let flag = match what {
+                "Default" => 1 << 0,
+                "Decrement" => 1 << 1,
+                "Increment" => 1 << 2,
+                "SetValue" => 1 << 3,
+                _ => panic!("Invalid accessibility action {what}"),
+            };
+            supported_accessibility_actions.entry(*index).or_default().push(format!("{flag}"));
         } else {
             accessible_string_cases.push(format!("    case ({index} << 8) | uintptr_t(slint::cbindgen_private::AccessibleStringProperty::{what}): return {expr};"));
         }
     }
     accessible_role_cases.push("}".into());
     accessible_string_cases.push("}".into());
+    accessibility_action_cases.push("}".into());
+
+    let mut supported_accessibility_actions_cases = vec!["switch (index) {".into()];
+    for sub in &component.sub_components {
+        // The actions of the root of the sub-component are merged with the ones set on the element
+        if let Some(actions) = supported_accessibility_actions.get_mut(&sub.index_in_tree) {
+            actions.push(format!("self->{}.supported_accessibility_actions(0)", ident(&sub.name)));
+        }
+    }
+    for (index, actions) in supported_accessibility_actions {
+        supported_accessibility_actions_cases
+            .push(format!("    case {index}: return {};", actions.join(" | ")));
+    }
+    supported_accessibility_actions_cases.push("}".into());
 
     accessible_function(
         "accessible_role",
         "(uintptr_t index) const -> slint::cbindgen_private::AccessibleRole",
         "",
+        "{}",
         accessible_role_cases,
     );
     accessible_function(
         "accessible_string_property",
         "(uintptr_t index, slint::cbindgen_private::AccessibleStringProperty what) const -> slint::SharedString",
         ", what",
+        "{}",
         accessible_string_cases,
     );
+    accessible_function(
+        "accessibility_action",
+        "(uintptr_t index, const slint::cbindgen_private::AccessibilityAction &action) const -> void",
+        ", action",
+        "",
+        accessibility_action_cases,
+    );
+    accessible_function(
+        "supported_accessibility_actions",
+        "(uintptr_t index) const -> uint32_t",
+        "",
+        "0",
+        supported_accessibility_actions_cases,
+    );
 
     if !children_visitor_cases.is_empty() {
         target_struct.members.push((
@@ -1795,6 +1903,32 @@ fn generate_repeated_component(
         ));
     }
 
+    if let Some(cell) = &repeated.grid_layout_cell {
+        let col = access_member(&cell.col, &ctx);
+        let row = access_member(&cell.row, &ctx);
+        let colspan = access_member(&cell.colspan, &ctx);
+        let rowspan = access_member(&cell.rowspan, &ctx);
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "grid_layout_data".into(),
+                signature: "(slint::cbindgen_private::Orientation o) const -> slint::cbindgen_private::GridLayoutCellData".to_owned(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    "bool h = o == slint::cbindgen_private::Orientation::Horizontal;".into(),
+                    format!("int col_or_row = h ? {}.get() : {}.get();", col, row),
+                    format!("int span = h ? {}.get() : {}.get();", colspan, rowspan),
+                    "slint::cbindgen_private::GridLayoutCellData cell;".into(),
+                    "cell.col_or_row = std::clamp(col_or_row, 0, 0xffff);".into(),
+                    "cell.span = std::clamp(span, 1, 0xffff);".into(),
+                    "cell.constraint = layout_info({&static_vtable, const_cast<void *>(static_cast<const void *>(this))}, o);".into(),
+                    "return cell;".into(),
+                ]),
+                ..Function::default()
+            }),
+        ));
+    }
+
     if let Some(index_prop) = repeated.index_prop {
         // Override default subtree_index function implementation
         let subtree_index_func = repeater_struct
@@ -2533,6 +2667,22 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             elements,
             *orientation,
             sub_expression,
+            ("BoxLayoutCellData", "box_layout_data"),
+            ctx,
+        ),
+        Expression::GridLayoutFunction {
+            cells_variable,
+            repeater_indices,
+            elements,
+            orientation,
+            sub_expression,
+        } => box_layout_function(
+            cells_variable,
+            repeater_indices.as_ref().map(String::as_str),
+            elements,
+            *orientation,
+            sub_expression,
+            ("GridLayoutCellData", "grid_layout_data"),
             ctx,
         ),
         Expression::ComputeDialogLayoutCells { cells_variable, roles, unsorted_cells } => {
@@ -2552,6 +2702,47 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 )
 
         }
+        Expression::RepeatedPathElements { elements } => {
+            let push_elements = elements.iter().map(|(element, repeated)| {
+                let (field_count, qualified_elem_type_name) = match element.ty(ctx) {
+                    Type::Struct { fields, name: Some(name), .. } => (fields.len(), name),
+                    _ => unreachable!(),
+                };
+                // Turn slint::private_api::PathLineTo into `LineTo`
+                let elem_type_name = qualified_elem_type_name.split("::").last().unwrap().strip_prefix("Path").unwrap();
+                let elem_init = if field_count > 0 { compile_expression(element, ctx) } else { String::new() };
+                let push = format!("elements.push_back(slint::private_api::PathElement::{}({}));", elem_type_name, elem_init);
+                match repeated {
+                    None => push,
+                    Some(repeated) if repeated.model.ty(ctx) == Type::Bool => {
+                        format!("if ({}) {{ {} }}", compile_expression(&repeated.model, ctx), push)
+                    }
+                    Some(repeated) => format!(
+                        r#"{{
+                            auto model = {model};
+                            (*model).track_row_count_changes();
+                            for (int row = 0; row < int((*model).row_count()); ++row) {{
+                                [[maybe_unused]] auto {data} = slint::private_api::access_array_index(model, row);
+                                [[maybe_unused]] auto {index} = row;
+                                {push}
+                            }}
+                        }}"#,
+                        model = compile_expression(&repeated.model, ctx),
+                        data = ident(&repeated.model_data_variable),
+                        index = ident(&repeated.index_variable),
+                        push = push,
+                    ),
+                }
+            });
+            format!(
+                r#"[&](){{
+                    std::vector<slint::private_api::PathElement> elements;
+                    {}
+                    return slint::private_api::PathData(elements.data(), elements.size());
+                }}()"#,
+                push_elements.join("\n")
+            )
+        }
     }
 }
 
@@ -2642,7 +2833,7 @@ fn compile_builtin_function_call(
             format!("{}.dark_color_scheme()", access_window_field(ctx))
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, close_requested, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2662,13 +2853,63 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
+                let close_on_click = compile_expression(close_on_click, ctx);
+                let close_requested = compile_expression(close_requested, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {{ {parent_component} }})"
+                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }}, {popup_index}, [self] {{ (void)self; {close_requested}; }})"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ClosePopupWindow => {
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window = access_window_field(ctx);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                format!("{window}.close_popup({{ {parent_item} }}, {popup_index})")
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableScrollTo => {
+            if let [llr::Expression::PropertyReference(pr), x, y] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                format!(
+                    "[&] {{ slint::cbindgen_private::ItemRc item_rc {{ {item_rc} }}; slint::cbindgen_private::slint_flickable_scroll_to(&item_rc, {x}, {y}); }}()"
+                )
+            } else {
+                panic!("internal error: invalid args to FlickableScrollTo {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableEnsureVisible => {
+            if let [llr::Expression::PropertyReference(pr), x, y, width, height] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                let width = compile_expression(width, ctx);
+                let height = compile_expression(height, ctx);
+                format!(
+                    "[&] {{ slint::cbindgen_private::ItemRc item_rc {{ {item_rc} }}; slint::cbindgen_private::slint_flickable_ensure_visible(&item_rc, {x}, {y}, {width}, {height}); }}()"
+                )
+            } else {
+                panic!("internal error: invalid args to FlickableEnsureVisible {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableEnsureItemVisible => {
+            if let [llr::Expression::PropertyReference(pr), index] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                let index = compile_expression(index, ctx);
+                format!(
+                    "[&] {{ int index = {index}; if (index >= 0) {{ slint::cbindgen_private::ItemRc item_rc {{ {item_rc} }}; slint::cbindgen_private::slint_flickable_ensure_item_visible(&item_rc, index); }} }}()"
+                )
+            } else {
+                panic!("internal error: invalid args to FlickableEnsureItemVisible {:?}", arguments)
+            }
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if let [llr::Expression::StringLiteral(path)] = arguments {
                 let window = access_window_field(ctx);
@@ -2693,6 +2934,36 @@ fn compile_builtin_function_call(
             // TODO
             "/*TODO: REGISTER FONT*/".into()
         }
+        BuiltinFunction::Translate => {
+            if let [original, context, domain, llr::Expression::Array { values, .. }, n, plural] =
+                arguments
+            {
+                let count = values.len();
+                let slice = if count == 0 {
+                    // std::array<T, 0>::data() may be null, but the Rust slice requires a non-null pointer
+                    "slint::cbindgen_private::Slice<slint::SharedString>{ reinterpret_cast<slint::SharedString*>(alignof(slint::SharedString)), 0 }".to_owned()
+                } else {
+                    let values = values
+                        .iter()
+                        .map(|v| format!("slint::SharedString({})", compile_expression(v, ctx)))
+                        .join(", ");
+                    format!("slint::cbindgen_private::Slice<slint::SharedString>{{ std::array<slint::SharedString, {count}>{{ {values} }}.data(), {count} }}")
+                };
+                format!(
+                    "slint::private_api::translate({}, {}, {}, {slice}, {}, {})",
+                    compile_expression(original, ctx),
+                    compile_expression(context, ctx),
+                    compile_expression(domain, ctx),
+                    compile_expression(n, ctx),
+                    compile_expression(plural, ctx)
+                )
+            } else {
+                panic!("internal error: invalid args to Translate {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FormatNumber => {
+            format!("slint::private_api::format_number({})", a.join(", "))
+        }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let native = native_item(pr, ctx);
@@ -2711,17 +2982,20 @@ fn compile_builtin_function_call(
     }
 }
 
+/// Also used for the grid layouts: `cell_type` is the type of the cells, and `cell_data_fn` is the
+/// function of the repeated components that returns the cell data
 fn box_layout_function(
     cells_variable: &str,
     repeated_indices: Option<&str>,
     elements: &[Either<llr::Expression, usize>],
     orientation: Orientation,
     sub_expression: &llr::Expression,
+    (cell_type, cell_data_fn): (&str, &str),
     ctx: &llr_EvaluationContext<String>,
 ) -> String {
     let repeated_indices = repeated_indices.map(ident);
     let mut push_code =
-        "std::vector<slint::cbindgen_private::BoxLayoutCellData> cells_vector;".to_owned();
+        format!("std::vector<slint::cbindgen_private::{}> cells_vector;", cell_type);
     let mut repeater_idx = 0usize;
 
     for item in elements {
@@ -2752,8 +3026,9 @@ fn box_layout_function(
                     push_code,
                     "if (self->repeater_{id}.inner) \
                         for (auto &&sub_comp : self->repeater_{id}.inner->data) \
-                           cells_vector.push_back((*sub_comp.ptr)->box_layout_data({o}));",
+                           cells_vector.push_back((*sub_comp.ptr)->{f}({o}));",
                     id = repeater,
+                    f = cell_data_fn,
                     o = to_cpp_orientation(orientation),
                 )
                 .unwrap();
@@ -2771,9 +3046,10 @@ fn box_layout_function(
         format!("std::array<int, {}> {}_array;", 2 * repeater_idx, ri)
     });
     format!(
-        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::BoxLayoutCellData>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
+        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::{}>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
         ri,
         push_code,
+        cell_type,
         ident(cells_variable),
         compile_expression(sub_expression, ctx)
     )
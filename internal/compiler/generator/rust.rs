@@ -770,10 +770,37 @@ fn generate_sub_component(
 
     let mut accessible_role_branch = vec![];
     let mut accessible_string_property_branch = vec![];
+    let mut accessibility_action_branch = vec![];
+    let mut supported_accessibility_actions = BTreeMap::<usize, Vec<_>>::new();
+    let mut supported_accessibility_actions_branch = vec![];
     for ((index, what), expr) in &component.accessible_prop {
+        let has_args = matches!(&*expr.borrow(), llr::Expression::CallBackCall { arguments, .. } if !arguments.is_empty());
         let expr = compile_expression(&expr.borrow(), &ctx);
         if what == "Role" {
             accessible_role_branch.push(quote!(#index => #expr,));
+        } else if let Some(what) = what.strip_prefix("Action") {
+            let what_ident = ident(what);
+            accessibility_action_branch.push(if has_args {
+                quote!((#index, AccessibilityAction::#what_ident(value)) => {
+                    let args = (value.clone(),);
+                    #expr;
+                })
+            } else {
+                quote!((#index, AccessibilityAction::#what_ident) => { #expr; })
+            });
+            // The constants of SupportedAccessibilityAction are in SCREAMING_SNAKE_CASE
+            let flag = what.chars().enumerate().fold(String::new(), |mut flag, (i, c)| {
+                if i > 0 && c.is_ascii_uppercase() {
+                    flag.push('_');
+                }
+                flag.push(c.to_ascii_uppercase());
+                flag
+            });
+            let flag = ident(&flag);
+            supported_accessibility_actions
+                .entry(*index)
+                .or_default()
+                .push(quote!(SupportedAccessibilityAction::#flag));
         } else {
             let what = ident(&what);
             accessible_string_property_branch
@@ -846,6 +873,12 @@ fn generate_sub_component(
         accessible_string_property_branch.push(quote!(
             (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).accessible_string_property(0, what),
         ));
+        accessibility_action_branch.push(quote!(
+            (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).accessibility_action(0, action),
+        ));
+        supported_accessibility_actions.entry(local_tree_index).or_default().push(quote!(
+            #sub_compo_field.apply_pin(_self).supported_accessibility_actions(0)
+        ));
         if sub_items_count > 1 {
             let range_begin = local_index_of_first_child as usize;
             let range_end = range_begin + sub_items_count - 2;
@@ -855,11 +888,20 @@ fn generate_sub_component(
             accessible_string_property_branch.push(quote!(
                 (#range_begin..=#range_end, _) => #sub_compo_field.apply_pin(_self).accessible_string_property(index - #range_begin + 1, what),
             ));
+            accessibility_action_branch.push(quote!(
+                (#range_begin..=#range_end, _) => #sub_compo_field.apply_pin(_self).accessibility_action(index - #range_begin + 1, action),
+            ));
+            supported_accessibility_actions_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(index - #range_begin + 1),
+            ));
         }
 
         sub_component_names.push(field_name);
         sub_component_types.push(sub_component_id);
     }
+    for (index, actions) in supported_accessibility_actions {
+        supported_accessibility_actions_branch.push(quote!(#index => #(#actions)|*,));
+    }
 
     for (prop1, prop2) in &component.two_way_bindings {
         let p1 = access_member(prop1, &ctx);
@@ -895,6 +937,27 @@ fn generate_sub_component(
         quote!(slint::private_unstable_api::re_exports::VWeakMapped::<slint::private_unstable_api::re_exports::ComponentVTable, #parent_component_id>)
     });
 
+    let mut change_tracker_names = vec![];
+    for (idx, (prop, expr)) in component.change_callbacks.iter().enumerate() {
+        let code = compile_expression(&expr.borrow(), &ctx);
+        let prop = access_member(prop, &ctx);
+        let change_tracker = format_ident!("change_tracker{}", idx);
+        user_init_code.push(quote! {
+            #[allow(unreachable_code, unused)]
+            slint::private_unstable_api::set_change_handler(&_self.#change_tracker, &self_rc,
+                |self_rc| {
+                    let _self = self_rc.as_pin_ref();
+                    #prop.get()
+                },
+                |self_rc, _| {
+                    let _self = self_rc.as_pin_ref();
+                    #code;
+                }
+            );
+        });
+        change_tracker_names.push(change_tracker);
+    }
+
     user_init_code.extend(component.init_code.iter().map(|e| {
         let code = compile_expression(&e.borrow(), &ctx);
         quote!(#code;)
@@ -931,6 +994,7 @@ struct #inner_component_id {
             #(#declared_property_vars : slint::private_unstable_api::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks : slint::private_unstable_api::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : slint::private_unstable_api::re_exports::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : slint::private_unstable_api::re_exports::ChangeTracker,)*
             self_weak : slint::private_unstable_api::re_exports::OnceCell<slint::private_unstable_api::re_exports::VWeakMapped<slint::private_unstable_api::re_exports::ComponentVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             // FIXME: Do we really need a window all the time?
@@ -1039,6 +1103,26 @@ fn accessible_string_property(
                 }
             }
 
+            fn accessibility_action(self: ::core::pin::Pin<&Self>, index: usize, action: &slint::private_unstable_api::re_exports::AccessibilityAction) {
+                #![allow(unused)]
+                use slint::private_unstable_api::re_exports::*;
+                let _self = self;
+                match (index, action) {
+                    #(#accessibility_action_branch)*
+                    _ => (),
+                }
+            }
+
+            fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: usize) -> slint::private_unstable_api::re_exports::SupportedAccessibilityAction {
+                #![allow(unused)]
+                use slint::private_unstable_api::re_exports::*;
+                let _self = self;
+                match index {
+                    #(#supported_accessibility_actions_branch)*
+                    _ => Default::default(),
+                }
+            }
+
             #(#declared_functions)*
         }
 
@@ -1423,6 +1507,14 @@ fn accessible_string_property(
             ) {
                 *result = self.accessible_string_property(index, what);
             }
+
+            fn accessibility_action(self: ::core::pin::Pin<&Self>, index: usize, action: &slint::private_unstable_api::re_exports::AccessibilityAction) {
+                self.accessibility_action(index, action);
+            }
+
+            fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: usize) -> slint::private_unstable_api::re_exports::SupportedAccessibilityAction {
+                self.supported_accessibility_actions(index)
+            }
         }
 
 
@@ -1489,6 +1581,30 @@ fn box_layout_data(self: ::core::pin::Pin<&Self>, o: slint::private_unstable_api
         }
     };
 
+    let grid_layout_fn = repeated.grid_layout_cell.as_ref().map(|cell| {
+        let col = access_member(&cell.col, &ctx);
+        let row = access_member(&cell.row, &ctx);
+        let colspan = access_member(&cell.colspan, &ctx);
+        let rowspan = access_member(&cell.rowspan, &ctx);
+        quote! {
+            fn grid_layout_data(self: ::core::pin::Pin<&Self>, o: slint::private_unstable_api::re_exports::Orientation)
+                -> slint::private_unstable_api::re_exports::GridLayoutCellData
+            {
+                use slint::private_unstable_api::re_exports::*;
+                let _self = self;
+                let (col_or_row, span) = match o {
+                    Orientation::Horizontal => (#col.get(), #colspan.get()),
+                    Orientation::Vertical => (#row.get(), #rowspan.get()),
+                };
+                GridLayoutCellData {
+                    col_or_row: col_or_row.clamp(0, u16::MAX as _) as u16,
+                    span: span.clamp(1, u16::MAX as _) as u16,
+                    constraint: self.as_ref().layout_info(o),
+                }
+            }
+        }
+    });
+
     let data_type = if let Some(data_prop) = repeated.data_prop {
         rust_primitive_type(&repeated.sub_tree.root.properties[data_prop].ty).unwrap()
     } else {
@@ -1530,6 +1646,7 @@ fn init(&self) {
                 );
             }
             #extra_fn
+            #grid_layout_fn
         }
     )
 }
@@ -2153,6 +2270,22 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             elements,
             *orientation,
             sub_expression,
+            quote!(box_layout_data),
+            ctx,
+        ),
+        Expression::GridLayoutFunction {
+            cells_variable,
+            repeater_indices,
+            elements,
+            orientation,
+            sub_expression,
+        } => box_layout_function(
+            cells_variable,
+            repeater_indices.as_ref().map(String::as_str),
+            elements,
+            *orientation,
+            sub_expression,
+            quote!(grid_layout_data),
             ctx,
         ),
         Expression::ComputeDialogLayoutCells { cells_variable, roles, unsorted_cells } => {
@@ -2170,6 +2303,46 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 let #cells_variable = slint::private_unstable_api::re_exports::Slice::from_slice(&#cells_variable);
             }
         }
+        Expression::RepeatedPathElements { elements } => {
+            let push_elements = elements.iter().map(|(element, repeated)| {
+                // Same as in the cast from array to PathData: Close{} is converted from the unit type
+                let element =
+                    if matches!(element, Expression::Struct { ty: Type::Struct { fields, .. }, .. } if fields.is_empty()) {
+                        TokenStream::default()
+                    } else {
+                        compile_expression(element, ctx)
+                    };
+                let push = quote!(elements.push((#element).into()););
+                match repeated {
+                    None => push,
+                    Some(repeated) if repeated.model.ty(ctx) == Type::Bool => {
+                        let model = compile_expression(&repeated.model, ctx);
+                        quote!(if #model { #push })
+                    }
+                    Some(repeated) => {
+                        let model = compile_expression(&repeated.model, ctx);
+                        let model_data = ident(&repeated.model_data_variable);
+                        let index = ident(&repeated.index_variable);
+                        quote!({
+                            let model = #model;
+                            model.model_tracker().track_row_count_changes();
+                            for row in 0..model.row_count() {
+                                #[allow(unused_variables)]
+                                let #model_data = model.row_data_tracked(row).unwrap_or_default();
+                                #[allow(unused_variables)]
+                                let #index = row as i32;
+                                #push
+                            }
+                        })
+                    }
+                }
+            });
+            quote!({
+                let mut elements = slint::private_unstable_api::re_exports::SharedVector::<slint::private_unstable_api::re_exports::PathElement>::default();
+                #(#push_elements)*
+                slint::private_unstable_api::re_exports::PathData::Elements(elements)
+            })
+        }
     }
 }
 
@@ -2192,7 +2365,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, close_requested, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2211,16 +2384,90 @@ fn compile_builtin_function_call(
                 let parent_component = access_item_rc(parent_ref, ctx);
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
+                let close_on_click = compile_expression(close_on_click, ctx);
+                let close_requested = compile_expression(close_requested, ctx);
+                let popup_index_usize = *popup_index as usize;
                 let window_adapter_tokens = access_window_adapter_field(ctx);
-                quote!(
-                    slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
+                quote!({
+                    let window_inner = slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window());
+                    window_inner.show_popup(
                         &VRc::into_dyn(#popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).into()),
                         Point::new(#x as slint::private_unstable_api::re_exports::Coord, #y as slint::private_unstable_api::re_exports::Coord),
-                        #parent_component
+                        #close_on_click,
+                        #parent_component,
+                        #popup_index_usize,
+                    );
+                    let self_weak = _self.self_weak.get().unwrap().clone();
+                    window_inner.on_popup_close_requested(move || {
+                        if let Some(self_rc) = self_weak.upgrade() {
+                            let _self = self_rc.as_pin_ref();
+                            #close_requested;
+                        }
+                    });
+                })
+            } else {
+                panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::ClosePopupWindow => {
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let popup_index = *popup_index as usize;
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!(slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup_if_active(#parent_item, #popup_index))
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableScrollTo => {
+            if let [Expression::PropertyReference(pr), x, y] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                quote!(
+                    #item.scroll_to(LogicalLength::new(#x as _), LogicalLength::new(#y as _), #item_rc)
+                )
+            } else {
+                panic!("internal error: invalid args to FlickableScrollTo {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableEnsureVisible => {
+            if let [Expression::PropertyReference(pr), x, y, width, height] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                let width = compile_expression(width, ctx);
+                let height = compile_expression(height, ctx);
+                quote!(
+                    #item.ensure_visible(
+                        LogicalRect::new(
+                            LogicalPoint::new(#x as _, #y as _),
+                            LogicalSize::new(#width as _, #height as _),
+                        ),
+                        #item_rc
                     )
                 )
             } else {
-                panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
+                panic!("internal error: invalid args to FlickableEnsureVisible {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FlickableEnsureItemVisible => {
+            if let [Expression::PropertyReference(pr), index] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let index = compile_expression(index, ctx);
+                quote!({
+                    let index: i32 = (#index) as _;
+                    if index >= 0 {
+                        #item.ensure_item_visible(index as usize, #item_rc)
+                    }
+                })
+            } else {
+                panic!("internal error: invalid args to FlickableEnsureItemVisible {:?}", arguments)
             }
         }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
@@ -2262,6 +2509,48 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to RegisterBitmapFont must be a number")
             }
         }
+        BuiltinFunction::Translate => {
+            if let [original, context, domain, Expression::Array { values, .. }, n, plural] =
+                arguments
+            {
+                let original = compile_expression(original, ctx);
+                let context = compile_expression(context, ctx);
+                let domain = compile_expression(domain, ctx);
+                let values = values.iter().map(|v| compile_expression(v, ctx));
+                let n = compile_expression(n, ctx);
+                let plural = compile_expression(plural, ctx);
+                quote!(slint::private_unstable_api::translate(
+                    &#original,
+                    &#context,
+                    &#domain,
+                    &[#(slint::private_unstable_api::re_exports::SharedString::from(#values)),*],
+                    (#n) as i32,
+                    &#plural
+                ))
+            } else {
+                panic!("internal error: invalid args to Translate {:?}", arguments)
+            }
+        }
+        BuiltinFunction::FormatNumber => {
+            if let [value, width, precision, zero_pad, grouping, localized] = arguments {
+                let value = compile_expression(value, ctx);
+                let width = compile_expression(width, ctx);
+                let precision = compile_expression(precision, ctx);
+                let zero_pad = compile_expression(zero_pad, ctx);
+                let grouping = compile_expression(grouping, ctx);
+                let localized = compile_expression(localized, ctx);
+                quote!(slint::private_unstable_api::format_number(
+                    (#value) as f64,
+                    (#width) as i32,
+                    (#precision) as i32,
+                    #zero_pad,
+                    #grouping,
+                    #localized
+                ))
+            } else {
+                panic!("internal error: invalid args to FormatNumber {:?}", arguments)
+            }
+        }
         BuiltinFunction::GetWindowScaleFactor => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(slint::private_unstable_api::re_exports::WindowInner::from_pub(#window_adapter_tokens.window()).scale_factor())
@@ -2349,12 +2638,15 @@ fn struct_name_to_tokens(name: &str) -> TokenStream {
     name.parse().unwrap()
 }
 
+/// Also used for the grid layouts: `cell_data_fn` is the function of the repeated components that
+/// returns the cell data
 fn box_layout_function(
     cells_variable: &str,
     repeated_indices: Option<&str>,
     elements: &[Either<Expression, usize>],
     orientation: Orientation,
     sub_expression: &Expression,
+    cell_data_fn: TokenStream,
     ctx: &EvaluationContext,
 ) -> TokenStream {
     let repeated_indices = repeated_indices.map(ident);
@@ -2390,7 +2682,7 @@ fn box_layout_function(
                         let internal_vec = _self.#repeater_id.components_vec();
                         #ri
                         for sub_comp in &internal_vec {
-                            items_vec.push(sub_comp.as_pin_ref().box_layout_data(#orientation))
+                            items_vec.push(sub_comp.as_pin_ref().#cell_data_fn(#orientation))
                         }
                     ));
             }
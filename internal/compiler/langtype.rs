@@ -346,11 +346,18 @@ pub struct BuiltinPropertyInfo {
     /// When set, this is the initial value that we will have to set if no other binding were specified
     pub default_value: Option<Expression>,
     pub property_visibility: PropertyVisibility,
+    /// The documentation comment of the property in builtins.slint
+    pub documentation: Option<String>,
 }
 
 impl BuiltinPropertyInfo {
     pub fn new(ty: Type) -> Self {
-        Self { ty, default_value: None, property_visibility: PropertyVisibility::InOut }
+        Self {
+            ty,
+            default_value: None,
+            property_visibility: PropertyVisibility::InOut,
+            documentation: None,
+        }
     }
 
     pub fn is_native_output(&self) -> bool {
@@ -681,6 +688,8 @@ pub struct BuiltinElement {
     pub default_size_binding: DefaultSizeBinding,
     /// When true this is an internal type not shown in the auto-completion
     pub is_internal: bool,
+    /// The documentation comment of the element in builtins.slint
+    pub documentation: Option<String>,
 }
 
 impl BuiltinElement {
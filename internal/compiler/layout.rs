@@ -264,6 +264,9 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
 }
 
 /// An element in a GridLayout
+///
+/// For repeated elements, the position of each instance is in the [`GridLayoutCell`] of the
+/// repeated component instead.
 #[derive(Debug, Clone)]
 pub struct GridLayoutElement {
     pub col: u16,
@@ -282,6 +285,35 @@ pub fn col_or_row_and_span(&self, orientation: Orientation) -> (u16, u16) {
     }
 }
 
+/// The properties that contain the position of the root element of a repeated component
+/// that is in a GridLayout. (These properties are declared in that component)
+#[derive(Debug, Clone)]
+pub struct GridLayoutCell {
+    pub col: NamedReference,
+    pub row: NamedReference,
+    pub colspan: NamedReference,
+    pub rowspan: NamedReference,
+}
+
+impl GridLayoutCell {
+    pub fn col_or_row_and_span(
+        &self,
+        orientation: Orientation,
+    ) -> (&NamedReference, &NamedReference) {
+        match orientation {
+            Orientation::Horizontal => (&self.col, &self.colspan),
+            Orientation::Vertical => (&self.row, &self.rowspan),
+        }
+    }
+
+    pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        visitor(&mut self.col);
+        visitor(&mut self.row);
+        visitor(&mut self.colspan);
+        visitor(&mut self.rowspan);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Padding {
     pub left: Option<NamedReference>,
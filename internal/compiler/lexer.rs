@@ -87,6 +87,9 @@ pub fn lex_comment(text: &str, _: &mut LexState) -> usize {
 }
 
 pub fn lex_string(text: &str, state: &mut LexState) -> usize {
+    if let Some(len) = lex_raw_string(text) {
+        return len;
+    }
     if let Some(brace_level) = state.template_string_stack.last_mut() {
         if text.starts_with('{') {
             *brace_level += 1;
@@ -132,6 +135,20 @@ pub fn lex_string(text: &str, state: &mut LexState) -> usize {
     }
 }
 
+/// Lex a raw string literal such as `r"..."` or `r#"..."#`.
+/// Returns None if the text does not start with a raw string literal
+fn lex_raw_string(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('r')?;
+    let hashes = rest.bytes().take_while(|c| *c == b'#').count();
+    if rest.as_bytes().get(hashes) != Some(&b'"') {
+        return None;
+    }
+    let start = hashes + 2;
+    let terminator = format!("\"{}", "#".repeat(hashes));
+    // FIXME: report an error for unterminated string
+    Some(text[start..].find(&terminator).map_or(0, |pos| start + pos + terminator.len()))
+}
+
 pub fn lex_number(text: &str, _: &mut LexState) -> usize {
     let mut len = 0;
     let mut chars = text.chars();
@@ -308,6 +325,16 @@ fn compare(source: &str, expected: &[(crate::parser::SyntaxKind, &str)]) {
             (crate::parser::SyntaxKind::Identifier, "j"),
         ],
     );
+    compare(
+        "r\"a\\{b\"r##\"c\"#d\"##r#x",
+        &[
+            (crate::parser::SyntaxKind::StringLiteral, "r\"a\\{b\""),
+            (crate::parser::SyntaxKind::StringLiteral, "r##\"c\"#d\"##"),
+            (crate::parser::SyntaxKind::Identifier, "r"),
+            (crate::parser::SyntaxKind::ColorLiteral, "#x"),
+        ],
+    );
+    compare("\"a\nb\\\nc\"", &[(crate::parser::SyntaxKind::StringLiteral, "\"a\nb\\\nc\"")]);
 
     // Fuzzer tests:
     compare(
@@ -57,9 +57,19 @@ fn test_parse_color_literal() {
 }
 
 pub fn unescape_string(string: &str) -> Option<String> {
-    if string.contains('\n') {
-        // FIXME: new line in string literal not yet supported
-        return None;
+    // The line breaks of multi-line strings are always '\n', regardless of the source file
+    let normalized;
+    let string = if string.contains("\r\n") {
+        normalized = string.replace("\r\n", "\n");
+        normalized.as_str()
+    } else {
+        string
+    };
+    if let Some(raw) = string.strip_prefix('r') {
+        // Raw string: r"..." or r#"..."#, without escape sequences
+        let hashes = &raw[..raw.bytes().take_while(|c| *c == b'#').count()];
+        let raw = raw[hashes.len()..].strip_prefix('"')?.strip_suffix(hashes)?;
+        return Some(raw.strip_suffix('"')?.into());
     }
     let string = string.strip_prefix('"').or_else(|| string.strip_prefix('}'))?;
     let string = string.strip_suffix('"').or_else(|| string.strip_suffix("\\{"))?;
@@ -85,6 +95,8 @@ pub fn unescape_string(string: &str) -> Option<String> {
             b'"' => result += "\"",
             b'\\' => result += "\\",
             b'n' => result += "\n",
+            // A backslash at the end of a line skips the line break and the indentation
+            b'\n' => pos += string[pos..].len() - string[pos..].trim_start().len(),
             b'u' => {
                 if string.as_bytes().get(pos)? != &b'{' {
                     return None;
@@ -121,6 +133,86 @@ fn test_unescape_string() {
     assert_eq!(unescape_string(r#""xxx\u{22""#), None);
     assert_eq!(unescape_string(r#""xxx\u{qsdf}""#), None);
     assert_eq!(unescape_string(r#""xxx\u{1234567890}""#), None);
+    assert_eq!(unescape_string("\"foo\nbar\""), Some("foo\nbar".into()));
+    assert_eq!(unescape_string("\"foo\r\n  bar\""), Some("foo\n  bar".into()));
+    assert_eq!(unescape_string("\"foo \\\n    bar\""), Some("foo bar".into()));
+    assert_eq!(unescape_string("\"foo \\\r\n\n  bar\""), Some("foo bar".into()));
+    assert_eq!(unescape_string(r#"r"fo\no""#), Some(r"fo\no".into()));
+    assert_eq!(unescape_string(r#"r"a\{b}""#), Some(r"a\{b}".into()));
+    assert_eq!(unescape_string(r###"r##"a"#b"##"###), Some(r##"a"#b"##.into()));
+    assert_eq!(unescape_string("r\"a\nb\""), Some("a\nb".into()));
+    assert_eq!(unescape_string(r###"r#"a""###), None);
+    assert_eq!(unescape_string(r#"r"a"#), None);
+}
+
+/// The format specification of a placeholder in a string template, such as `"\{value:08,.2L}"`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberFormatSpec {
+    /// The minimum number of characters
+    pub width: u8,
+    /// The number of digits after the decimal mark. None for the shortest representation
+    pub precision: Option<u8>,
+    /// Pad with zeros instead of spaces
+    pub zero_pad: bool,
+    /// Separate the thousands
+    pub grouping: bool,
+    /// Use the decimal mark and the group separator of the current locale
+    pub localized: bool,
+}
+
+/// Parse the format specification of a placeholder in a string template (without the `:`).
+/// The syntax is `[0][width][,][.precision][L]`
+pub fn parse_number_format_spec(spec: &str) -> Option<NumberFormatSpec> {
+    fn parse_digits(s: &str) -> (&str, &str) {
+        s.split_at(s.bytes().take_while(u8::is_ascii_digit).count())
+    }
+    let mut result = NumberFormatSpec::default();
+    let mut spec = spec;
+    if let Some(rest) = spec.strip_prefix('0') {
+        result.zero_pad = true;
+        spec = rest;
+    }
+    let (width, rest) = parse_digits(spec);
+    if !width.is_empty() {
+        result.width = width.parse().ok()?;
+    }
+    spec = rest;
+    if let Some(rest) = spec.strip_prefix(',') {
+        result.grouping = true;
+        spec = rest;
+    }
+    if let Some(rest) = spec.strip_prefix('.') {
+        let (precision, rest) = parse_digits(rest);
+        result.precision = Some(precision.parse().ok()?);
+        spec = rest;
+    }
+    if let Some(rest) = spec.strip_prefix('L') {
+        result.localized = true;
+        spec = rest;
+    }
+    spec.is_empty().then_some(result)
+}
+
+#[test]
+fn test_parse_number_format_spec() {
+    let spec = |width, precision, zero_pad, grouping, localized| {
+        Some(NumberFormatSpec { width, precision, zero_pad, grouping, localized })
+    };
+    assert_eq!(parse_number_format_spec(""), spec(0, None, false, false, false));
+    assert_eq!(parse_number_format_spec(".2"), spec(0, Some(2), false, false, false));
+    assert_eq!(parse_number_format_spec("8"), spec(8, None, false, false, false));
+    assert_eq!(parse_number_format_spec("08.3"), spec(8, Some(3), true, false, false));
+    assert_eq!(parse_number_format_spec("03"), spec(3, None, true, false, false));
+    assert_eq!(parse_number_format_spec(","), spec(0, None, false, true, false));
+    assert_eq!(parse_number_format_spec("12,.0L"), spec(12, Some(0), false, true, true));
+    assert_eq!(parse_number_format_spec("L"), spec(0, None, false, false, true));
+    assert_eq!(parse_number_format_spec("."), None);
+    assert_eq!(parse_number_format_spec(".x"), None);
+    assert_eq!(parse_number_format_spec("2.2.2"), None);
+    assert_eq!(parse_number_format_spec("L,"), None);
+    assert_eq!(parse_number_format_spec("300"), None);
+    assert_eq!(parse_number_format_spec(" 2"), None);
+    assert_eq!(parse_number_format_spec("2px"), None);
 }
 
 pub fn parse_number_literal(s: String) -> Result<Expression, String> {
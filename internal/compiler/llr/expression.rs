@@ -172,6 +172,17 @@ pub enum Expression {
         orientation: Orientation,
         sub_expression: Box<Expression>,
     },
+    /// Same as [`Self::BoxLayoutFunction`], but the cells are GridLayoutCellData
+    GridLayoutFunction {
+        /// The local variable (as read with [`Self::ReadLocalVariable`]) that contains the cells
+        cells_variable: String,
+        /// The name for the local variable that contains the repeater indices
+        repeater_indices: Option<String>,
+        /// Either an expression of type GridLayoutCellData, or an index to the repeater
+        elements: Vec<Either<Expression, usize>>,
+        orientation: Orientation,
+        sub_expression: Box<Expression>,
+    },
 
     ComputeDialogLayoutCells {
         /// The local variable where the slice of cells is going to be stored
@@ -180,6 +191,24 @@ pub enum Expression {
         /// This is an Expression::Array
         unsorted_cells: Box<Expression>,
     },
+
+    /// The PathData of a path with elements that are repeated with `for` or `if`
+    RepeatedPathElements {
+        /// The struct for each path element (named after the path element type, like in the array
+        /// casted to PathData), and its model if it is repeated
+        elements: Vec<(Expression, Option<RepeatedPathElement>)>,
+    },
+}
+
+/// The model of a path element in [`Expression::RepeatedPathElements`]
+#[derive(Debug, Clone)]
+pub struct RepeatedPathElement {
+    /// The model, or a boolean for `if`
+    pub model: Expression,
+    /// The local variable that contains the model data when evaluating the element
+    pub model_data_variable: String,
+    /// The local variable that contains the index when evaluating the element
+    pub index_variable: String,
 }
 
 impl Expression {
@@ -288,10 +317,12 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::ReturnStatement(_) => Type::Invalid,
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
-            Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
+            Self::BoxLayoutFunction { sub_expression, .. }
+            | Self::GridLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
             Self::ComputeDialogLayoutCells { .. } => {
                 Type::Array(super::lower_expression::grid_layout_cell_data_ty().into())
             }
+            Self::RepeatedPathElements { .. } => Type::PathData,
         }
     }
 }
@@ -366,7 +397,8 @@ macro_rules! visit_impl {
                     $visitor(repeater_index);
                 }
             }
-            Expression::BoxLayoutFunction { elements, sub_expression, .. } => {
+            Expression::BoxLayoutFunction { elements, sub_expression, .. }
+            | Expression::GridLayoutFunction { elements, sub_expression, .. } => {
                 $visitor(sub_expression);
                 elements.$iter().filter_map(|x| x.$as_ref().left()).for_each($visitor);
             }
@@ -374,6 +406,14 @@ macro_rules! visit_impl {
                 $visitor(roles);
                 $visitor(unsorted_cells);
             }
+            Expression::RepeatedPathElements { elements } => {
+                for (element, repeated) in elements.$iter() {
+                    if let Some(RepeatedPathElement { model, .. }) = repeated {
+                        $visitor(model);
+                    }
+                    $visitor(element);
+                }
+            }
         }
     };
 }
@@ -127,6 +127,29 @@ pub struct ListViewInfo {
     pub prop_height: PropertyReference,
 }
 
+/// The properties that contain the position of a repeated component in a GridLayout
+///
+/// The property references are in the repeated's component context
+#[derive(Debug)]
+pub struct GridLayoutCellInfo {
+    pub col: PropertyReference,
+    pub row: PropertyReference,
+    pub colspan: PropertyReference,
+    pub rowspan: PropertyReference,
+}
+
+impl GridLayoutCellInfo {
+    pub fn col_or_row_and_span(
+        &self,
+        orientation: crate::layout::Orientation,
+    ) -> (&PropertyReference, &PropertyReference) {
+        match orientation {
+            crate::layout::Orientation::Horizontal => (&self.col, &self.colspan),
+            crate::layout::Orientation::Vertical => (&self.row, &self.rowspan),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct RepeatedElement {
     pub model: MutExpression,
@@ -139,6 +162,8 @@ pub struct RepeatedElement {
     pub index_in_tree: usize,
 
     pub listview: Option<ListViewInfo>,
+    /// Set when the repeated element is in a GridLayout
+    pub grid_layout_cell: Option<GridLayoutCellInfo>,
 }
 
 pub struct Item {
@@ -234,6 +259,8 @@ pub struct SubComponent {
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<MutExpression>,
+    /// The `changed` callbacks: the code to run when the value of the property changes
+    pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
 
     pub layout_info_h: MutExpression,
     pub layout_info_v: MutExpression,
@@ -349,6 +376,9 @@ pub fn for_each_expression<'a>(
             for e in &sc.init_code {
                 visitor(e, ctx);
             }
+            for (_, e) in &sc.change_callbacks {
+                visitor(e, ctx);
+            }
             for (_, e) in &sc.property_init {
                 visitor(&e.expression, ctx);
             }
@@ -15,7 +15,7 @@
 use crate::layout::Orientation;
 use crate::llr::Expression as llr_Expression;
 use crate::namedreference::NamedReference;
-use crate::object_tree::{Element, ElementRc, PropertyAnimation};
+use crate::object_tree::{Component, Element, ElementRc, PropertyAnimation};
 
 pub struct ExpressionContext<'a> {
     pub component: &'a Rc<crate::object_tree::Component>,
@@ -116,6 +116,9 @@ pub fn lower_expression(
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ShowPopupWindow, _) => {
                 lower_show_popup(arguments, ctx)
             }
+            tree_Expression::BuiltinFunctionReference(BuiltinFunction::ClosePopupWindow, _) => {
+                lower_close_popup(arguments, ctx)
+            }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let arguments = arguments.iter().map(|e| lower_expression(e, ctx)).collect::<_>();
                 llr_Expression::BuiltinFunctionCall { function: *f, arguments }
@@ -329,39 +332,75 @@ fn repeater_special_property(
     llr_Expression::PropertyReference(r)
 }
 
+/// Returns the component that contains the popup whose root element is `popup_window`, and the
+/// index of the popup in the popup windows of that component
+fn find_popup(popup_window: &ElementRc) -> (Rc<Component>, usize) {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_component =
+        pop_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+    let popup_index = parent_component
+        .popup_windows
+        .borrow()
+        .iter()
+        .position(|p| Rc::ptr_eq(&p.component, &pop_comp))
+        .unwrap();
+    (parent_component, popup_index)
+}
+
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
-        let popup_window = e.upgrade().unwrap();
-        let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-        let parent_component = pop_comp
-            .parent_element
-            .upgrade()
-            .unwrap()
-            .borrow()
-            .enclosing_component
-            .upgrade()
-            .unwrap();
+        let (parent_component, popup_index) = find_popup(&e.upgrade().unwrap());
         let popup_list = parent_component.popup_windows.borrow();
-        let (popup_index, popup) = popup_list
-            .iter()
-            .enumerate()
-            .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
-            .unwrap();
+        let popup = &popup_list[popup_index];
         let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
         let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
+        let close_on_click =
+            llr_Expression::PropertyReference(ctx.map_property_reference(&popup.close_on_click));
+        // Not a PropertyReference, so that it is not inlined: it is invoked later
+        let close_requested = llr_Expression::CallBackCall {
+            callback: ctx.map_property_reference(&popup.close_requested),
+            arguments: vec![],
+        };
         let item_ref = lower_expression(
             &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
             ctx,
         );
         llr_Expression::BuiltinFunctionCall {
             function: BuiltinFunction::ShowPopupWindow,
-            arguments: vec![llr_Expression::NumberLiteral(popup_index as _), x, y, item_ref],
+            arguments: vec![
+                llr_Expression::NumberLiteral(popup_index as _),
+                x,
+                y,
+                close_on_click,
+                close_requested,
+                item_ref,
+            ],
         }
     } else {
         panic!("invalid arguments to ShowPopupWindow");
     }
 }
 
+fn lower_close_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        let (parent_component, popup_index) = find_popup(&e.upgrade().unwrap());
+        let parent_element =
+            parent_component.popup_windows.borrow()[popup_index].parent_element.clone();
+        // The popup is identified by its index and the item it is shown for, so that closing it
+        // doesn't close another popup that is shown instead
+        let item_ref = lower_expression(
+            &tree_Expression::ElementReference(Rc::downgrade(&parent_element)),
+            ctx,
+        );
+        llr_Expression::BuiltinFunctionCall {
+            function: BuiltinFunction::ClosePopupWindow,
+            arguments: vec![llr_Expression::NumberLiteral(popup_index as _), item_ref],
+        }
+    } else {
+        panic!("invalid arguments to ClosePopupWindow");
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
@@ -459,11 +498,21 @@ fn compute_layout_info(
     match l {
         crate::layout::Layout::GridLayout(layout) => {
             let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
-            let cells = grid_layout_cell_data(layout, o, ctx);
-            llr_Expression::ExtraBuiltinFunctionCall {
+            let gld = grid_layout_cell_data(layout, o, ctx);
+            let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
                 function: "grid_layout_info".into(),
-                arguments: vec![cells, spacing, padding],
+                arguments: vec![gld.cells, spacing, padding],
                 return_ty: crate::layout::layout_info_type(),
+            };
+            match gld.compute_cells {
+                Some((cells_variable, elements)) => llr_Expression::GridLayoutFunction {
+                    cells_variable,
+                    repeater_indices: None,
+                    elements,
+                    orientation: o,
+                    sub_expression: Box::new(sub_expression),
+                },
+                None => sub_expression,
             }
         }
         crate::layout::Layout::BoxLayout(layout) => {
@@ -504,8 +553,14 @@ fn solve_layout(
     match l {
         crate::layout::Layout::GridLayout(layout) => {
             let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
-            let cells = grid_layout_cell_data(layout, o, ctx);
+            let gld = grid_layout_cell_data(layout, o, ctx);
+            let cells = gld.cells;
             let size = layout_geometry_size(&layout.geometry.rect, o, ctx);
+            let no_repeater_indices = || llr_Expression::Array {
+                element_ty: Type::Int32,
+                values: vec![],
+                as_model: false,
+            };
             if let (Some(button_roles), Orientation::Horizontal) = (&layout.dialog_button_roles, o)
             {
                 let cells_ty = cells.ty(ctx);
@@ -532,38 +587,61 @@ fn solve_layout(
                     },
                     llr_Expression::ExtraBuiltinFunctionCall {
                         function: "solve_grid_layout".into(),
-                        arguments: vec![make_struct(
-                            "GridLayoutData".into(),
-                            [
-                                ("size", Type::Float32, size),
-                                ("spacing", Type::Float32, spacing),
-                                ("padding", padding.ty(ctx), padding),
-                                (
-                                    "cells",
-                                    cells_ty.clone(),
-                                    llr_Expression::ReadLocalVariable {
-                                        name: "cells".into(),
-                                        ty: cells_ty,
-                                    },
-                                ),
-                            ],
-                        )],
+                        arguments: vec![
+                            make_struct(
+                                "GridLayoutData".into(),
+                                [
+                                    ("size", Type::Float32, size),
+                                    ("spacing", Type::Float32, spacing),
+                                    ("padding", padding.ty(ctx), padding),
+                                    (
+                                        "cells",
+                                        cells_ty.clone(),
+                                        llr_Expression::ReadLocalVariable {
+                                            name: "cells".into(),
+                                            ty: cells_ty,
+                                        },
+                                    ),
+                                ],
+                            ),
+                            no_repeater_indices(),
+                        ],
                         return_ty: Type::LayoutCache,
                     },
                 ])
             } else {
-                llr_Expression::ExtraBuiltinFunctionCall {
-                    function: "solve_grid_layout".into(),
-                    arguments: vec![make_struct(
-                        "GridLayoutData".into(),
-                        [
-                            ("size", Type::Float32, size),
-                            ("spacing", Type::Float32, spacing),
-                            ("padding", padding.ty(ctx), padding),
-                            ("cells", cells.ty(ctx), cells),
-                        ],
-                    )],
-                    return_ty: Type::LayoutCache,
+                let data = make_struct(
+                    "GridLayoutData".into(),
+                    [
+                        ("size", Type::Float32, size),
+                        ("spacing", Type::Float32, spacing),
+                        ("padding", padding.ty(ctx), padding),
+                        ("cells", cells.ty(ctx), cells),
+                    ],
+                );
+                match gld.compute_cells {
+                    Some((cells_variable, elements)) => llr_Expression::GridLayoutFunction {
+                        cells_variable,
+                        repeater_indices: Some("repeated_indices".into()),
+                        elements,
+                        orientation: o,
+                        sub_expression: Box::new(llr_Expression::ExtraBuiltinFunctionCall {
+                            function: "solve_grid_layout".into(),
+                            arguments: vec![
+                                data,
+                                llr_Expression::ReadLocalVariable {
+                                    name: "repeated_indices".into(),
+                                    ty: Type::Array(Type::Int32.into()),
+                                },
+                            ],
+                            return_ty: Type::LayoutCache,
+                        }),
+                    },
+                    None => llr_Expression::ExtraBuiltinFunctionCall {
+                        function: "solve_grid_layout".into(),
+                        arguments: vec![data, no_repeater_indices()],
+                        return_ty: Type::LayoutCache,
+                    },
                 }
             }
         }
@@ -702,32 +780,65 @@ fn box_layout_data(
     }
 }
 
+struct GridLayoutCellDataResult {
+    cells: llr_Expression,
+    /// When there are repeater involved, we need to do a GridLayoutFunction with the
+    /// given cell variable and elements
+    compute_cells: Option<(String, Vec<Either<llr_Expression, usize>>)>,
+}
+
 fn grid_layout_cell_data(
     layout: &crate::layout::GridLayout,
     orientation: Orientation,
     ctx: &ExpressionContext,
-) -> llr_Expression {
-    llr_Expression::Array {
-        element_ty: grid_layout_cell_data_ty(),
-        values: layout
-            .elems
-            .iter()
-            .map(|c| {
-                let (col_or_row, span) = c.col_or_row_and_span(orientation);
-                let layout_info =
-                    get_layout_info(&c.item.element, ctx, &c.item.constraints, orientation);
+) -> GridLayoutCellDataResult {
+    let make_cell = |c: &crate::layout::GridLayoutElement| {
+        let (col_or_row, span) = c.col_or_row_and_span(orientation);
+        let layout_info = get_layout_info(&c.item.element, ctx, &c.item.constraints, orientation);
 
-                make_struct(
-                    "GridLayoutCellData".into(),
-                    [
-                        ("constraint", crate::layout::layout_info_type(), layout_info),
-                        ("col_or_row", Type::Int32, llr_Expression::NumberLiteral(col_or_row as _)),
-                        ("span", Type::Int32, llr_Expression::NumberLiteral(span as _)),
-                    ],
-                )
-            })
-            .collect(),
-        as_model: false,
+        make_struct(
+            "GridLayoutCellData".into(),
+            [
+                ("constraint", crate::layout::layout_info_type(), layout_info),
+                ("col_or_row", Type::Int32, llr_Expression::NumberLiteral(col_or_row as _)),
+                ("span", Type::Int32, llr_Expression::NumberLiteral(span as _)),
+            ],
+        )
+    };
+
+    let repeater_count =
+        layout.elems.iter().filter(|c| c.item.element.borrow().repeated.is_some()).count();
+
+    if repeater_count == 0 {
+        let cells = llr_Expression::Array {
+            element_ty: grid_layout_cell_data_ty(),
+            values: layout.elems.iter().map(make_cell).collect(),
+            as_model: false,
+        };
+        GridLayoutCellDataResult { cells, compute_cells: None }
+    } else {
+        let mut elements = vec![];
+        for c in &layout.elems {
+            if c.item.element.borrow().repeated.is_some() {
+                let repeater_index = match ctx
+                    .mapping
+                    .element_mapping
+                    .get(&c.item.element.clone().into())
+                    .unwrap()
+                {
+                    LoweredElement::Repeated { repeated_index } => *repeated_index,
+                    _ => panic!(),
+                };
+                elements.push(Either::Right(repeater_index))
+            } else {
+                elements.push(Either::Left(make_cell(c)));
+            }
+        }
+        let cells = llr_Expression::ReadLocalVariable {
+            name: "cells".into(),
+            ty: Type::Array(Box::new(grid_layout_cell_data_ty())),
+        };
+        GridLayoutCellDataResult { cells, compute_cells: Some(("cells".into(), elements)) }
     }
 }
 
@@ -848,7 +959,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
 
     match path {
         crate::expression_tree::Path::Elements(elements) => {
-            let converted_elements = elements
+            let converted_elements: Vec<_> = elements
                 .iter()
                 .map(|element| {
                     let element_type = Type::Struct {
@@ -886,7 +997,24 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                     }
                 })
                 .collect();
-            llr_path_elements(converted_elements)
+            if elements.iter().all(|element| element.repeated.is_none()) {
+                return llr_path_elements(converted_elements);
+            }
+            llr_Expression::RepeatedPathElements {
+                elements: converted_elements
+                    .into_iter()
+                    .zip(elements.iter())
+                    .map(|(converted, element)| {
+                        let repeated =
+                            element.repeated.as_ref().map(|repeated| super::RepeatedPathElement {
+                                model: lower_expression(&repeated.model, ctx),
+                                model_data_variable: repeated.model_data_variable.clone(),
+                                index_variable: repeated.index_variable.clone(),
+                            });
+                        (converted, repeated)
+                    })
+                    .collect(),
+            }
         }
         crate::expression_tree::Path::Events(events, points) => {
             if events.is_empty() || points.is_empty() {
@@ -190,6 +190,7 @@ fn lower_sub_component(
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        change_callbacks: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
@@ -404,6 +405,18 @@ fn lower_sub_component(
         .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
         .collect();
 
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |element, _| {
+        for (p, handlers) in &element.borrow().change_callbacks {
+            let prop = ctx.map_property_reference(&NamedReference::new(element, p));
+            for e in handlers {
+                sub_component.change_callbacks.push((
+                    prop.clone(),
+                    super::lower_expression::lower_expression(e, &ctx).into(),
+                ));
+            }
+        }
+    });
+
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
         &ctx,
@@ -436,6 +449,16 @@ fn lower_sub_component(
                 }
                 Type::String => {}
                 Type::Enumeration(e) if e.name == "AccessibleRole" => {}
+                Type::Callback { args, .. } => {
+                    // The arguments (the value for `SetValue`) are provided by the generated
+                    // code that dispatches the action
+                    expr = super::Expression::CallBackCall {
+                        callback: ctx.map_property_reference(&nr),
+                        arguments: (0..args.len())
+                            .map(|index| super::Expression::FunctionParameterReference { index })
+                            .collect(),
+                    };
+                }
                 _ => panic!("Invalid type for accessible property"),
             }
 
@@ -494,6 +517,16 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
         prop_height: map_inner_prop("height"),
     });
 
+    let grid_layout_cell = component.grid_layout_cell.borrow().as_ref().map(|cell| {
+        let map = |nr: &NamedReference| sc.mapping.map_property_reference(nr, ctx.state);
+        GridLayoutCellInfo {
+            col: map(&cell.col),
+            row: map(&cell.row),
+            colspan: map(&cell.colspan),
+            rowspan: map(&cell.rowspan),
+        }
+    });
+
     RepeatedElement {
         model: super::lower_expression::lower_expression(&repeated.model, ctx).into(),
         sub_tree: ItemTree {
@@ -505,6 +538,7 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
         data_prop: (!repeated.is_conditional_element).then(|| 0),
         index_in_tree: *e.item_index.get().unwrap(),
         listview,
+        grid_layout_cell,
     }
 }
 
@@ -58,6 +58,11 @@ pub fn count_property_use(root: &PublicComponent) {
         for expr in &sc.init_code {
             expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
         }
+        // and the change callbacks
+        for (p, expr) in &sc.change_callbacks {
+            visit_property(p, ctx);
+            expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
         // 4. the models
         for (idx, r) in sc.repeated.iter().enumerate() {
             r.model.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
@@ -78,6 +83,18 @@ pub fn count_property_use(root: &PublicComponent) {
                 visit_property(&lv.prop_width, &rep_ctx);
                 visit_property(&lv.prop_height, &rep_ctx);
             }
+            if let Some(cell) = &r.grid_layout_cell {
+                let rep_ctx = EvaluationContext::new_sub_component(
+                    root,
+                    &r.sub_tree.root,
+                    (),
+                    Some(ParentCtx::new(ctx, Some(idx))),
+                );
+                visit_property(&cell.col, &rep_ctx);
+                visit_property(&cell.row, &rep_ctx);
+                visit_property(&cell.colspan, &rep_ctx);
+                visit_property(&cell.rowspan, &rep_ctx);
+            }
             for idx in r.data_prop.iter().chain(r.index_prop.iter()) {
                 // prevent optimizing model properties
                 let p = &r.sub_tree.root.properties[*idx];
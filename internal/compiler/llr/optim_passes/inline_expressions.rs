@@ -53,7 +53,9 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::ReturnStatement(_) => 1,
         Expression::LayoutCacheAccess { .. } => PROPERTY_ACCESS_COST,
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
+        Expression::GridLayoutFunction { .. } => return isize::MAX,
         Expression::ComputeDialogLayoutCells { .. } => return isize::MAX,
+        Expression::RepeatedPathElements { .. } => return isize::MAX,
     };
 
     exp.visit(|e| cost = cost.saturating_add(expression_cost(e, ctx)));
@@ -87,9 +89,13 @@ fn builtin_function_cost(function: BuiltinFunction) -> isize {
         BuiltinFunction::Log => 10,
         BuiltinFunction::Pow => 10,
         BuiltinFunction::SetFocusItem => isize::MAX,
-        BuiltinFunction::ShowPopupWindow => isize::MAX,
+        BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => isize::MAX,
+        BuiltinFunction::FlickableScrollTo
+        | BuiltinFunction::FlickableEnsureVisible
+        | BuiltinFunction::FlickableEnsureItemVisible => isize::MAX,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
+        BuiltinFunction::FormatNumber => 50,
         BuiltinFunction::ColorBrighter => 50,
         BuiltinFunction::ColorDarker => 50,
         BuiltinFunction::ImageSize => 50,
@@ -100,6 +106,7 @@ fn builtin_function_cost(function: BuiltinFunction) -> isize {
         BuiltinFunction::RegisterCustomFontByMemory => isize::MAX,
         BuiltinFunction::RegisterBitmapFont => isize::MAX,
         BuiltinFunction::DarkColorScheme => isize::MAX,
+        BuiltinFunction::Translate => isize::MAX,
     }
 }
 
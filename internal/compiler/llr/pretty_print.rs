@@ -247,9 +247,11 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 write!(f, "{}[{} % {}]", DisplayPropertyRef(layout_cache_prop, ctx), index, e(ri))
             }
             Expression::BoxLayoutFunction { .. } => write!(f, "BoxLayoutFunction(TODO)",),
+            Expression::GridLayoutFunction { .. } => write!(f, "GridLayoutFunction(TODO)",),
             Expression::ComputeDialogLayoutCells { .. } => {
                 write!(f, "ComputeDialogLayoutCells(TODO)",)
             }
+            Expression::RepeatedPathElements { .. } => write!(f, "RepeatedPathElements(TODO)",),
         }
     }
 }
@@ -14,7 +14,7 @@
     BuiltinElement, BuiltinPropertyInfo, DefaultSizeBinding, ElementType, NativeClass, Type,
 };
 use crate::object_tree::{self, *};
-use crate::parser::{identifier_text, syntax_nodes, SyntaxKind, SyntaxNode};
+use crate::parser::{doc_comment, identifier_text, syntax_nodes, SyntaxKind, SyntaxNode};
 use crate::typeregister::TypeRegister;
 
 /// Parse the contents of builtins.slint and fill the builtin type registry
@@ -87,6 +87,7 @@ pub fn load_builtins(register: &mut TypeRegister) {
                     ));
 
                     info.property_visibility = PropertyVisibility::Private;
+                    info.documentation = doc_comment(&p);
 
                     for token in p.children_with_tokens() {
                         if token.kind() != SyntaxKind::Identifier {
@@ -115,9 +116,7 @@ pub fn load_builtins(register: &mut TypeRegister) {
                     (prop_name, info)
                 })
                 .chain(e.CallbackDeclaration().map(|s| {
-                    (
-                        identifier_text(&s.DeclaredIdentifier()).unwrap(),
-                        BuiltinPropertyInfo::new(Type::Callback {
+                    let mut info = BuiltinPropertyInfo::new(Type::Callback {
                             args: s
                                 .Type()
                                 .map(|a| {
@@ -131,8 +130,9 @@ pub fn load_builtins(register: &mut TypeRegister) {
                                     register,
                                 ))
                             }),
-                        }),
-                    )
+                        });
+                    info.documentation = doc_comment(&s);
+                    (identifier_text(&s.DeclaredIdentifier()).unwrap(), info)
                 })),
         );
         n.deprecated_aliases = e
@@ -171,6 +171,7 @@ enum Base {
         };
         let mut builtin = BuiltinElement::new(Rc::new(n));
         builtin.is_global = matches!(base, Base::Global);
+        builtin.documentation = doc_comment(&c);
         let properties = &mut builtin.properties;
         if let Base::NativeParent(parent) = &base {
             properties.extend(parent.properties.iter().map(|(k, v)| (k.clone(), v.clone())));
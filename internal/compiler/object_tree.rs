@@ -13,7 +13,7 @@
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
 use crate::langtype::{BuiltinElement, NativeClass, Type};
 use crate::langtype::{ElementType, PropertyLookupResult};
-use crate::layout::{LayoutConstraints, Orientation};
+use crate::layout::{GridLayoutCell, LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
 use crate::parser;
 use crate::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
@@ -179,6 +179,10 @@ pub struct PopupWindow {
     pub component: Rc<Component>,
     pub x: NamedReference,
     pub y: NamedReference,
+    /// Property in the parent component with the value of the `close-on-click` property of the popup
+    pub close_on_click: NamedReference,
+    /// Callback in the parent component with the handler of the `close-requested` callback of the popup
+    pub close_requested: NamedReference,
     pub parent_element: ElementRc,
 }
 
@@ -219,6 +223,10 @@ pub struct Component {
     /// The layout constraints of the root item
     pub root_constraints: RefCell<LayoutConstraints>,
 
+    /// When this is a repeated component within a GridLayout, the properties that contain
+    /// the row and column of the root item
+    pub grid_layout_cell: RefCell<Option<GridLayoutCell>>,
+
     /// When creating this component and inserting "children", append them to the children of
     /// the element pointer to by this field.
     pub child_insertion_point: RefCell<Option<ChildrenInsertionPoint>>,
@@ -478,6 +486,10 @@ pub struct Element {
 
     pub property_declarations: BTreeMap<String, PropertyDeclaration>,
 
+    /// The `changed <property> => { ... }` handlers, by property name.
+    /// There can be several handlers for the same property after inlining.
+    pub change_callbacks: BTreeMap<String, Vec<Expression>>,
+
     /// Main owner for a reference to a property.
     pub named_references: crate::namedreference::NamedReferenceContainer,
 
@@ -585,6 +597,14 @@ macro_rules! indent {
             writeln!(f, "{} <=> {:?};", name, nr)?;
         }
     }
+    for (name, handlers) in &e.change_callbacks {
+        for expr in handlers {
+            indent!();
+            write!(f, "changed {} => ", name)?;
+            expression_tree::pretty_print(f, expr)?;
+            writeln!(f)?;
+        }
+    }
     if !e.states.is_empty() {
         indent!();
         writeln!(f, "states {:?}", e.states)?;
@@ -727,6 +747,7 @@ pub fn from_node(
                     error_on(&cb, "an 'init' callback")
                 }
             });
+            node.PropertyChangedCallback().for_each(|n| error_on(&n, "change callbacks"));
 
             ElementType::Global
         } else if parent_type != ElementType::Error {
@@ -1056,6 +1077,30 @@ pub fn from_node(
             }
         }
 
+        for ch in node.PropertyChangedCallback() {
+            let unresolved_prop_name =
+                unwrap_or_continue!(parser::identifier_text(&ch.DeclaredIdentifier()); diag);
+            let prop_name_token =
+                ch.DeclaredIdentifier().child_token(SyntaxKind::Identifier).unwrap();
+            let lookup_result = r.lookup_property(&unresolved_prop_name);
+            if !lookup_result.property_type.is_property_type() {
+                let msg = match lookup_result.property_type {
+                    Type::Invalid => {
+                        format!("Unknown property {} in {}", unresolved_prop_name, r.base_type)
+                    }
+                    _ => format!("'{}' is not a property in {}", unresolved_prop_name, r.base_type),
+                };
+                diag.push_error(msg, &prop_name_token);
+                continue;
+            }
+            let handlers =
+                r.change_callbacks.entry(lookup_result.resolved_name.into_owned()).or_default();
+            if !handlers.is_empty() {
+                diag.push_error("Duplicated change callback".into(), &prop_name_token);
+            }
+            handlers.push(Expression::Uncompiled(ch.clone().into()));
+        }
+
         for anim in node.PropertyAnimation() {
             if let Some(star) = anim.child_token(SyntaxKind::Star) {
                 diag.push_error(
@@ -1903,6 +1948,12 @@ fn visit_element_expressions_simple(
         }
     }
     elem.borrow_mut().transitions = transitions;
+
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for e in change_callbacks.values_mut().flatten() {
+        vis(e, None, &|| Type::Callback { return_type: None, args: vec![] });
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
 }
 
 pub fn visit_named_references_in_expression(
@@ -2001,9 +2052,14 @@ pub fn visit_all_named_references(
             if !Weak::ptr_eq(parent_compo, &compo) {
                 let compo = compo.upgrade().unwrap();
                 compo.root_constraints.borrow_mut().visit_named_references(vis);
+                if let Some(cell) = compo.grid_layout_cell.borrow_mut().as_mut() {
+                    cell.visit_named_references(vis);
+                }
                 compo.popup_windows.borrow_mut().iter_mut().for_each(|p| {
                     vis(&mut p.x);
                     vis(&mut p.y);
+                    vis(&mut p.close_on_click);
+                    vis(&mut p.close_requested);
                 });
             }
             compo
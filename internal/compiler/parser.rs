@@ -317,6 +317,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         Question -> "?",
         Dollar -> "$",
         At -> "@",
+        Pipe -> "|",
+        Percent -> "%",
     }
     // syntax kind
     {
@@ -327,7 +329,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *Function, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *PropertyChangedCallback, *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -337,6 +339,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
+        /// `changed property => { ... }`
+        PropertyChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
@@ -358,13 +362,23 @@ fn source_file(&self) -> Option<&SourceFile> {
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient,
-                       ?MemberAccess ],
+                       ?AtTr, ?MemberAccess ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
-        StringTemplate -> [*Expression],
+        /// The expressions are the string parts and the placeholders, in order.
+        /// A placeholder can be followed by a format specification
+        StringTemplate -> [*Expression, *TemplateFormatSpec],
+        /// `:` followed by the format specification of a placeholder in a string template
+        TemplateFormatSpec -> [],
         /// `@image-url("foo.png")`
         AtImageUrl -> [],
         /// `@linear-gradient(...)` or `@radial-gradient(...)`
         AtGradient -> [*Expression],
+        /// `@tr("foo", ...)`  // the string is a StringLiteral
+        AtTr -> [?TrContext, ?TrPlural, *Expression],
+        /// `"foo" =>`  in a `AtTr` node
+        TrContext -> [],
+        /// `| "foo" % n`  in a `AtTr` node
+        TrPlural -> [Expression],
         /// expression()
         FunctionCallExpression -> [*Expression],
         /// `expression[index]`
@@ -893,6 +907,69 @@ pub fn normalize_identifier(ident: &str) -> String {
     ident.replace('_', "-")
 }
 
+/// Return the documentation of this node: the text of the `///` or `/** */` comments that
+/// directly precede it, without the comment markers.
+pub fn doc_comment(node: &SyntaxNode) -> Option<String> {
+    let is_trivia = |t: &rowan::SyntaxToken<Language>| {
+        matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment)
+    };
+    // The comments can either be within the node itself, or in the siblings before it
+    let mut leading = node
+        .node
+        .children_with_tokens()
+        .map_while(|t| t.into_token().filter(is_trivia))
+        .collect::<Vec<_>>();
+    leading.reverse();
+    let preceding = node
+        .node
+        .siblings_with_tokens(rowan::Direction::Prev)
+        .skip(1)
+        .map_while(|t| t.into_token().filter(is_trivia));
+
+    // The lines of the comments, in reverse order
+    let mut lines = Vec::new();
+    for token in leading.into_iter().chain(preceding) {
+        let text = token.text();
+        if token.kind() == SyntaxKind::Whitespace {
+            if text.matches('\n').count() > 1 {
+                // An empty line separates the comment from the node
+                break;
+            }
+        } else if let Some(line) = text.strip_prefix("///").filter(|l| !l.starts_with('/')) {
+            lines.push(line.trim_end().to_string());
+        } else if let Some(block) = text
+            .strip_prefix("/**")
+            .and_then(|b| b.strip_suffix("*/"))
+            .filter(|b| !b.is_empty() && !b.starts_with('*'))
+        {
+            lines.extend(block.lines().rev().map(|l| {
+                let l = l.trim();
+                l.strip_prefix('*').unwrap_or(l).trim_end().to_string()
+            }));
+        } else if !text.starts_with("//-") {
+            // A regular comment, which is not documentation
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        // `export component Foo { ... }`: the comment is before the `export` keyword
+        return node
+            .parent()
+            .filter(|p| p.kind() == SyntaxKind::ExportsList)
+            .and_then(|p| doc_comment(&p));
+    }
+    lines.reverse();
+    let doc = lines
+        .iter()
+        .map(|l| l.strip_prefix(' ').unwrap_or(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    (!doc.is_empty()).then(|| doc)
+}
+
 // Parse an expression into a BindingExpression. This is used by the LSP to syntax
 // check the values of properties.
 pub fn parse_expression_as_bindingexpression(
@@ -32,6 +32,7 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// for xx in model: Sub {}
 /// if condition : Sub {}
 /// clicked => {}
+/// changed foo => {}
 /// callback foobar;
 /// property<int> width;
 /// animate someProp { }
@@ -52,6 +53,12 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     parse_callback_connection(&mut *p)
                 }
                 SyntaxKind::DoubleArrow => parse_two_way_binding(&mut *p),
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier if p.peek().as_str() == "for" => {
                     parse_repeated_element(&mut *p);
                 }
@@ -274,6 +281,23 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,PropertyChangedCallback
+/// changed foo => {}
+/// changed the-property => { bar; }
+/// ```
+fn parse_changed_callback(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::PropertyChangedCallback);
+    debug_assert_eq!(p.peek().as_str(), "changed");
+    p.consume(); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
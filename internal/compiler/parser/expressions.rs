@@ -229,10 +229,13 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "tr" => {
+            parse_tr(p);
+        }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
         }
     }
 }
@@ -303,6 +306,8 @@ fn parse_function_arguments(p: &mut impl Parser) {
 /// ```test,StringTemplate
 /// "foo\{bar}"
 /// "foo\{4 + 5}foo"
+/// "foo\{bar:.2}"
+/// "\{a.b:08,.3L} \{c:,}"
 /// ```
 fn parse_template_string(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::StringTemplate);
@@ -313,6 +318,13 @@ fn parse_template_string(p: &mut impl Parser) {
     }
     loop {
         parse_expression(&mut *p);
+        if p.peek().kind == SyntaxKind::Colon {
+            let mut p = p.start_node(SyntaxKind::TemplateFormatSpec);
+            p.consume();
+            while !matches!(p.peek().kind, SyntaxKind::StringLiteral | SyntaxKind::Eof) {
+                p.consume();
+            }
+        }
         let peek = p.peek();
         if peek.kind != SyntaxKind::StringLiteral || !peek.as_str().starts_with('}') {
             p.error("Error while parsing string template")
@@ -350,3 +362,44 @@ fn parse_gradient(p: &mut impl Parser) {
         p.test(SyntaxKind::Comma);
     }
 }
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AtTr
+/// @tr("foo")
+/// @tr("foo{0}", bar(42))
+/// @tr("context" => "ccc{}", 0)
+/// @tr("xxx" => "ccc{n}" | "ddd{}" % 42, 45)
+/// @tr("ccc{n}" | "ddd{n}" % len)
+/// ```
+fn parse_tr(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtTr);
+    p.expect(SyntaxKind::At);
+    debug_assert_eq!(p.peek().as_str(), "tr");
+    p.expect(SyntaxKind::Identifier); //"tr"
+    p.expect(SyntaxKind::LParent);
+
+    if p.nth(0).kind() == SyntaxKind::StringLiteral && p.nth(1).kind() == SyntaxKind::FatArrow {
+        let mut p = p.start_node(SyntaxKind::TrContext);
+        p.consume(); // the context string
+        p.consume(); // "=>"
+    }
+
+    if !p.expect(SyntaxKind::StringLiteral) {
+        return;
+    }
+
+    if p.nth(0).kind() == SyntaxKind::Pipe {
+        let mut p = p.start_node(SyntaxKind::TrPlural);
+        p.consume(); // "|"
+        p.expect(SyntaxKind::StringLiteral);
+        p.expect(SyntaxKind::Percent);
+        parse_expression(&mut *p);
+    }
+
+    while p.test(SyntaxKind::Comma) {
+        if !parse_expression(&mut *p) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RParent);
+}
@@ -161,6 +161,19 @@ fn analyze_element(
     for (_, nr) in &elem.borrow().accessibility_props.0 {
         process_property(&PropertyPath::from(nr.clone()), context, reverse_aliases, diag);
     }
+    for (name, handlers) in &elem.borrow().change_callbacks {
+        process_property(
+            &PropertyPath::from(NamedReference::new(elem, name)),
+            context,
+            reverse_aliases,
+            diag,
+        );
+        for expr in handlers {
+            recurse_expression(expr, &mut |prop| {
+                process_property(prop, context, reverse_aliases, diag);
+            });
+        }
+    }
 }
 
 #[derive(Copy, Clone, dm::BitAnd, dm::BitOr, dm::BitAndAssign, dm::BitOrAssign)]
@@ -347,7 +360,23 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath)) {
             }
             match l {
                 crate::layout::Layout::GridLayout(l) => {
-                    visit_layout_items_dependencies(l.elems.iter().map(|it| &it.item), *o, vis)
+                    visit_layout_items_dependencies(l.elems.iter().map(|it| &it.item), *o, vis);
+                    // The position of the repeated elements is computed at runtime
+                    for it in &l.elems {
+                        let cell = if it.item.element.borrow().repeated.is_some() {
+                            let rep_comp =
+                                it.item.element.borrow().base_type.as_component().clone();
+                            let cell = rep_comp.grid_layout_cell.borrow().clone();
+                            cell
+                        } else {
+                            None
+                        };
+                        if let Some(cell) = cell {
+                            let (col_or_row, span) = cell.col_or_row_and_span(*o);
+                            vis(&col_or_row.clone().into());
+                            vis(&span.clone().into());
+                        }
+                    }
                 }
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
@@ -6,7 +6,7 @@
 use crate::langtype::ElementType;
 use crate::object_tree::Element;
 
-/// Check that the rotation is only on Image
+/// Check that the rotation is only on Image or Text
 pub fn check_rotation(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
     for cmp in &doc.inner_components {
         crate::object_tree::recurse_elem_including_sub_components(cmp, &(), &mut |elem, _| {
@@ -15,7 +15,7 @@ pub fn check_rotation(doc: &crate::object_tree::Document, diag: &mut BuildDiagno
                 .iter()
                 .any(|(property_name, _)| is_property_set(&*e, *&property_name))
             {
-                if matches!(e.native_class(), Some(native) if native.class_name != "ClippedImage") {
+                if !e.native_class().map_or(true, |native| can_rotate(&native.class_name)) {
                     let span = e
                         .bindings
                         .get("rotation-angle")
@@ -23,7 +23,8 @@ pub fn check_rotation(doc: &crate::object_tree::Document, diag: &mut BuildDiagno
                         .unwrap_or_else(|| e.to_source_location());
 
                     diag.push_error_with_span(
-                        "rotation properties can only be applied to the Image element".into(),
+                        "rotation properties can only be applied to the Image or Text element"
+                            .into(),
                         span,
                     );
                 } else if has_any_children(&*e) {
@@ -37,6 +38,11 @@ pub fn check_rotation(doc: &crate::object_tree::Document, diag: &mut BuildDiagno
     }
 }
 
+/// Returns true if the native class supports the rotation properties.
+fn can_rotate(class_name: &str) -> bool {
+    matches!(class_name, "ClippedImage" | "Text")
+}
+
 /// Returns true if this element or its base have any children.
 fn has_any_children(e: &Element) -> bool {
     !e.children.is_empty()
@@ -8,11 +8,14 @@
 //! to a vector of path elements (PathData) that is assigned to the
 //! elements property of the Path element. That way the generators have to deal
 //! with path embedding only as part of the property assignment.
+//!
+//! The path elements repeated with `for` or `if` are not kept as repeated elements:
+//! their bindings are evaluated for each row of the model while building the path data.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::*;
-use crate::langtype::ElementType;
 use crate::langtype::Type;
+use crate::langtype::{BuiltinElement, ElementType};
 use crate::object_tree::*;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -42,6 +45,14 @@ pub fn compile_paths(
         let path_data_binding = if let Some(commands_expr) =
             elem.bindings.remove("commands").map(RefCell::into_inner)
         {
+            if let Some(points) = elem.bindings.get("points") {
+                diag.push_error(
+                    "The points property cannot be mixed with the use of the SVG commands property"
+                        .into(),
+                    &*points.borrow(),
+                );
+                return;
+            }
             if let Some(path_child) = elem.children.iter().find(|child| {
                 element_types
                     .contains_key(&child.borrow().base_type.as_builtin().native_class.class_name)
@@ -82,6 +93,14 @@ pub fn compile_paths(
 
             let mut path_data = Vec::new();
 
+            if let Some(points) = elem.bindings.remove("points").map(RefCell::into_inner) {
+                let line_to = match element_types.get("LineTo") {
+                    Some(ElementType::Builtin(b)) => b.clone(),
+                    _ => panic!("Incorrect type registry -- expected built-in type for LineTo"),
+                };
+                path_data.push(polyline_path_element(points.expression, line_to));
+            }
+
             for child in old_children {
                 let element_name =
                     &child.borrow().base_type.as_builtin().native_class.class_name.clone();
@@ -95,15 +114,9 @@ pub fn compile_paths(
                         ),
                     };
 
-                    if child.borrow().repeated.is_some() {
-                        diag.push_error(
-                            "Path elements are not supported with `for`-`in` syntax, yet (https://github.com/slint-ui/slint/issues/754)".into(),
-                            &*child.borrow(),
-                        );
-                    } else {
-                        let bindings = std::mem::take(&mut child.borrow_mut().bindings);
-                        path_data.push(PathElement { element_type, bindings });
-                    }
+                    let repeated = repeated_path_element(&child);
+                    let bindings = std::mem::take(&mut child.borrow_mut().bindings);
+                    path_data.push(PathElement { element_type, bindings, repeated });
                 } else {
                     elem.children.push(child);
                 }
@@ -115,6 +128,69 @@ pub fn compile_paths(
     });
 }
 
+/// If the path element is repeated, replace the references to the model data and index in its
+/// bindings by local variables.
+fn repeated_path_element(child: &ElementRc) -> Option<RepeatedPathElement> {
+    let model_data_ty = Expression::RepeaterModelReference { element: Rc::downgrade(child) }.ty();
+    let repeated = child.borrow_mut().repeated.take()?;
+    let model_data_variable = "path_model_data".to_owned();
+    let index_variable = "path_model_index".to_owned();
+    for binding in child.borrow().bindings.values() {
+        binding.borrow_mut().expression.visit_recursive_mut(&mut |e| match e {
+            Expression::RepeaterModelReference { element }
+                if element.upgrade().map_or(false, |e| Rc::ptr_eq(&e, child)) =>
+            {
+                *e = Expression::ReadLocalVariable {
+                    name: model_data_variable.clone(),
+                    ty: model_data_ty.clone(),
+                }
+            }
+            Expression::RepeaterIndexReference { element }
+                if element.upgrade().map_or(false, |e| Rc::ptr_eq(&e, child)) =>
+            {
+                *e = Expression::ReadLocalVariable { name: index_variable.clone(), ty: Type::Int32 }
+            }
+            _ => {}
+        });
+    }
+    Some(RepeatedPathElement { model: repeated.model, model_data_variable, index_variable })
+}
+
+/// Returns a `LineTo` element repeated for each point of the `points` property
+fn polyline_path_element(points: Expression, line_to: Rc<BuiltinElement>) -> PathElement {
+    let model_data_variable = "path_point".to_owned();
+    let point_ty = match points.ty() {
+        Type::Array(ty) => (*ty).clone(),
+        _ => Type::Invalid,
+    };
+    let coordinate = |name: &str| {
+        RefCell::new(
+            Expression::StructFieldAccess {
+                base: Box::new(Expression::ReadLocalVariable {
+                    name: model_data_variable.clone(),
+                    ty: point_ty.clone(),
+                }),
+                name: name.into(),
+            }
+            .into(),
+        )
+    };
+    let bindings = IntoIterator::into_iter([
+        ("x".to_owned(), coordinate("x")),
+        ("y".to_owned(), coordinate("y")),
+    ])
+    .collect();
+    PathElement {
+        element_type: line_to,
+        bindings,
+        repeated: Some(RepeatedPathElement {
+            model: Expression::Cast { from: Box::new(points), to: Type::Model },
+            model_data_variable,
+            index_variable: "path_point_index".into(),
+        }),
+    }
+}
+
 fn compile_path_from_string_literal(
     commands: &str,
 ) -> Result<BindingExpression, lyon_extra::parser::ParseError> {
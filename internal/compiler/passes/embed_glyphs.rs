@@ -39,7 +39,8 @@ pub fn embed_glyphs<'a>(
             .chain('A'..='Z')
             .chain('0'..='9')
             .chain(" !\"#$%&'()*+,-./:;<=>?@\\]^_|~".chars())
-            .chain(std::iter::once('…')),
+            // The ellipsis of elided text and the character shown in password fields
+            .chain(['…', '●']),
     );
 
     if let Ok(sizes_str) = std::env::var("SLINT_FONT_SIZES") {
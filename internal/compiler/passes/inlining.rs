@@ -162,6 +162,11 @@ fn inline_element(
         }
     }
 
+    for (k, val) in inlined_component.root_element.borrow().change_callbacks.iter() {
+        // The handlers of the base component run first
+        elem_mut.change_callbacks.entry(k.clone()).or_default().splice(0..0, val.iter().cloned());
+    }
+
     if let Some(orig) = &inlined_component.root_element.borrow().layout_info_prop {
         if let Some(_new) = &mut elem_mut.layout_info_prop {
             todo!("Merge layout infos");
@@ -201,6 +206,8 @@ fn inline_element(
     for p in root_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, &mapping);
         fixup_reference(&mut p.y, &mapping);
+        fixup_reference(&mut p.close_on_click, &mapping);
+        fixup_reference(&mut p.close_requested, &mapping);
     }
 }
 
@@ -216,6 +223,7 @@ fn duplicate_element_with_mapping(
         base_type: elem.base_type.clone(),
         id: elem.id.clone(),
         property_declarations: elem.property_declarations.clone(),
+        change_callbacks: elem.change_callbacks.clone(),
         // We will do the fixup of the references in bindings later
         bindings: elem
             .bindings
@@ -294,6 +302,7 @@ fn duplicate_sub_component(
         ),
         embedded_file_resources: component_to_duplicate.embedded_file_resources.clone(),
         root_constraints: component_to_duplicate.root_constraints.clone(),
+        grid_layout_cell: component_to_duplicate.grid_layout_cell.clone(),
         child_insertion_point: component_to_duplicate.child_insertion_point.clone(),
         inlined_init_code: component_to_duplicate.inlined_init_code.clone(),
         init_code: component_to_duplicate.init_code.clone(),
@@ -317,11 +326,16 @@ fn duplicate_sub_component(
     for p in new_component.popup_windows.borrow_mut().iter_mut() {
         fixup_reference(&mut p.x, mapping);
         fixup_reference(&mut p.y, mapping);
+        fixup_reference(&mut p.close_on_click, mapping);
+        fixup_reference(&mut p.close_requested, mapping);
     }
     new_component
         .root_constraints
         .borrow_mut()
         .visit_named_references(&mut |nr| fixup_reference(nr, mapping));
+    if let Some(cell) = new_component.grid_layout_cell.borrow_mut().as_mut() {
+        cell.visit_named_references(&mut |nr| fixup_reference(nr, mapping));
+    }
     new_component
 }
 
@@ -337,6 +351,8 @@ fn duplicate_popup(
     PopupWindow {
         x: p.x.clone(),
         y: p.y.clone(),
+        close_on_click: p.close_on_click.clone(),
+        close_requested: p.close_requested.clone(),
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -42,6 +42,7 @@ pub fn lower_accessibility_properties(component: &Rc<Component>, diag: &mut Buil
             for prop_name in crate::typeregister::RESERVED_ACCESSIBILITY_PROPERTIES
                 .iter()
                 .map(|x| x.0)
+                .chain(crate::typeregister::reserved_accessibility_actions().map(|x| x.0))
                 .chain(std::iter::once("accessible-role"))
             {
                 if accessible_role_set {
@@ -62,17 +63,22 @@ pub fn lower_accessibility_properties(component: &Rc<Component>, diag: &mut Buil
 
 fn apply_builtin(e: &ElementRc) {
     let bty = if let Some(bty) = e.borrow().builtin_type() { bty } else { return };
-    if bty.name == "Text" {
+    let set_role = |role: &str| {
         e.borrow_mut().set_binding_if_not_set("accessible-role".into(), || {
             let enum_ty = crate::typeregister::BUILTIN_ENUMS.with(|e| e.AccessibleRole.clone());
             Expression::EnumerationValue(EnumerationValue {
-                value: enum_ty.values.iter().position(|v| v == "text").unwrap(),
+                value: enum_ty.values.iter().position(|v| v == role).unwrap(),
                 enumeration: enum_ty,
             })
         });
+    };
+    if bty.name == "Text" {
+        set_role("text");
         let text_prop = NamedReference::new(e, "text");
         e.borrow_mut().set_binding_if_not_set("accessible-label".into(), || {
             Expression::PropertyReference(text_prop)
         });
+    } else if bty.name == "ClippedImage" || bty.name == "ImageItem" {
+        set_role("image");
     }
 }
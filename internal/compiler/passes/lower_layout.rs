@@ -206,6 +206,17 @@ fn add_element(
         layout_cache_prop_v: &NamedReference,
         diag: &mut BuildDiagnostics,
     ) {
+        if item_element.borrow().repeated.is_some() {
+            self.add_repeated_element(
+                item_element,
+                (*row, *col),
+                layout_cache_prop_h,
+                layout_cache_prop_v,
+                diag,
+            );
+            return;
+        }
+
         let mut get_const_value = |name: &str| {
             item_element
                 .borrow_mut()
@@ -242,35 +253,110 @@ fn add_element_with_coord(
         layout_cache_prop_v: &NamedReference,
         diag: &mut BuildDiagnostics,
     ) {
-        let index = self.elems.len();
         if let Some(layout_item) = create_layout_item(item_element, diag) {
             if layout_item.repeater_index.is_some() {
                 diag.push_error(
-                    "'if' or 'for' expressions are not currently supported in grid layouts"
-                        .to_string(),
+                    "'if' or 'for' expressions are not currently supported in a Dialog".to_string(),
                     &*item_element.borrow(),
                 );
                 return;
             }
+            self.push_layout_item(
+                layout_item,
+                (row, col),
+                (rowspan, colspan),
+                layout_cache_prop_h,
+                layout_cache_prop_v,
+                diag,
+            );
+        }
+    }
 
-            let e = &layout_item.elem;
-            set_prop_from_cache(e, "x", layout_cache_prop_h, index * 2, &None, diag);
-            if !layout_item.item.constraints.fixed_width {
-                set_prop_from_cache(e, "width", layout_cache_prop_h, index * 2 + 1, &None, diag);
-            }
-            set_prop_from_cache(e, "y", layout_cache_prop_v, index * 2, &None, diag);
-            if !layout_item.item.constraints.fixed_height {
-                set_prop_from_cache(e, "height", layout_cache_prop_v, index * 2 + 1, &None, diag);
-            }
+    /// Add an element that has a `for` or an `if`.
+    ///
+    /// The `row`, `col`, `rowspan` and `colspan` of the repeated element are evaluated for each instance
+    /// at runtime, so they can depend on the model. By default, every instance is placed in its own row,
+    /// starting at the position where the element would have been placed.
+    fn add_repeated_element(
+        &mut self,
+        item_element: &ElementRc,
+        (row, col): (u16, u16),
+        layout_cache_prop_h: &NamedReference,
+        layout_cache_prop_v: &NamedReference,
+        diag: &mut BuildDiagnostics,
+    ) {
+        let layout_item = match create_layout_item(item_element, diag) {
+            Some(layout_item) => layout_item,
+            None => return,
+        };
+        let repeater_index = layout_item.repeater_index.clone().unwrap_or_default();
+        let root = &layout_item.elem;
+        let int_expr = |e: Expression| Expression::Cast { from: Box::new(e), to: Type::Int32 };
+        let literal = |v: u16| Expression::NumberLiteral(v as _, Unit::None);
+
+        let has_row = root.borrow().bindings.contains_key("row");
+        let cell_prop = |name: &str, default: Expression| {
+            let nr = create_new_prop(root, &format!("grid-{}", name), Type::Int32);
+            let binding = root
+                .borrow_mut()
+                .bindings
+                .remove(name)
+                .unwrap_or_else(|| RefCell::new(default.into()));
+            root.borrow_mut().bindings.insert(nr.name().into(), binding);
+            nr
+        };
+        let cell = GridLayoutCell {
+            row: cell_prop(
+                "row",
+                int_expr(Expression::BinaryExpression {
+                    lhs: Box::new(literal(row)),
+                    rhs: Box::new(Expression::Cast {
+                        from: Box::new(repeater_index),
+                        to: Type::Float32,
+                    }),
+                    op: '+',
+                }),
+            ),
+            // Just like for other elements, setting the row without the column puts it in the first column
+            col: cell_prop("col", int_expr(literal(if has_row { 0 } else { col }))),
+            rowspan: cell_prop("rowspan", int_expr(literal(1))),
+            colspan: cell_prop("colspan", int_expr(literal(1))),
+        };
+        let rep_comp = item_element.borrow().base_type.as_component().clone();
+        *rep_comp.grid_layout_cell.borrow_mut() = Some(cell);
+
+        self.push_layout_item(
+            layout_item,
+            (row, col),
+            (1, 1),
+            layout_cache_prop_h,
+            layout_cache_prop_v,
+            diag,
+        );
+    }
 
-            self.elems.push(GridLayoutElement {
-                col,
-                row,
-                colspan,
-                rowspan,
-                item: layout_item.item,
-            });
+    fn push_layout_item(
+        &mut self,
+        layout_item: CreateLayoutItemResult,
+        (row, col): (u16, u16),
+        (rowspan, colspan): (u16, u16),
+        layout_cache_prop_h: &NamedReference,
+        layout_cache_prop_v: &NamedReference,
+        diag: &mut BuildDiagnostics,
+    ) {
+        let index = self.elems.len();
+        let e = &layout_item.elem;
+        let rep_idx = &layout_item.repeater_index;
+        set_prop_from_cache(e, "x", layout_cache_prop_h, index * 2, rep_idx, diag);
+        if !layout_item.item.constraints.fixed_width {
+            set_prop_from_cache(e, "width", layout_cache_prop_h, index * 2 + 1, rep_idx, diag);
+        }
+        set_prop_from_cache(e, "y", layout_cache_prop_v, index * 2, rep_idx, diag);
+        if !layout_item.item.constraints.fixed_height {
+            set_prop_from_cache(e, "height", layout_cache_prop_v, index * 2 + 1, rep_idx, diag);
         }
+
+        self.elems.push(GridLayoutElement { col, row, colspan, rowspan, item: layout_item.item });
     }
 }
 
@@ -615,6 +701,17 @@ fn create_layout_item(
             PropertyDeclaration { property_type: Type::Percent, ..PropertyDeclaration::default() },
         );
     };
+    let root_element = match &item_element.borrow().base_type {
+        ElementType::Component(c) if item_element.borrow().repeated.is_some() => {
+            c.root_element.clone()
+        }
+        _ => item_element.clone(),
+    };
+    if matches!(&root_element.borrow().base_type, ElementType::Builtin(b) if b.name == "Shortcut") {
+        // Shortcuts don't take any space in the layout
+        return None;
+    }
+
     fix_explicit_percent("width", item_element);
     fix_explicit_percent("height", item_element);
 
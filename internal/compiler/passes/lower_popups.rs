@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 //! Passe that transform the PopupWindow element into a component
+//!
+//! Since the component of the popup only exists while the popup is shown, the properties of the
+//! elements of the popup that are accessed from the enclosing component are replaced by properties
+//! of the parent element, with a two way binding from within the popup.
 
 use crate::diagnostics::BuildDiagnostics;
-use crate::expression_tree::{Expression, NamedReference};
+use crate::expression_tree::{BindingExpression, Expression, NamedReference};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
 use crate::typeregister::TypeRegister;
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
 
 pub fn lower_popups(
     component: &Rc<Component>,
@@ -18,12 +23,13 @@ pub fn lower_popups(
 ) {
     let window_type = type_register.lookup_element("Window").unwrap();
 
+    wrap_repeated_popups(component, &type_register.empty_type());
+
     recurse_elem_including_sub_components_no_borrow(
         component,
         &None,
         &mut |elem, parent_element: &Option<ElementRc>| {
-            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow");
-            if is_popup {
+            if is_popup_window(elem) {
                 lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
             }
             Some(elem.clone())
@@ -31,6 +37,51 @@ pub fn lower_popups(
     )
 }
 
+fn is_popup_window(elem: &ElementRc) -> bool {
+    matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow")
+}
+
+/// A PopupWindow that is directly repeated or conditional is put in an Empty element which is
+/// repeated instead, so that the popup has a parent element in the repeated component.
+/// The `init` callback of the popup is moved to that element, so that it is invoked when the
+/// element is instantiated and can show the popup.
+fn wrap_repeated_popups(component: &Rc<Component>, empty_type: &ElementType) {
+    let mut repeated_popups = Vec::new();
+    recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+        if elem.borrow().repeated.is_some() {
+            if let ElementType::Component(base) = &elem.borrow().base_type {
+                if is_popup_window(&base.root_element) {
+                    repeated_popups.push(elem.clone());
+                }
+            }
+        }
+    });
+
+    for repeated in repeated_popups {
+        let popup = repeated.borrow().base_type.as_component().root_element.clone();
+        let wrapper = Rc::new(RefCell::new(Element {
+            id: format!("{}-popup-parent", popup.borrow().id),
+            base_type: empty_type.clone(),
+            enclosing_component: popup.borrow().enclosing_component.clone(),
+            ..Default::default()
+        }));
+        inject_element_as_repeated_element(&repeated, wrapper.clone());
+
+        let mut wrapper = wrapper.borrow_mut();
+        // The position of the popup stays relative to its parent
+        for coord in ["x", "y"] {
+            if let Some(binding) = wrapper.bindings.remove(coord) {
+                popup.borrow_mut().bindings.insert(coord.into(), binding);
+            }
+        }
+        wrapper.bindings.remove("width");
+        wrapper.bindings.remove("height");
+        if let Some(init) = popup.borrow_mut().bindings.remove("init") {
+            wrapper.bindings.insert("init".into(), init);
+        }
+    }
+}
+
 fn lower_popup_window(
     popup_window_element: &ElementRc,
     parent_element: Option<&ElementRc>,
@@ -49,13 +100,6 @@ fn lower_popup_window(
     };
 
     let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
-    if Rc::ptr_eq(&parent_component.root_element, popup_window_element) {
-        diag.push_error(
-            "PopupWindow cannot be directly repeated or conditional".into(),
-            &*popup_window_element.borrow(),
-        );
-        return;
-    }
 
     // Remove the popup_window_element from its parent
     let old_size = parent_element.borrow().children.len();
@@ -85,30 +129,171 @@ fn lower_popup_window(
     let coord_x = create_coordinate(&popup_comp, parent_element, "x");
     let coord_y = create_coordinate(&popup_comp, parent_element, "y");
 
-    // Throw error when accessing the popup from outside
+    // The close-on-click and close-requested of the popup are used when showing the popup,
+    // so they are moved to the parent element
+    let close_on_click = move_to_parent(&popup_comp, parent_element, "close-on-click", Type::Bool);
+    let close_requested = move_to_parent(
+        &popup_comp,
+        parent_element,
+        "close-requested",
+        Type::Callback { return_type: None, args: vec![] },
+    );
+    let redirect = |nr: &mut NamedReference| {
+        if Rc::ptr_eq(&nr.element(), popup_window_element) {
+            match nr.name() {
+                "close-on-click" => *nr = close_on_click.clone(),
+                "close-requested" => *nr = close_requested.clone(),
+                _ => (),
+            }
+        }
+    };
+    visit_all_named_references(&popup_comp, &mut |nr| redirect(nr));
+    visit_all_named_references(&parent_component, &mut |nr| redirect(nr));
+
+    // Collect the properties of the popup that are accessed from outside.
     // FIXME:
     // - the span is the span of the PopupWindow, that's wrong, we should have the span of the reference
     // - There are other object reference than in the NamedReference
-    // - Maybe this should actually be allowed
+    let mut outside_accesses = Vec::new();
     visit_all_named_references(&parent_component, &mut |nr| {
-        if std::rc::Weak::ptr_eq(&nr.element().borrow().enclosing_component, &weak) {
-            diag.push_error(
-                "Cannot access the inside of a PopupWindow from enclosing component".into(),
-                &*popup_window_element.borrow(),
-            );
-            // just set it to whatever is a valid NamedReference, otherwise we'll panic later
-            *nr = coord_x.clone();
+        if Weak::ptr_eq(&nr.element().borrow().enclosing_component, &weak)
+            && !outside_accesses.contains(nr)
+        {
+            outside_accesses.push(nr.clone());
         }
     });
+    let outside_accesses = outside_accesses
+        .into_iter()
+        .map(|nr| {
+            let replacement = lower_outside_access(&nr, &popup_comp, parent_element)
+                .unwrap_or_else(|message| {
+                    diag.push_error(message, &*popup_window_element.borrow());
+                    // just set it to whatever is a valid NamedReference, otherwise we'll panic later
+                    coord_x.clone()
+                });
+            (nr, replacement)
+        })
+        .collect::<HashMap<_, _>>();
+    if !outside_accesses.is_empty() {
+        visit_all_named_references(&parent_component, &mut |nr| {
+            if let Some(replacement) = outside_accesses.get(nr) {
+                *nr = replacement.clone();
+            }
+        });
+    }
 
     parent_component.popup_windows.borrow_mut().push(PopupWindow {
         component: popup_comp,
         x: coord_x,
         y: coord_y,
+        close_on_click,
+        close_requested,
         parent_element: parent_element.clone(),
     });
 }
 
+/// Declare a property in the parent element for the given property of the root of the popup,
+/// and move its binding there.
+fn move_to_parent(
+    popup_comp: &Rc<Component>,
+    parent_element: &ElementRc,
+    name: &str,
+    ty: Type,
+) -> NamedReference {
+    let binding = popup_comp.root_element.borrow_mut().bindings.remove(name);
+    let property_name = format!("{}-popup-{}", popup_comp.root_element.borrow().id, name);
+    let mut parent = parent_element.borrow_mut();
+    parent.property_declarations.insert(property_name.clone(), ty.into());
+    if let Some(binding) = binding {
+        parent.bindings.insert(property_name.clone(), binding);
+    }
+    drop(parent);
+    NamedReference::new(parent_element, &property_name)
+}
+
+/// The property `nr` of an element of the popup is accessed from the enclosing component.
+/// Declare a property in the parent element that holds the value of that property, even while the popup
+/// is not shown, and return a reference to it. The property within the popup is bound to it with a two way
+/// binding.
+fn lower_outside_access(
+    nr: &NamedReference,
+    popup_comp: &Rc<Component>,
+    parent_element: &ElementRc,
+) -> Result<NamedReference, String> {
+    let element = nr.element();
+    let ty = element.borrow().lookup_property(nr.name()).property_type;
+    match ty {
+        Type::Callback { .. } => {
+            return Err(format!(
+                "Cannot access the callback '{}' of an element of a PopupWindow from the enclosing component",
+                nr.name()
+            ))
+        }
+        Type::Function { .. } => {
+            return Err(format!(
+                "Cannot call the function '{}' of an element of a PopupWindow from the enclosing component",
+                nr.name()
+            ))
+        }
+        _ => (),
+    }
+
+    let binding = element.borrow_mut().bindings.remove(nr.name());
+    if let Some(binding) = &binding {
+        if depends_on_popup(&mut binding.borrow_mut(), popup_comp) {
+            element.borrow_mut().bindings.insert(nr.name().into(), binding.clone());
+            return Err(format!(
+                "Cannot access the property '{}' of an element of a PopupWindow from the enclosing component, because its binding depends on other elements of the PopupWindow",
+                nr.name()
+            ));
+        }
+    }
+
+    let property_name = format!(
+        "{}-popup-{}-{}",
+        popup_comp.root_element.borrow().id,
+        element.borrow().id,
+        nr.name()
+    );
+    parent_element.borrow_mut().property_declarations.insert(property_name.clone(), ty.into());
+    if let Some(binding) = binding {
+        parent_element.borrow_mut().bindings.insert(property_name.clone(), binding);
+    }
+    let outside = NamedReference::new(parent_element, &property_name);
+    element
+        .borrow_mut()
+        .bindings
+        .insert(nr.name().into(), RefCell::new(BindingExpression::new_two_way(outside.clone())));
+    Ok(outside)
+}
+
+/// Returns true if the binding references an element within the popup
+fn depends_on_popup(binding: &mut BindingExpression, popup_comp: &Rc<Component>) -> bool {
+    let is_in_popup = |element: &ElementRc| {
+        let mut component = element.borrow().enclosing_component.upgrade();
+        while let Some(c) = component {
+            if Rc::ptr_eq(&c, popup_comp) {
+                return true;
+            }
+            component = c
+                .parent_element
+                .upgrade()
+                .and_then(|parent| parent.borrow().enclosing_component.upgrade());
+        }
+        false
+    };
+    let mut depends = binding.two_way_bindings.iter().any(|nr| is_in_popup(&nr.element()));
+    binding.expression.visit_recursive(&mut |e| {
+        if let Expression::ElementReference(element) = e {
+            depends |= element.upgrade().map_or(false, |e| is_in_popup(&e));
+        }
+    });
+    visit_named_references_in_expression(&mut binding.expression, &mut |nr| {
+        depends |= is_in_popup(&nr.element())
+    });
+    depends
+}
+
 fn create_coordinate(
     popup_comp: &Rc<Component>,
     parent_element: &ElementRc,
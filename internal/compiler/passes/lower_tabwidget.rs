@@ -9,10 +9,13 @@
 //! be further inlined as it may expends to native widget that needs inlining
 
 use crate::diagnostics::BuildDiagnostics;
-use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::expression_tree::{
+    BindingExpression, BuiltinFunction, Expression, NamedReference, Unit,
+};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
 
 pub async fn lower_tabwidget(
@@ -65,30 +68,52 @@ fn process_tabwidget(
 
     elem.borrow_mut().base_type = tabwidget_impl;
     let mut children = std::mem::take(&mut elem.borrow_mut().children);
-    let num_tabs = children.len();
+    let tabbar = Element {
+        id: format!("{}-tabbar", elem.borrow().id),
+        base_type: tabbar_impl,
+        enclosing_component: elem.borrow().enclosing_component.clone(),
+        ..Default::default()
+    };
+    let tabbar = Rc::new(RefCell::new(tabbar));
+    // The index of a tab is the number of static tabs before it, plus the number of instances
+    // of the dynamic tabs before it (stored in properties of the TabWidget).
+    let mut num_static_tabs = 0;
+    let mut repeated_tab_counts = Vec::new();
     let mut tabs = Vec::new();
     for child in &mut children {
-        if child.borrow().repeated.is_some() {
-            diag.push_error(
-                "dynamic tabs ('if' or 'for') are currently not supported".into(),
-                &*child.borrow(),
-            );
-            continue;
-        }
         if child.borrow().base_type.to_string() != "Tab" {
             assert!(diag.has_error());
             continue;
         }
-        let index = tabs.len();
+        let first_index = tab_count_expression(num_static_tabs, &repeated_tab_counts);
+        if let Some(repeated) = &mut child.borrow_mut().repeated {
+            // The model is used by the content, by the tab in the tabbar, and for the count,
+            // so it is stored in a property to be evaluated only once
+            let model = share_repeated_model(elem, repeated);
+            let count =
+                super::lower_layout::create_new_prop(elem, "repeated-tab-count", Type::Int32);
+            elem.borrow_mut().bindings.insert(
+                count.name().into(),
+                RefCell::new(
+                    repeated_count_expression(model, repeated.is_conditional_element).into(),
+                ),
+            );
+            repeated_tab_counts.push(count);
+        } else {
+            num_static_tabs += 1;
+        }
+        let repeated = child.borrow().repeated.clone();
+
         child.borrow_mut().base_type = empty_type.clone();
         child.borrow_mut().property_declarations.insert("title".to_owned(), Type::String.into());
+        child.borrow_mut().property_declarations.insert("closable".to_owned(), Type::Bool.into());
         set_geometry_prop(elem, child, "x", diag);
         set_geometry_prop(elem, child, "y", diag);
         set_geometry_prop(elem, child, "width", diag);
         set_geometry_prop(elem, child, "height", diag);
         let condition = Expression::BinaryExpression {
             lhs: Expression::PropertyReference(NamedReference::new(elem, "current-index")).into(),
-            rhs: Expression::NumberLiteral(index as _, Unit::None).into(),
+            rhs: tab_index_expression(first_index.clone(), child).into(),
             op: '=',
         };
         let old = child
@@ -102,51 +127,76 @@ fn process_tabwidget(
             );
         }
 
-        let mut tab = Element {
-            id: format!("{}-tab{}", elem.borrow().id, index),
+        let tab = Element {
+            id: format!("{}-tab{}", elem.borrow().id, tabs.len()),
             base_type: tab_impl.clone(),
             enclosing_component: elem.borrow().enclosing_component.clone(),
+            repeated,
             ..Default::default()
         };
-        tab.bindings.insert(
-            "title".to_owned(),
-            BindingExpression::new_two_way(NamedReference::new(child, "title")).into(),
-        );
-        tab.bindings.insert(
+        let tab = Rc::new(RefCell::new(tab));
+        if tab.borrow().repeated.is_some() {
+            // The tab in the tabbar is not in the same repeater as the content, so it gets
+            // its own copy of the bindings
+            copy_repeated_tab_binding(child, &tab, "title", diag);
+            copy_repeated_tab_binding(child, &tab, "closable", diag);
+        } else {
+            let mut tab = tab.borrow_mut();
+            tab.bindings.insert(
+                "title".to_owned(),
+                BindingExpression::new_two_way(NamedReference::new(child, "title")).into(),
+            );
+            tab.bindings.insert(
+                "closable".to_owned(),
+                BindingExpression::new_two_way(NamedReference::new(child, "closable")).into(),
+            );
+        }
+        let tab_index = tab_index_expression(first_index, &tab);
+        let mut tab_mut = tab.borrow_mut();
+        tab_mut.bindings.insert(
             "current".to_owned(),
             BindingExpression::new_two_way(NamedReference::new(elem, "current-index")).into(),
         );
-        tab.bindings.insert(
+        tab_mut.bindings.insert(
             "current-focused".to_owned(),
             BindingExpression::new_two_way(NamedReference::new(elem, "current-focused")).into(),
         );
-        tab.bindings.insert(
-            "tab-index".to_owned(),
-            RefCell::new(Expression::NumberLiteral(index as _, Unit::None).into()),
-        );
-        tab.bindings.insert(
+        tab_mut.bindings.insert("tab-index".to_owned(), RefCell::new(tab_index.into()));
+        tab_mut.bindings.insert(
             "num-tabs".to_owned(),
-            RefCell::new(Expression::NumberLiteral(num_tabs as _, Unit::None).into()),
+            RefCell::new(
+                Expression::PropertyReference(NamedReference::new(&tabbar, "num-tabs")).into(),
+            ),
+        );
+        tab_mut.bindings.insert(
+            "close-requested".to_owned(),
+            RefCell::new(close_requested_expression(elem, &tabbar).into()),
         );
-        tabs.push(Rc::new(RefCell::new(tab)));
+        drop(tab_mut);
+        tabs.push(tab);
     }
 
-    let tabbar = Element {
-        id: format!("{}-tabbar", elem.borrow().id),
-        base_type: tabbar_impl.clone(),
-        enclosing_component: elem.borrow().enclosing_component.clone(),
-        children: tabs,
-        ..Default::default()
-    };
-    let tabbar = Rc::new(RefCell::new(tabbar));
+    tabbar.borrow_mut().children = tabs;
     set_tabbar_geometry_prop(elem, &tabbar, "x");
     set_tabbar_geometry_prop(elem, &tabbar, "y");
     set_tabbar_geometry_prop(elem, &tabbar, "width");
     set_tabbar_geometry_prop(elem, &tabbar, "height");
     tabbar.borrow_mut().bindings.insert(
         "num-tabs".to_owned(),
-        RefCell::new(Expression::NumberLiteral(num_tabs as _, Unit::None).into()),
+        RefCell::new(
+            Expression::Cast {
+                from: Box::new(tab_count_expression(num_static_tabs, &repeated_tab_counts)),
+                to: Type::Int32,
+            }
+            .into(),
+        ),
     );
+    if !repeated_tab_counts.is_empty() {
+        tabbar
+            .borrow_mut()
+            .change_callbacks
+            .insert("num-tabs".to_owned(), vec![clamp_current_index_expression(elem, &tabbar)]);
+    }
     elem.borrow_mut().bindings.insert(
         "current-index".to_owned(),
         BindingExpression::new_two_way(NamedReference::new(&tabbar, "current")).into(),
@@ -164,8 +214,11 @@ fn process_tabwidget(
         BindingExpression::new_two_way(NamedReference::new(&tabbar, "preferred-height")).into(),
     );
 
+    // The properties of the repeated tabs can't be accessed from outside of the repeater, so
+    // only the static tabs are taken into account for the minimum size of the content
     if let Some(expr) = children
         .iter()
+        .filter(|x| x.borrow().repeated.is_none())
         .map(|x| Expression::PropertyReference(NamedReference::new(x, "min-width")))
         .reduce(|lhs, rhs| crate::builtin_macros::min_max_expression(lhs, rhs, '>'))
     {
@@ -173,6 +226,7 @@ fn process_tabwidget(
     };
     if let Some(expr) = children
         .iter()
+        .filter(|x| x.borrow().repeated.is_none())
         .map(|x| Expression::PropertyReference(NamedReference::new(x, "min-height")))
         .reduce(|lhs, rhs| crate::builtin_macros::min_max_expression(lhs, rhs, '>'))
     {
@@ -182,6 +236,219 @@ fn process_tabwidget(
     elem.borrow_mut().children = std::iter::once(tabbar).chain(children.into_iter()).collect();
 }
 
+/// Returns the handler of the `close-requested` callback of a tab in the tabbar.
+///
+/// It calls the `close-requested` callback of the TabWidget, and if the tab was removed, keeps
+/// the same tab selected, or selects the previous one if the last tab was closed.
+fn close_requested_expression(elem: &ElementRc, tabbar: &ElementRc) -> Expression {
+    let num_tabs = || Expression::PropertyReference(NamedReference::new(tabbar, "num-tabs"));
+    let current_index = || NamedReference::new(elem, "current-index");
+    let closed_index = || Expression::FunctionParameterReference { index: 0, ty: Type::Int32 };
+    let num_tabs_before = "num_tabs_before_close";
+    let tab_removed = Expression::BinaryExpression {
+        lhs: Box::new(num_tabs()),
+        rhs: Box::new(Expression::ReadLocalVariable {
+            name: num_tabs_before.into(),
+            ty: Type::Int32,
+        }),
+        op: '<',
+    };
+    let before_current = Expression::BinaryExpression {
+        lhs: Box::new(closed_index()),
+        rhs: Box::new(Expression::PropertyReference(current_index())),
+        op: '<',
+    };
+    Expression::CodeBlock(vec![
+        Expression::StoreLocalVariable {
+            name: num_tabs_before.into(),
+            value: Box::new(num_tabs()),
+        },
+        Expression::FunctionCall {
+            function: Box::new(Expression::CallbackReference(
+                NamedReference::new(elem, "close-requested"),
+                None,
+            )),
+            arguments: vec![closed_index()],
+            source_location: None,
+        },
+        Expression::Condition {
+            condition: Box::new(Expression::BinaryExpression {
+                lhs: Box::new(tab_removed),
+                rhs: Box::new(before_current),
+                op: '&',
+            }),
+            true_expr: Box::new(Expression::SelfAssignment {
+                lhs: Box::new(Expression::PropertyReference(current_index())),
+                rhs: Box::new(Expression::Cast {
+                    from: Box::new(Expression::NumberLiteral(1., Unit::None)),
+                    to: Type::Int32,
+                }),
+                op: '-',
+            }),
+            false_expr: Box::new(Expression::CodeBlock(vec![])),
+        },
+        clamp_current_index_expression(elem, tabbar),
+    ])
+}
+
+/// Returns an expression that sets the `current-index` of the TabWidget to the last tab if it
+/// is past the end, for example because tabs were removed from a model.
+fn clamp_current_index_expression(elem: &ElementRc, tabbar: &ElementRc) -> Expression {
+    let num_tabs = || Expression::PropertyReference(NamedReference::new(tabbar, "num-tabs"));
+    let current_index =
+        || Expression::PropertyReference(NamedReference::new(elem, "current-index"));
+    let last_index = crate::builtin_macros::min_max_expression(
+        Expression::BinaryExpression {
+            lhs: Box::new(Expression::Cast { from: Box::new(num_tabs()), to: Type::Float32 }),
+            rhs: Box::new(Expression::NumberLiteral(1., Unit::None)),
+            op: '-',
+        },
+        Expression::NumberLiteral(0., Unit::None),
+        '>',
+    );
+    Expression::Condition {
+        condition: Box::new(Expression::BinaryExpression {
+            lhs: Box::new(current_index()),
+            rhs: Box::new(num_tabs()),
+            op: '≥',
+        }),
+        true_expr: Box::new(Expression::SelfAssignment {
+            lhs: Box::new(current_index()),
+            rhs: Box::new(Expression::Cast { from: Box::new(last_index), to: Type::Int32 }),
+            op: '=',
+        }),
+        false_expr: Box::new(Expression::CodeBlock(vec![])),
+    }
+}
+
+/// Returns an expression for the number of static tabs plus the number of dynamic tabs in the
+/// given properties.
+fn tab_count_expression(
+    num_static_tabs: usize,
+    repeated_tab_counts: &[NamedReference],
+) -> Expression {
+    repeated_tab_counts.iter().fold(
+        Expression::NumberLiteral(num_static_tabs as _, Unit::None),
+        |lhs, count| Expression::BinaryExpression {
+            lhs: Box::new(lhs),
+            rhs: Box::new(Expression::Cast {
+                from: Box::new(Expression::PropertyReference(count.clone())),
+                to: Type::Float32,
+            }),
+            op: '+',
+        },
+    )
+}
+
+/// Returns the index of the tab: `first_index` plus the index in the repeater if the element is
+/// repeated with a `for`.
+fn tab_index_expression(first_index: Expression, element: &ElementRc) -> Expression {
+    let index = match &element.borrow().repeated {
+        Some(repeated) if !repeated.is_conditional_element => Expression::BinaryExpression {
+            lhs: Box::new(first_index),
+            rhs: Box::new(Expression::Cast {
+                from: Box::new(Expression::RepeaterIndexReference {
+                    element: Rc::downgrade(element),
+                }),
+                to: Type::Float32,
+            }),
+            op: '+',
+        },
+        _ => first_index,
+    };
+    Expression::Cast { from: Box::new(index), to: Type::Int32 }
+}
+
+/// Move the model of the repeater into a new property of the TabWidget, and make the repeater
+/// refer to it. Returns the reference to the model, as it was before the conversion to a model.
+fn share_repeated_model(elem: &ElementRc, repeated: &mut RepeatedElementInfo) -> Expression {
+    let model = std::mem::take(&mut repeated.model);
+    // Numbers and arrays were converted to a model when the expression was resolved
+    let (model, is_cast) = match model {
+        Expression::Cast { from, to: Type::Model } => (*from, true),
+        model => (model, false),
+    };
+    let prop = super::lower_layout::create_new_prop(elem, "repeated-tab-model", model.ty());
+    elem.borrow_mut().bindings.insert(prop.name().into(), RefCell::new(model.into()));
+    let model = Expression::PropertyReference(prop);
+    repeated.model = if is_cast {
+        Expression::Cast { from: Box::new(model.clone()), to: Type::Model }
+    } else {
+        model.clone()
+    };
+    model
+}
+
+/// Returns the expression for the number of elements created by a repeater with that model
+fn repeated_count_expression(model: Expression, is_conditional_element: bool) -> Expression {
+    let literal = |v: usize| Expression::NumberLiteral(v as _, Unit::None);
+    let count = if is_conditional_element {
+        Expression::Condition {
+            condition: Box::new(model),
+            true_expr: Box::new(literal(1)),
+            false_expr: Box::new(literal(0)),
+        }
+    } else if matches!(model.ty(), Type::Int32 | Type::Float32) {
+        crate::builtin_macros::min_max_expression(
+            Expression::Cast { from: Box::new(model), to: Type::Float32 },
+            literal(0),
+            '>',
+        )
+    } else {
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::ArrayLength,
+                None,
+            )),
+            arguments: vec![model],
+            source_location: None,
+        }
+    };
+    Expression::Cast { from: Box::new(count), to: Type::Int32 }
+}
+
+/// Copy the binding for `prop` from the content of a repeated tab to the tab in the tabbar.
+///
+/// The references to the model data and the index are changed to refer to the repeater of the tab
+/// in the tabbar. The binding can't refer to elements within the content.
+fn copy_repeated_tab_binding(
+    content: &ElementRc,
+    tab: &ElementRc,
+    prop: &str,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut binding = match content.borrow().bindings.get(prop) {
+        Some(binding) => binding.borrow().clone(),
+        None => return,
+    };
+    let mut content_elements = HashSet::new();
+    recurse_elem(content, &(), &mut |elem, _| {
+        content_elements.insert(Rc::as_ptr(elem));
+    });
+    let mut valid = binding
+        .two_way_bindings
+        .iter()
+        .all(|nr| !content_elements.contains(&Rc::as_ptr(&nr.element())));
+    visit_named_references_in_expression(&mut binding.expression, &mut |nr| {
+        if Rc::ptr_eq(&nr.element(), content) && nr.name() == "$model" {
+            *nr = NamedReference::new(tab, "$model");
+        } else if content_elements.contains(&Rc::as_ptr(&nr.element())) {
+            valid = false;
+        }
+    });
+    if !valid {
+        diag.push_error(
+            format!(
+                "The '{}' of a dynamic tab can only depend on the model data or on properties outside of the tab",
+                prop
+            ),
+            &binding,
+        );
+        return;
+    }
+    tab.borrow_mut().bindings.insert(prop.into(), binding.into());
+}
+
 fn set_geometry_prop(
     tab_widget: &ElementRc,
     content: &ElementRc,
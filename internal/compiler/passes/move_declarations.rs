@@ -31,6 +31,7 @@ fn do_move_declarations(component: &Rc<Component>) {
 
     let mut new_root_bindings = HashMap::new();
     let mut new_root_property_analysis = HashMap::new();
+    let mut new_root_change_callbacks = HashMap::new();
 
     let move_bindings_and_animations = &mut |elem: &ElementRc| {
         visit_all_named_references_in_element(elem, fixup_reference);
@@ -61,6 +62,18 @@ fn do_move_declarations(component: &Rc<Component>) {
         }
         elem.borrow_mut().bindings = new_bindings;
 
+        let change_callbacks = core::mem::take(&mut elem.borrow_mut().change_callbacks);
+        let mut new_change_callbacks = BTreeMap::new();
+        for (k, e) in change_callbacks {
+            let will_be_moved = elem.borrow().property_declarations.contains_key(&k);
+            if will_be_moved {
+                new_root_change_callbacks.insert(map_name(elem, k.as_str()), e);
+            } else {
+                new_change_callbacks.insert(k, e);
+            }
+        }
+        elem.borrow_mut().change_callbacks = new_change_callbacks;
+
         let property_analysis = elem.borrow().property_analysis.take();
         let mut new_property_analysis = HashMap::with_capacity(property_analysis.len());
         for (prop, a) in property_analysis {
@@ -78,9 +91,14 @@ fn do_move_declarations(component: &Rc<Component>) {
     recurse_elem(&component.root_element, &(), &mut |e, _| move_bindings_and_animations(e));
 
     component.root_constraints.borrow_mut().visit_named_references(&mut fixup_reference);
+    if let Some(cell) = component.grid_layout_cell.borrow_mut().as_mut() {
+        cell.visit_named_references(&mut fixup_reference);
+    }
     component.popup_windows.borrow_mut().iter_mut().for_each(|p| {
         fixup_reference(&mut p.x);
         fixup_reference(&mut p.y);
+        fixup_reference(&mut p.close_on_click);
+        fixup_reference(&mut p.close_requested);
         visit_all_named_references(&p.component, &mut fixup_reference)
     });
     component.init_code.borrow_mut().iter_mut().for_each(|expr| {
@@ -106,6 +124,7 @@ fn do_move_declarations(component: &Rc<Component>) {
         r.property_declarations = decl.property_declarations;
         r.bindings.extend(new_root_bindings.into_iter());
         r.property_analysis.borrow_mut().extend(new_root_property_analysis.into_iter());
+        r.change_callbacks.extend(new_root_change_callbacks.into_iter());
     }
 
     // By now, the optimized item should be unused
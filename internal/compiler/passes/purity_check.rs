@@ -3,6 +3,7 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::Expression;
+use crate::langtype::Type;
 
 /// Check that pure expression only call pure functions
 pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
@@ -15,7 +16,7 @@ pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnost
                     true => crate::diagnostics::DiagnosticLevel::Warning,
                     false => crate::diagnostics::DiagnosticLevel::Error,
                 };
-                crate::object_tree::visit_element_expressions(elem, |expr, name, _| {
+                crate::object_tree::visit_element_expressions(elem, |expr, name, ty| {
                     if let Some(name) = name {
                         let lookup = elem.borrow().lookup_property(name);
                         if lookup.declared_pure.unwrap_or(false)
@@ -23,8 +24,8 @@ pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnost
                         {
                             ensure_pure(expr, Some(diag), level);
                         }
-                    } else {
-                        // model expression must be pure
+                    } else if !matches!(ty(), Type::Callback { .. }) {
+                        // model expression must be pure (but not the change callbacks)
                         ensure_pure(expr, Some(diag), level);
                     };
                 })
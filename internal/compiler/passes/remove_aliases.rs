@@ -168,17 +168,31 @@ pub fn remove_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
                 .borrow()
                 .get(remove.name())
                 .map_or(false, |v| v.is_read_externally || v.is_set_externally);
+            // The change callbacks can only be moved to a property of the same component
+            let has_change_callbacks = elem.change_callbacks.contains_key(remove.name());
             if let Some(d) = elem.property_declarations.get_mut(remove.name()) {
-                if d.expose_in_public_api || used_externally {
+                if d.expose_in_public_api
+                    || used_externally
+                    || (has_change_callbacks && !same_component)
+                {
                     d.is_alias = Some(to.clone());
                     drop(elem);
                     // one must mark the aliased property as settable from outside
                     to.mark_as_set();
                 } else {
                     elem.property_declarations.remove(remove.name());
+                    let change_callbacks = elem.change_callbacks.remove(remove.name());
                     let analysis = elem.property_analysis.borrow().get(remove.name()).cloned();
+                    drop(elem);
+                    if let Some(change_callbacks) = change_callbacks {
+                        to_elem
+                            .borrow_mut()
+                            .change_callbacks
+                            .entry(to.name().to_owned())
+                            .or_default()
+                            .extend(change_callbacks);
+                    }
                     if let Some(analysis) = analysis {
-                        drop(elem);
                         to.element()
                             .borrow()
                             .property_analysis
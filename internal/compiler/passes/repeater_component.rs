@@ -33,6 +33,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 property_analysis: std::mem::take(&mut elem.property_analysis),
                 children: std::mem::take(&mut elem.children),
                 property_declarations: std::mem::take(&mut elem.property_declarations),
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
                 named_references: Default::default(),
                 repeated: None,
                 node: elem.node.clone(),
@@ -50,6 +50,9 @@ fn resolve_expression(
                 Expression::from_callback_connection(node.clone().into(), &mut lookup_ctx)
             }
             SyntaxKind::Function => Expression::from_function(node.clone().into(), &mut lookup_ctx),
+            SyntaxKind::PropertyChangedCallback => {
+                Expression::from_changed_callback(node.clone().into(), &mut lookup_ctx)
+            }
             SyntaxKind::Expression => {
                 //FIXME again: this happen for non-binding expression (i.e: model)
                 Expression::from_expression_node(node.clone().into(), &mut lookup_ctx)
@@ -213,6 +216,17 @@ fn from_callback_connection(
         )
     }
 
+    fn from_changed_callback(
+        node: syntax_nodes::PropertyChangedCallback,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        Self::from_codeblock_node(node.CodeBlock(), ctx).maybe_convert_to(
+            Type::Void,
+            &node,
+            ctx.diag,
+        )
+    }
+
     fn from_function(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expression {
         ctx.arguments = node
             .ArgumentDeclaration()
@@ -230,6 +244,7 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             .map(|n| Self::from_expression_node(n, ctx))
             .or_else(|| node.AtImageUrl().map(|n| Self::from_at_image_url_node(n, ctx)))
             .or_else(|| node.AtGradient().map(|n| Self::from_at_gradient(n, ctx)))
+            .or_else(|| node.AtTr().map(|n| Self::from_at_tr(n, ctx)))
             .or_else(|| {
                 node.QualifiedName().map(|n| {
                     let exp = Self::from_qualified_name_node(n.clone(), ctx);
@@ -339,6 +354,84 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
         }
     }
 
+    fn from_at_tr(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Expression {
+        let string = match node
+            .child_text(SyntaxKind::StringLiteral)
+            .and_then(|s| crate::literals::unescape_string(&s))
+        {
+            Some(s) => s,
+            None => {
+                ctx.diag.push_error("Cannot parse string literal".into(), &node);
+                return Expression::Invalid;
+            }
+        };
+        let context = node.TrContext().map(|n| {
+            n.child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| {
+                    ctx.diag.push_error("Cannot parse string literal".into(), &n);
+                    Default::default()
+                })
+        });
+        let plural = node.TrPlural().map(|pl| {
+            let s = pl
+                .child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| {
+                    ctx.diag.push_error("Cannot parse string literal".into(), &pl);
+                    Default::default()
+                });
+            let n = pl.Expression();
+            let expr = Expression::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                Type::Int32,
+                &n,
+                ctx.diag,
+            );
+            (s, expr)
+        });
+
+        let domain = ctx
+            .type_loader
+            .and_then(|tl| tl.compiler_config.translation_domain.clone())
+            .unwrap_or_default();
+
+        let values = node
+            .Expression()
+            .map(|n| {
+                Expression::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                    Type::String,
+                    &n,
+                    ctx.diag,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // check that the format string is valid and that the number of arguments match
+        check_tr_format_string(&string, values.len(), plural.is_some(), &node, ctx.diag);
+        if let Some((plural, _)) = &plural {
+            check_tr_format_string(plural, values.len(), true, &node, ctx.diag);
+        }
+
+        let (plural, plural_n) =
+            plural.unwrap_or((String::new(), Expression::NumberLiteral(1., Unit::None)));
+
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::Translate,
+                Some(node.to_source_location()),
+            )),
+            arguments: vec![
+                Expression::StringLiteral(string),
+                Expression::StringLiteral(context.unwrap_or_default()),
+                Expression::StringLiteral(domain),
+                Expression::Array { element_ty: Type::String, values },
+                plural_n,
+                Expression::StringLiteral(plural),
+            ],
+            source_location: Some(node.to_source_location()),
+        }
+    }
+
     fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self {
         enum GradKind {
             Linear { angle: Box<Expression> },
@@ -947,13 +1040,20 @@ fn from_string_template_node(
         node: syntax_nodes::StringTemplate,
         ctx: &mut LookupCtx,
     ) -> Expression {
-        let mut exprs = node.Expression().map(|e| {
-            Expression::from_expression_node(e.clone(), ctx).maybe_convert_to(
-                Type::String,
-                &e,
-                ctx.diag,
-            )
-        });
+        let mut exprs = vec![];
+        let mut children = node.children().peekable();
+        while let Some(child) = children.next() {
+            if child.kind() != SyntaxKind::Expression {
+                continue;
+            }
+            let e: syntax_nodes::Expression = child.into();
+            let value = Expression::from_expression_node(e.clone(), ctx);
+            exprs.push(match children.next_if(|c| c.kind() == SyntaxKind::TemplateFormatSpec) {
+                Some(spec) => Self::from_template_format_spec(value, &e, spec.into(), ctx),
+                None => value.maybe_convert_to(Type::String, &e, ctx.diag),
+            });
+        }
+        let mut exprs = exprs.into_iter();
         let mut result = exprs.next().unwrap_or_default();
         for x in exprs {
             result = Expression::BinaryExpression {
@@ -965,6 +1065,44 @@ fn from_string_template_node(
         result
     }
 
+    /// Format the value of a placeholder of a string template according to the format
+    /// specification, such as `"\{value:08,.2L}"`
+    fn from_template_format_spec(
+        value: Expression,
+        value_node: &syntax_nodes::Expression,
+        spec_node: syntax_nodes::TemplateFormatSpec,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let value = value.maybe_convert_to(Type::Float32, value_node, ctx.diag);
+        let spec_text = spec_node.text().to_string();
+        let spec_text = spec_text.trim().strip_prefix(':').unwrap_or_default().trim();
+        let spec = match crate::literals::parse_number_format_spec(spec_text) {
+            Some(spec) => spec,
+            None => {
+                ctx.diag.push_error(
+                    format!("Invalid format specification '{spec_text}', expected [0][width][,][.precision][L]"),
+                    &spec_node,
+                );
+                return Expression::Invalid;
+            }
+        };
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::FormatNumber,
+                Some(spec_node.to_source_location()),
+            )),
+            arguments: vec![
+                value,
+                Expression::NumberLiteral(spec.width as _, Unit::None),
+                Expression::NumberLiteral(spec.precision.map_or(-1., |p| p as _), Unit::None),
+                Expression::BoolLiteral(spec.zero_pad),
+                Expression::BoolLiteral(spec.grouping),
+                Expression::BoolLiteral(spec.localized),
+            ],
+            source_location: Some(spec_node.to_source_location()),
+        }
+    }
+
     /// This function is used to find a type that's suitable for casting each instance of a bunch of expressions
     /// to a type that captures most aspects. For example for an array of object literals the result is a merge of
     /// all seen fields.
@@ -1339,3 +1477,89 @@ pub fn resolve_two_way_binding(
         }
     }
 }
+
+/// Check the placeholders of the format string of a `@tr(...)` and report errors in the diagnostics.
+///
+/// The placeholders are `{}` for the next argument, `{0}`, `{1}`, ... for the argument at the given
+/// position, and `{n}` for the count in the plural form. `{{` and `}}` are escaped braces.
+fn check_tr_format_string(
+    string: &str,
+    arg_count: usize,
+    has_plural: bool,
+    node: &dyn Spanned,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut next_positional = 0;
+    let mut max_index = None::<usize>;
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                if chars.peek() == Some(&'{') {
+                    chars.next();
+                    continue;
+                }
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            diag.push_error(
+                                "Unterminated placeholder in format string. '{' must be escaped with '{{'"
+                                    .into(),
+                                node,
+                            );
+                            return;
+                        }
+                    }
+                }
+                let index = if placeholder.is_empty() {
+                    next_positional += 1;
+                    next_positional - 1
+                } else if placeholder == "n" {
+                    if !has_plural {
+                        diag.push_error(
+                            "`{n}` placeholder can only be found in plural form".into(),
+                            node,
+                        );
+                    }
+                    continue;
+                } else if let Ok(index) = placeholder.parse::<usize>() {
+                    index
+                } else {
+                    diag.push_error(
+                        "Invalid '{...}' placeholder in format string. The placeholder must be a number, or braces must be escaped with '{{' and '}}'".into(),
+                        node,
+                    );
+                    return;
+                };
+                max_index = max_index.max(Some(index));
+            }
+            '}' => {
+                if chars.peek() == Some(&'}') {
+                    chars.next();
+                    continue;
+                }
+                diag.push_error(
+                    "Unescaped '}' in format string. Escape '}' with '}}'".into(),
+                    node,
+                );
+                return;
+            }
+            _ => {}
+        }
+    }
+    if let Some(max_index) = max_index {
+        if max_index >= arg_count {
+            diag.push_error(
+                format!(
+                    "Format string contains {} placeholders, but only {} extra arguments were given",
+                    max_index + 1,
+                    arg_count
+                ),
+                node,
+            );
+        }
+    }
+}
@@ -107,6 +107,17 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("accessible-value-step", Type::Float32),
 ];
 
+/// The callbacks invoked when an assistive technology requests an action on the element
+pub fn reserved_accessibility_actions() -> impl Iterator<Item = (&'static str, Type)> {
+    let callback = |args| Type::Callback { return_type: None, args };
+    IntoIterator::into_iter([
+        ("accessible-action-default", callback(vec![])),
+        ("accessible-action-decrement", callback(vec![])),
+        ("accessible-action-increment", callback(vec![])),
+        ("accessible-action-set-value", callback(vec![Type::String])),
+    ])
+}
+
 /// list of reserved property injected in every item
 pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type)> {
     RESERVED_GEOMETRY_PROPERTIES
@@ -117,6 +128,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type)> {
         .chain(RESERVED_ROTATION_PROPERTIES.iter())
         .chain(RESERVED_ACCESSIBILITY_PROPERTIES.iter())
         .map(|(k, v)| (*k, v.clone()))
+        .chain(reserved_accessibility_actions())
         .chain(IntoIterator::into_iter([
             ("forward-focus", Type::ElementReference),
             ("focus", BuiltinFunction::SetFocusItem.ty()),
@@ -259,6 +271,29 @@ pub fn builtin() -> Rc<RefCell<Self>> {
                     .unwrap()
                     .member_functions
                     .insert("show".into(), BuiltinFunction::ShowPopupWindow);
+                Rc::get_mut(b).unwrap().properties.insert(
+                    "close".into(),
+                    BuiltinPropertyInfo::new(BuiltinFunction::ClosePopupWindow.ty()),
+                );
+                Rc::get_mut(b)
+                    .unwrap()
+                    .member_functions
+                    .insert("close".into(), BuiltinFunction::ClosePopupWindow);
+            }
+            _ => unreachable!(),
+        };
+
+        match &mut register.elements.get_mut("Flickable").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let b = Rc::get_mut(b).unwrap();
+                for (name, function) in [
+                    ("scroll-to", BuiltinFunction::FlickableScrollTo),
+                    ("ensure-visible", BuiltinFunction::FlickableEnsureVisible),
+                    ("ensure-item-visible", BuiltinFunction::FlickableEnsureItemVisible),
+                ] {
+                    b.properties.insert(name.into(), BuiltinPropertyInfo::new(function.ty()));
+                    b.member_functions.insert(name.into(), function);
+                }
             }
             _ => unreachable!(),
         };
@@ -4,6 +4,7 @@
 // cSpell: ignore descendents
 
 use crate::items::ItemRc;
+use crate::SharedString;
 
 // The property names of the accessible-properties
 #[repr(C)]
@@ -21,6 +22,69 @@ pub enum AccessibleStringProperty {
     ValueStep,
 }
 
+/// An action that an assistive technology requests on an accessible item
+/// cbindgen:derive-tagged-enum-destructor
+/// cbindgen:derive-tagged-enum-copy-constructor
+/// cbindgen:derive-tagged-enum-copy-assignment
+#[repr(C)]
+#[derive(PartialEq, Clone, Debug)]
+pub enum AccessibilityAction {
+    /// Activate the item, for example click a button or toggle a checkbox
+    Default,
+    /// Decrement the value of the item, for example of a slider
+    Decrement,
+    /// Increment the value of the item, for example of a slider
+    Increment,
+    /// Set the value of the item
+    SetValue(SharedString),
+}
+
+/// The set of [`AccessibilityAction`]s that an accessible item supports, as a bit field.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct SupportedAccessibilityAction(pub u32);
+
+impl SupportedAccessibilityAction {
+    pub const DEFAULT: Self = Self(1 << 0);
+    pub const DECREMENT: Self = Self(1 << 1);
+    pub const INCREMENT: Self = Self(1 << 2);
+    pub const SET_VALUE: Self = Self(1 << 3);
+
+    /// Returns true if all the actions of `other` are supported
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the item supports no action at all
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl core::ops::BitOr for SupportedAccessibilityAction {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for SupportedAccessibilityAction {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
+
+impl From<&AccessibilityAction> for SupportedAccessibilityAction {
+    fn from(action: &AccessibilityAction) -> Self {
+        match action {
+            AccessibilityAction::Default => Self::DEFAULT,
+            AccessibilityAction::Decrement => Self::DECREMENT,
+            AccessibilityAction::Increment => Self::INCREMENT,
+            AccessibilityAction::SetValue(_) => Self::SET_VALUE,
+        }
+    }
+}
+
 /// Find accessible descendents of `root_item`.
 ///
 /// This will recurse through all children of `root_item`, but will not recurse
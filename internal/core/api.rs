@@ -10,7 +10,7 @@
 use alloc::boxed::Box;
 
 use crate::component::ComponentVTable;
-use crate::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use crate::input::{KeyEventType, KeyInputEvent, MouseEvent, TouchEvent};
 use crate::window::{WindowAdapter, WindowInner};
 
 /// A position represented in the coordinate space of logical pixels. That is the space before applying
@@ -410,6 +410,87 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
         }
     }
 
+    /// Returns whether the window is covering the whole screen.
+    pub fn is_fullscreen(&self) -> bool {
+        self.0.window_item().map_or(false, |w| w.as_pin_ref().full_screen())
+    }
+
+    /// Makes the window cover the whole screen, or restores it to its normal size.
+    ///
+    /// This sets the `full-screen` property of the `Window` element.
+    pub fn set_fullscreen(&self, fullscreen: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().full_screen.set(fullscreen);
+        }
+    }
+
+    /// Returns whether the window is maximized.
+    pub fn is_maximized(&self) -> bool {
+        self.0.window_item().map_or(false, |w| w.as_pin_ref().maximized())
+    }
+
+    /// Maximizes the window, or restores it to its normal size.
+    ///
+    /// This sets the `maximized` property of the `Window` element.
+    pub fn set_maximized(&self, maximized: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().maximized.set(maximized);
+        }
+    }
+
+    /// Returns whether the window is minimized.
+    pub fn is_minimized(&self) -> bool {
+        self.0.window_item().map_or(false, |w| w.as_pin_ref().minimized())
+    }
+
+    /// Minimizes the window, or restores it.
+    ///
+    /// This sets the `minimized` property of the `Window` element.
+    pub fn set_minimized(&self, minimized: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().minimized.set(minimized);
+        }
+    }
+
+    /// Returns whether the user can resize the window.
+    pub fn is_resizable(&self) -> bool {
+        self.0.window_item().map_or(true, |w| w.as_pin_ref().resizable())
+    }
+
+    /// Sets whether the user can resize the window. When not resizable, the window keeps its
+    /// current size.
+    ///
+    /// This sets the `resizable` property of the `Window` element.
+    pub fn set_resizable(&self, resizable: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().resizable.set(resizable);
+        }
+    }
+
+    /// Returns whether the window stays on top of other windows.
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.window_item().map_or(false, |w| w.as_pin_ref().always_on_top())
+    }
+
+    /// Sets whether the window stays on top of other windows.
+    ///
+    /// This sets the `always-on-top` property of the `Window` element.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        if let Some(window_item) = self.0.window_item() {
+            window_item.as_pin_ref().always_on_top.set(always_on_top);
+        }
+    }
+
+    /// Registers the path of a file that is dragged over the window from the desktop, and returns
+    /// the index to pass as `file` in [`WindowEvent::FileHovered`](crate::platform::WindowEvent::FileHovered)
+    /// and [`WindowEvent::FileDropped`](crate::platform::WindowEvent::FileDropped).
+    ///
+    /// The registered files are forgotten when the drag ends, after the files were dropped or
+    /// after [`WindowEvent::FileHoverCancelled`](crate::platform::WindowEvent::FileHoverCancelled).
+    pub fn register_dragged_file(&self, path: SharedString) -> usize {
+        self.0.register_dragged_file(path)
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -445,6 +526,24 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { id, position } => {
+                self.0.process_touch_input(TouchEvent::Pressed {
+                    id,
+                    position: position.to_euclid().cast(),
+                })
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                self.0.process_touch_input(TouchEvent::Moved {
+                    id,
+                    position: position.to_euclid().cast(),
+                })
+            }
+            crate::platform::WindowEvent::TouchReleased { id, position } => {
+                self.0.process_touch_input(TouchEvent::Released {
+                    id,
+                    position: position.to_euclid().cast(),
+                })
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(KeyInputEvent {
@@ -460,6 +559,13 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                     ..Default::default()
                 })
             }
+            crate::platform::WindowEvent::FileHovered { file, position } => {
+                self.0.process_file_hover(file, position.to_euclid().cast())
+            }
+            crate::platform::WindowEvent::FileDropped { file, position } => {
+                self.0.process_file_drop(file, position.to_euclid().cast())
+            }
+            crate::platform::WindowEvent::FileHoverCancelled => self.0.cancel_file_drag(),
         }
     }
 
@@ -728,6 +834,40 @@ pub fn quit_event_loop() -> Result<(), EventLoopError> {
         .quit_event_loop()
 }
 
+/// Changes the language used for the strings marked with `@tr(...)` in `.slint` files.
+///
+/// All the bindings that use translated strings are re-evaluated, so the user interface is updated
+/// in the new language without having to re-create the components. The layouts adapt to the new
+/// size of the texts.
+///
+/// The language is a locale name such as `"fr"` or `"de_CH"`, and an empty string resets it to
+/// the language of the environment. When translations are looked up with gettext (the `gettext`
+/// feature), the language is selected by setting the `LANGUAGE` environment variable of the
+/// process, so no locale needs to be installed for that language. However, gettext doesn't
+/// translate anything in the "C" locale: if the locale of the environment is "C", the
+/// `LC_MESSAGES` locale is set to `C.UTF-8`. An error is returned if that locale isn't installed
+/// either, and the language is not changed.
+///
+/// # Example
+/// ```rust
+/// slint::slint! { MyApp := Window { Text { text: @tr("Hello"); } } }
+/// # i_slint_backend_testing::init();
+/// let handle = MyApp::new();
+/// slint::set_language("fr").unwrap();
+/// ```
+pub fn set_language(language: &str) -> Result<(), SetLanguageError> {
+    crate::translations::set_language(language)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+/// Error returned from the [`set_language()`] function
+pub enum SetLanguageError {
+    /// The translations cannot be looked up with gettext, because the locale of the environment
+    /// is "C" and the `C.UTF-8` locale is not installed.
+    NoUsableLocale,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[non_exhaustive]
 /// Error returned from the [`invoke_from_event_loop()`] and [`quit_event_loop()`] function
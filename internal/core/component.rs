@@ -5,7 +5,9 @@
 
 //! This module contains the basic datastructures that are exposed to the C API
 
-use crate::accessibility::AccessibleStringProperty;
+use crate::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+};
 use crate::item_tree::{
     ItemTreeNode, ItemVisitorVTable, ItemWeak, TraversalOrder, VisitChildrenResult,
 };
@@ -101,6 +103,19 @@ pub struct ComponentVTable {
         result: &mut SharedString,
     ),
 
+    /// Executes an accessibility action.
+    pub accessibility_action: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        item_index: usize,
+        action: &AccessibilityAction,
+    ),
+
+    /// Returns the supported accessibility actions.
+    pub supported_accessibility_actions: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        item_index: usize,
+    ) -> SupportedAccessibilityAction,
+
     /// in-place destructor (for VRc)
     pub drop_in_place: unsafe fn(VRefMut<ComponentVTable>) -> vtable::Layout,
     /// dealloc function (for VRc)
@@ -33,9 +33,7 @@
 pub(crate) mod color;
 pub use color::*;
 
-#[cfg(feature = "std")]
 mod path;
-#[cfg(feature = "std")]
 pub use path::*;
 
 mod brush;
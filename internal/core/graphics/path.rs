@@ -294,6 +294,7 @@ pub fn iter(self) -> Option<PathDataIterator> {
                 PathData::Events(events, coordinates) => {
                     LyonPathIteratorVariant::FromEvents(events, coordinates)
                 }
+                #[cfg(feature = "std")]
                 PathData::Commands(commands) => {
                     let mut builder = lyon_path::Path::builder();
                     let mut parser = lyon_extra::parser::PathParser::new();
@@ -304,11 +305,20 @@ pub fn iter(self) -> Option<PathDataIterator> {
                     ) {
                         Ok(()) => LyonPathIteratorVariant::FromPath(builder.build()),
                         Err(e) => {
-                            eprintln!("Error while parsing path commands '{commands}': {e:?}");
+                            crate::debug_log!(
+                                "Error while parsing path commands '{commands}': {e:?}"
+                            );
                             LyonPathIteratorVariant::FromPath(Default::default())
                         }
                     }
                 }
+                #[cfg(not(feature = "std"))]
+                PathData::Commands(commands) => {
+                    crate::debug_log!(
+                        "Parsing of path commands is not supported without std: '{commands}'"
+                    );
+                    LyonPathIteratorVariant::FromPath(Default::default())
+                }
             },
             transform: Default::default(),
         }
@@ -1,13 +1,13 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
-/*! Module handling mouse events
+/*! Module handling mouse and touch events
 */
 #![warn(missing_docs)]
 
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
-use crate::items::{ItemRef, TextCursorDirection};
+use crate::items::{DropArea, DropEvent, ItemRef, TextCursorDirection};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
 use crate::window::{WindowAdapter, WindowInner};
@@ -68,6 +68,63 @@ pub fn translate(&mut self, vec: LogicalVector) {
     }
 }
 
+/// A touch event, for one of the points touching a touch screen.
+///
+/// `id` identifies the touch point across events, as long as it touches the screen.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum TouchEvent {
+    /// A finger started touching the screen
+    Pressed { id: u64, position: LogicalPoint },
+    /// The finger was moved
+    Moved { id: u64, position: LogicalPoint },
+    /// The finger was lifted from the screen, or the touch was cancelled
+    Released { id: u64, position: LogicalPoint },
+}
+
+impl TouchEvent {
+    /// The identifier of the touch point
+    pub fn id(&self) -> u64 {
+        match self {
+            TouchEvent::Pressed { id, .. }
+            | TouchEvent::Moved { id, .. }
+            | TouchEvent::Released { id, .. } => *id,
+        }
+    }
+
+    /// The position of the touch point
+    pub fn position(&self) -> LogicalPoint {
+        match self {
+            TouchEvent::Pressed { position, .. }
+            | TouchEvent::Moved { position, .. }
+            | TouchEvent::Released { position, .. } => *position,
+        }
+    }
+
+    /// Translate the position by the given value
+    pub fn translate(&mut self, vec: LogicalVector) {
+        match self {
+            TouchEvent::Pressed { position, .. }
+            | TouchEvent::Moved { position, .. }
+            | TouchEvent::Released { position, .. } => *position += vec,
+        }
+    }
+
+    /// The mouse event that the touch point emulates
+    fn to_mouse_event(self) -> MouseEvent {
+        match self {
+            TouchEvent::Pressed { position, .. } => {
+                MouseEvent::Pressed { position, button: PointerEventButton::Left }
+            }
+            TouchEvent::Moved { position, .. } => MouseEvent::Moved { position },
+            TouchEvent::Released { position, .. } => {
+                MouseEvent::Released { position, button: PointerEventButton::Left }
+            }
+        }
+    }
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -151,6 +208,14 @@ fn from(k: Key) -> Self {
                     char::from(k).into()
                 }
             }
+
+            /// Returns the character of the special key with the given name, such as `"PageUp"`
+            pub(crate) fn special_key_from_name(name: &str) -> Option<char> {
+                match name {
+                    $(stringify!($name) => Some($name),)*
+                    _ => None,
+                }
+            }
         };
     }
 
@@ -313,6 +378,57 @@ pub fn shortcut(&self) -> Option<StandardShortcut> {
         }
     }
 
+    /// Returns true if this event is the press of the key combination described by `keys`,
+    /// such as `"Ctrl+S"` or `"Ctrl+Shift+F5"`.
+    ///
+    /// The combination is made of the modifiers (`Ctrl`, `Alt`, `Shift` or `Meta`) followed by
+    /// the key, separated by `+`. The key is either a single character or the name of a
+    /// [`Key`](key_codes::Key), and letters are case insensitive. Like for the [`KeyboardModifiers`],
+    /// `Ctrl` is the command key on macOS.
+    pub fn matches_keys(&self, keys: &str) -> bool {
+        if self.event_type != KeyEventType::KeyPressed {
+            return false;
+        }
+        let (modifiers, key) = match keys.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => keys.rsplit_once('+').unwrap_or(("", keys)),
+        };
+
+        let mut expected = KeyboardModifiers::default();
+        for modifier in modifiers.split('+').map(str::trim).filter(|m| !m.is_empty()) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => expected.control = true,
+                "alt" => expected.alt = true,
+                "shift" => expected.shift = true,
+                "meta" => expected.meta = true,
+                _ => return false,
+            }
+        }
+
+        let key = key.trim();
+        let mut key_chars = key.chars();
+        let (key_char, is_special) = match (key_chars.next(), key_chars.next()) {
+            (Some(c), None) => (c, false),
+            _ => match key_codes::special_key_from_name(key) {
+                Some(c) => (c, true),
+                None => return false,
+            },
+        };
+
+        let mut text = self.text.chars();
+        let event_char = match (text.next(), text.next()) {
+            (Some(c), None) => c,
+            _ => return false,
+        };
+
+        let mut actual = self.modifiers;
+        if !is_special && !key_char.is_alphanumeric() {
+            // Punctuation characters might need shift to be typed, depending on the keyboard layout
+            actual.shift = expected.shift;
+        }
+        actual == expected && key_char.to_lowercase().eq(event_char.to_lowercase())
+    }
+
     /// If a shortcut concerning text editing was pressed, this function
     /// returns `Some(TextShortcut)`. Otherwise it returns None.
     pub fn text_shortcut(&self) -> Option<TextShortcut> {
@@ -738,6 +854,197 @@ fn send_mouse_event_to_item(
     }
 }
 
+/// The state which a window should hold for the touch input
+#[derive(Default)]
+pub struct TouchInputState {
+    /// The ids of the touch points currently pressed
+    points: Vec<u64>,
+    /// The touch point that is also sent as mouse events, so that the elements which only handle
+    /// the mouse, such as the TouchArea, can be used with a touch screen
+    primary_point: Option<u64>,
+    /// The gesture handlers under the first touch point, innermost first
+    gesture_handlers: Vec<ItemWeak>,
+    /// Set when one of the gesture handlers recognized its gesture. The touch points are not sent
+    /// as mouse events anymore, until they are all released.
+    gesture_recognized: bool,
+}
+
+/// Process the `touch_event` on the `component`.
+///
+/// All the touch points are sent to the gesture handlers that are under the first touch point.
+/// Until one of them recognizes its gesture, the first touch point is also returned as a mouse
+/// event, which is meant to be sent to the component. When a gesture is recognized,
+/// [`MouseEvent::Exit`] is returned to cancel the mouse emulation.
+pub fn process_touch_input(
+    component: ComponentRc,
+    touch_event: TouchEvent,
+    mut touch_input_state: TouchInputState,
+) -> (TouchInputState, Option<MouseEvent>) {
+    match touch_event {
+        TouchEvent::Pressed { id, position } => {
+            if touch_input_state.points.is_empty() {
+                let mut gesture_handlers = Vec::new();
+                collect_items_at(
+                    &ItemRc::new(component, 0),
+                    position,
+                    &crate::items::is_gesture_handler,
+                    &mut gesture_handlers,
+                );
+                touch_input_state = TouchInputState {
+                    primary_point: Some(id),
+                    gesture_handlers,
+                    ..Default::default()
+                };
+            }
+            touch_input_state.points.push(id);
+        }
+        TouchEvent::Moved { .. } => (),
+        TouchEvent::Released { id, .. } => touch_input_state.points.retain(|p| *p != id),
+    }
+
+    let mut recognized = None;
+    for (index, handler) in touch_input_state.gesture_handlers.iter().enumerate() {
+        let item = if let Some(item) = handler.upgrade() { item } else { continue };
+        let mut event = touch_event;
+        event.translate(-item.map_to_window(item.geometry().origin).to_vector());
+        if crate::items::gesture_touch_event(&item, event) && recognized.is_none() {
+            recognized = Some(index);
+        }
+    }
+
+    let mut mouse_event = None;
+    if let Some(recognized) = recognized {
+        if !touch_input_state.gesture_recognized {
+            touch_input_state.gesture_recognized = true;
+            // Only one gesture can be recognized at the same time
+            for (index, handler) in touch_input_state.gesture_handlers.iter().enumerate() {
+                if index != recognized {
+                    if let Some(item) = handler.upgrade() {
+                        crate::items::gesture_cancel(&item);
+                    }
+                }
+            }
+            mouse_event = Some(MouseEvent::Exit);
+        }
+    } else if !touch_input_state.gesture_recognized
+        && touch_input_state.primary_point == Some(touch_event.id())
+    {
+        mouse_event = Some(touch_event.to_mouse_event());
+    }
+
+    if touch_input_state.points.is_empty() {
+        touch_input_state = TouchInputState::default();
+    }
+    (touch_input_state, mouse_event)
+}
+
+/// Collect the items that contain the `position` and for which `filter` returns true,
+/// innermost first. `position` is relative to the parent of `item_rc`.
+fn collect_items_at(
+    item_rc: &ItemRc,
+    position: LogicalPoint,
+    filter: &dyn Fn(&ItemRc) -> bool,
+    result: &mut Vec<ItemWeak>,
+) {
+    let geom = item_rc.geometry();
+    // Like for the mouse events, the items clipped away by a Flickable or a Clip are skipped.
+    // This also skips the items that are not visible (see `ItemRc::is_visible`), as
+    // `visible: false` is implemented with a Clip of empty size.
+    if crate::item_rendering::is_clipping_item(item_rc.borrow()) && !geom.contains(position) {
+        return;
+    }
+    let position_in_item = position - geom.origin.to_vector();
+    let mut actual_visitor =
+        |component: &ComponentRc, index: usize, _: Pin<ItemRef>| -> VisitChildrenResult {
+            let child = ItemRc::new(component.clone(), index);
+            collect_items_at(&child, position_in_item, filter, result);
+            VisitChildrenResult::CONTINUE
+        };
+    vtable::new_vref!(let mut actual_visitor : VRefMut<crate::item_tree::ItemVisitorVTable> for crate::item_tree::ItemVisitor = &mut actual_visitor);
+    vtable::VRc::borrow_pin(&item_rc.component()).as_ref().visit_children_item(
+        item_rc.index() as isize,
+        crate::item_tree::TraversalOrder::FrontToBack,
+        actual_visitor,
+    );
+    if geom.contains(position) && filter(item_rc) {
+        result.push(item_rc.downgrade());
+    }
+}
+
+/// The state which a window should hold for the drag and drop operation in progress
+#[derive(Default)]
+pub struct DragDropState {
+    /// The DropArea under the dragged payload
+    target: ItemWeak,
+    /// Whether the target accepts the payload
+    accepted: bool,
+}
+
+/// Move the payload of a drag and drop operation to `position` on the `component`.
+///
+/// The innermost enabled DropArea under the position becomes the target: its `can-drop` callback
+/// is invoked when the payload enters it, and the previous target is left.
+pub fn process_drag_move(
+    component: ComponentRc,
+    event: &DropEvent,
+    position: LogicalPoint,
+    drag_drop_state: DragDropState,
+) -> DragDropState {
+    let mut drop_areas = Vec::new();
+    collect_items_at(
+        &ItemRc::new(component, 0),
+        position,
+        &|item: &ItemRc| item.downcast::<DropArea>().map_or(false, |d| d.as_pin_ref().enabled()),
+        &mut drop_areas,
+    );
+    let target = drop_areas.into_iter().next().unwrap_or_default();
+    let target_item = target.upgrade();
+    let position_in_target =
+        |item: &ItemRc| position - item.map_to_window(item.geometry().origin).to_vector();
+
+    if target == drag_drop_state.target {
+        if let Some(item) = &target_item {
+            let drop_area = item.downcast::<DropArea>().unwrap();
+            drop_area.as_pin_ref().drag_move(position_in_target(item));
+        }
+        return drag_drop_state;
+    }
+
+    process_drag_cancel(drag_drop_state);
+    let accepted = target_item.map_or(false, |item| {
+        let drop_area = item.downcast::<DropArea>().unwrap();
+        drop_area.as_pin_ref().drag_enter(event, position_in_target(&item))
+    });
+    DragDropState { target, accepted }
+}
+
+/// Drop the payload of a drag and drop operation at `position` on the `component`.
+/// Returns true if a DropArea accepted it.
+pub fn process_drop(
+    component: ComponentRc,
+    event: &DropEvent,
+    position: LogicalPoint,
+    drag_drop_state: DragDropState,
+) -> bool {
+    let drag_drop_state = process_drag_move(component, event, position, drag_drop_state);
+    let accepted = drag_drop_state.accepted;
+    if let Some(item) = drag_drop_state.target.upgrade() {
+        let drop_area = item.downcast::<DropArea>().unwrap();
+        if accepted {
+            drop_area.as_pin_ref().drop_payload(event);
+        }
+        drop_area.as_pin_ref().drag_leave();
+    }
+    accepted
+}
+
+/// The drag and drop operation was cancelled: the target DropArea is left.
+pub fn process_drag_cancel(drag_drop_state: DragDropState) {
+    if let Some(item) = drag_drop_state.target.upgrade() {
+        item.downcast::<DropArea>().unwrap().as_pin_ref().drag_leave();
+    }
+}
+
 /// The TextCursorBlinker takes care of providing a toggled boolean property
 /// that can be used to animate a blinking cursor. It's typically stored in the
 /// Window using a Weak and set_binding() can be used to set up a binding on a given
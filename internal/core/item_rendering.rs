@@ -18,6 +18,8 @@
 use alloc::boxed::Box;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 use vtable::VRc;
@@ -241,11 +243,11 @@ pub fn item_children_bounding_rect(
             }
 
             if !is_clipping_item(item) {
-                bounding_rect = bounding_rect.union(&item_children_bounding_rect(
-                    component,
-                    index as isize,
-                    &local_clip_rect,
-                ));
+                // The children's bounding rect is relative to the item, bring it back to our coordinates
+                bounding_rect = bounding_rect.union(
+                    &item_children_bounding_rect(component, index as isize, &local_clip_rect)
+                        .translate(item_geometry.origin.to_vector()),
+                );
             }
             VisitChildrenResult::CONTINUE
         };
@@ -271,7 +273,6 @@ pub trait ItemRenderer {
     fn draw_clipped_image(&mut self, image: Pin<&ClippedImage>, _self_rc: &ItemRc);
     fn draw_text(&mut self, text: Pin<&Text>, _self_rc: &ItemRc);
     fn draw_text_input(&mut self, text_input: Pin<&TextInput>, _self_rc: &ItemRc);
-    #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&Path>, _self_rc: &ItemRc);
     fn draw_box_shadow(&mut self, box_shadow: Pin<&BoxShadow>, _self_rc: &ItemRc);
     fn visit_opacity(&mut self, opacity_item: Pin<&Opacity>, _self_rc: &ItemRc) -> RenderingResult {
@@ -282,6 +283,18 @@ fn visit_layer(&mut self, _layer_item: Pin<&Layer>, _self_rc: &ItemRc) -> Render
         // Not supported
         RenderingResult::ContinueRenderingChildren
     }
+    // Apply the rotation of the Rotate element. The default implementation calls rotate around
+    // the rotation origin, but the renderer may choose to render the children in a layer instead.
+    fn visit_rotate(&mut self, rotate_item: Pin<&Rotate>, _self_rc: &ItemRc) -> RenderingResult {
+        let origin = LogicalVector::from_lengths(
+            rotate_item.rotation_origin_x(),
+            rotate_item.rotation_origin_y(),
+        );
+        self.translate(origin);
+        self.rotate(rotate_item.rotation_angle());
+        self.translate(-origin);
+        RenderingResult::ContinueRenderingChildren
+    }
 
     // Apply the bounds of the Clip element, if enabled. The default implementation calls
     // combine_clip, but the render may choose an alternate way of implementing the clip.
@@ -366,8 +379,67 @@ fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetr
     }
 }
 
+/// The geometry of an item, as cached by the [`PartialRenderer`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CachedItemGeometry {
+    /// The geometry of the item, relative to its parent
+    pub geometry: LogicalRect,
+    /// The area covered by the drawing of the item, relative to its parent. This is larger
+    /// than the geometry for the items that draw outside of it, such as box shadows or
+    /// rotated children.
+    pub bounding_rect: LogicalRect,
+}
+
+impl CachedItemGeometry {
+    fn new(item: Pin<ItemRef>, geometry: LogicalRect) -> Self {
+        let bounding_rect = if let Some(box_shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+            // The shadow is drawn at an offset, and blurred on both sides of its edges
+            let blur = box_shadow.blur().get().max(0 as _);
+            geometry
+                .translate(LogicalVector::from_lengths(
+                    box_shadow.offset_x(),
+                    box_shadow.offset_y(),
+                ))
+                .inflate(blur, blur)
+        } else if let Some(rotate) = ItemRef::downcast_pin::<Rotate>(item) {
+            rotated_bounding_rect(
+                geometry,
+                LogicalVector::from_lengths(rotate.rotation_origin_x(), rotate.rotation_origin_y()),
+                rotate.rotation_angle(),
+            )
+        } else {
+            geometry
+        };
+        Self { geometry, bounding_rect }
+    }
+}
+
+/// Returns the bounding rectangle of `rect` once rotated by `angle` degrees around the point at
+/// `origin`, which is relative to the rectangle.
+/// (The children of a Rotate item fill its geometry, since only Image and Text can be rotated.)
+fn rotated_bounding_rect(rect: LogicalRect, origin: LogicalVector, angle: f32) -> LogicalRect {
+    let angle = angle % 360.;
+    if angle == 0. {
+        return rect;
+    }
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let rect = rect.cast::<f32>();
+    let pivot = rect.origin + origin.cast::<f32>();
+    let corners = [
+        rect.min(),
+        euclid::point2(rect.max_x(), rect.min_y()),
+        euclid::point2(rect.min_x(), rect.max_y()),
+        rect.max(),
+    ]
+    .map(|p| {
+        let v = p - pivot;
+        pivot + euclid::vec2(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+    });
+    euclid::Box2D::from_points(corners).to_rect().round_out().cast()
+}
+
 /// The cache that needs to be held by the Window for the partial rendering
-pub type PartialRenderingCache = RenderingCache<LogicalRect>;
+pub type PartialRenderingCache = RenderingCache<CachedItemGeometry>;
 
 /// FIXME: Should actually be a region and not just a rectangle
 pub type DirtyRegion = euclid::Box2D<Coord, LogicalPx>;
@@ -404,22 +476,23 @@ pub fn compute_dirty_regions(&mut self, component: &ComponentRc, origin: Logical
                             let old_geom = *data;
                             drop(borrowed);
                             let geom = crate::properties::evaluate_no_tracking(|| {
-                                item.as_ref().geometry()
+                                CachedItemGeometry::new(item, item.as_ref().geometry())
                             });
-                            self.mark_dirty_rect(old_geom, *offset);
-                            self.mark_dirty_rect(geom, *offset);
-                            ItemVisitorResult::Continue(*offset + geom.origin.to_vector())
+                            self.mark_dirty_rect(old_geom.bounding_rect, *offset);
+                            self.mark_dirty_rect(geom.bounding_rect, *offset);
+                            ItemVisitorResult::Continue(*offset + geom.geometry.origin.to_vector())
                         } else {
                             tr.as_ref().register_as_dependency_to_current_binding();
-                            ItemVisitorResult::Continue(*offset + data.origin.to_vector())
+                            ItemVisitorResult::Continue(*offset + data.geometry.origin.to_vector())
                         }
                     }
                     _ => {
                         drop(borrowed);
-                        let geom =
-                            crate::properties::evaluate_no_tracking(|| item.as_ref().geometry());
-                        self.mark_dirty_rect(geom, *offset);
-                        ItemVisitorResult::Continue(*offset + geom.origin.to_vector())
+                        let geom = crate::properties::evaluate_no_tracking(|| {
+                            CachedItemGeometry::new(item, item.as_ref().geometry())
+                        });
+                        self.mark_dirty_rect(geom.bounding_rect, *offset);
+                        ItemVisitorResult::Continue(*offset + geom.geometry.origin.to_vector())
                     }
                 }
             },
@@ -436,7 +509,7 @@ fn mark_dirty_rect(&mut self, rect: LogicalRect, offset: euclid::Vector2D<Coord,
     fn do_rendering(
         cache: &RefCell<PartialRenderingCache>,
         rendering_data: &CachedRenderingData,
-        render_fn: impl FnOnce() -> LogicalRect,
+        render_fn: impl FnOnce() -> CachedItemGeometry,
     ) {
         if let Some(entry) = rendering_data.get_entry(&mut cache.borrow_mut()) {
             entry
@@ -468,9 +541,12 @@ fn $fn(&mut self, obj: Pin<&$Ty>, item_rc: &ItemRc) {
                 let height = Ty::FIELD_OFFSETS.height.apply_pin(obj).get_untracked();
                 let x = Ty::FIELD_OFFSETS.x.apply_pin(obj).get_untracked();
                 let y = Ty::FIELD_OFFSETS.y.apply_pin(obj).get_untracked();
-                LogicalRect::new(
-                    LogicalPoint::from_lengths(x, y),
-                    LogicalSize::from_lengths(width, height),
+                CachedItemGeometry::new(
+                    item_rc.borrow(),
+                    LogicalRect::new(
+                        LogicalPoint::from_lengths(x, y),
+                        LogicalSize::from_lengths(width, height),
+                    ),
                 )
             })
         }
@@ -486,12 +562,15 @@ fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
                 dependency_tracker
                     .get_or_insert_with(|| Box::pin(crate::properties::PropertyTracker::default()))
                     .as_ref()
-                    .evaluate_if_dirty(|| *data = item.as_ref().geometry());
+                    .evaluate_if_dirty(|| {
+                        *data = CachedItemGeometry::new(item, item.as_ref().geometry())
+                    });
                 *data
             }
             None => {
-                let cache_entry =
-                    crate::graphics::CachedGraphicsData::new(|| item.as_ref().geometry());
+                let cache_entry = crate::graphics::CachedGraphicsData::new(|| {
+                    CachedItemGeometry::new(item, item.as_ref().geometry())
+                });
                 let geom = cache_entry.data;
                 rendering_data.cache_index.set(cache.insert(cache_entry));
                 rendering_data.cache_generation.set(cache.generation());
@@ -502,8 +581,8 @@ fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
         //let clip = self.get_current_clip().intersection(&self.dirty_region.to_rect());
         //let draw = clip.map_or(false, |r| r.intersects(&item_geometry));
         //FIXME: the dirty_region is in global coordinate but item_geometry and current_clip is not
-        let draw = self.get_current_clip().intersects(&item_geometry);
-        (draw, item_geometry)
+        let draw = self.get_current_clip().intersects(&item_geometry.bounding_rect);
+        (draw, item_geometry.geometry)
     }
 
     forward_rendering_call!(fn draw_rectangle(Rectangle));
@@ -512,10 +591,17 @@ fn filter_item(&mut self, item: Pin<ItemRef>) -> (bool, LogicalRect) {
     forward_rendering_call!(fn draw_clipped_image(ClippedImage));
     forward_rendering_call!(fn draw_text(Text));
     forward_rendering_call!(fn draw_text_input(TextInput));
-    #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
 
+    fn visit_opacity(&mut self, opacity_item: Pin<&Opacity>, self_rc: &ItemRc) -> RenderingResult {
+        self.actual_renderer.visit_opacity(opacity_item, self_rc)
+    }
+
+    fn visit_rotate(&mut self, rotate_item: Pin<&Rotate>, self_rc: &ItemRc) -> RenderingResult {
+        self.actual_renderer.visit_rotate(rotate_item, self_rc)
+    }
+
     fn combine_clip(
         &mut self,
         rect: LogicalRect,
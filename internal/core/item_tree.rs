@@ -180,6 +180,21 @@ pub fn accessible_string_property(
         result
     }
 
+    /// Execute the accessibility action on this item. Nothing happens if the item does not
+    /// support that action.
+    pub fn accessibility_action(&self, action: &crate::accessibility::AccessibilityAction) {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        comp_ref_pin.as_ref().accessibility_action(self.index, action);
+    }
+
+    /// Returns the accessibility actions supported by this item
+    pub fn supported_accessibility_actions(
+        &self,
+    ) -> crate::accessibility::SupportedAccessibilityAction {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        comp_ref_pin.as_ref().supported_accessibility_actions(self.index)
+    }
+
     pub fn geometry(&self) -> LogicalRect {
         self.borrow().as_ref().geometry()
     }
@@ -480,6 +495,13 @@ fn eq(&self, other: &Self) -> bool {
 
 impl Eq for ItemWeak {}
 
+impl core::hash::Hash for ItemWeak {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        VWeak::as_ptr(&self.component).hash(state);
+        self.index.hash(state);
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum TraversalOrder {
@@ -842,7 +864,9 @@ mod tests {
 
     use super::*;
 
-    use crate::accessibility::AccessibleStringProperty;
+    use crate::accessibility::{
+        AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+    };
     use crate::component::{Component, ComponentRc, ComponentVTable, ComponentWeak, IndexRange};
     use crate::items::AccessibleRole;
     use crate::layout::{LayoutInfo, Orientation};
@@ -920,6 +944,15 @@ fn accessible_string_property(
             _: &mut SharedString,
         ) {
         }
+
+        fn accessibility_action(self: Pin<&Self>, _: usize, _: &AccessibilityAction) {}
+
+        fn supported_accessibility_actions(
+            self: Pin<&Self>,
+            _: usize,
+        ) -> SupportedAccessibilityAction {
+            Default::default()
+        }
     }
 
     crate::component::ComponentVTable_static!(static TEST_COMPONENT_VT for TestComponent);
@@ -28,9 +28,7 @@
 use crate::item_rendering::CachedRenderingData;
 pub use crate::item_tree::ItemRc;
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{
-    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PointLengths,
-};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PointLengths};
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowAdapterRc, WindowInner};
@@ -42,15 +40,17 @@
 use i_slint_core_macros::*;
 use vtable::*;
 
+mod drag_n_drop;
+pub use drag_n_drop::*;
 mod flickable;
 pub use flickable::*;
+mod gestures;
+pub use gestures::*;
 mod text;
 pub use text::*;
 mod image;
 pub use self::image::*;
-#[cfg(feature = "std")]
 mod path;
-#[cfg(feature = "std")]
 pub use path::*;
 
 /// Alias for `&mut dyn ItemRenderer`. Required so cbindgen generates the ItemVTable
@@ -741,6 +741,109 @@ impl ItemConsts for FocusScope {
     fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
 }
 
+/// The implementation of the `Shortcut` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct Shortcut {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub activated: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Shortcut {
+    /// Returns true if the shortcut is enabled and its keys match the key event.
+    pub fn matches(self: Pin<&Self>, event: &KeyEvent) -> bool {
+        self.enabled() && event.matches_keys(self.keys().as_str())
+    }
+
+    /// Invokes the `activated` callback
+    pub fn activate(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.activated.apply_pin(self).call(&());
+    }
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        // The shortcuts are looked up by the window when the focus item doesn't handle the key
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        Shortcut,
+        CachedRenderingData,
+    > = Shortcut::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_ShortcutVTable() -> ShortcutVTable for Shortcut
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1113,14 +1216,9 @@ fn focus_event(
     fn render(
         self: Pin<&Self>,
         backend: &mut ItemRendererRef,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
     ) -> RenderingResult {
-        let origin =
-            LogicalVector::from_lengths(self.rotation_origin_x(), self.rotation_origin_y());
-        (*backend).translate(origin);
-        (*backend).rotate(self.rotation_angle());
-        (*backend).translate(-origin);
-        RenderingResult::ContinueRenderingChildren
+        backend.visit_rotate(self, self_rc)
     }
 }
 
@@ -1139,6 +1237,22 @@ fn slint_get_RotateVTable() -> RotateVTable for Rotate
     fn slint_get_FlickableVTable() -> FlickableVTable for Flickable
 }
 
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
+}
+
 /// The implementation of the `PropertyAnimation` element
 #[repr(C)]
 #[derive(FieldOffsets, SlintElement, Clone, Debug)]
@@ -1172,6 +1286,11 @@ pub struct WindowItem {
     pub background: Property<Brush>,
     pub title: Property<SharedString>,
     pub no_frame: Property<bool>,
+    pub full_screen: Property<bool>,
+    pub maximized: Property<bool>,
+    pub minimized: Property<bool>,
+    pub resizable: Property<bool>,
+    pub always_on_top: Property<bool>,
     pub icon: Property<crate::graphics::Image>,
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
@@ -1388,7 +1507,6 @@ fn slint_get_ImageItemVTable() -> ImageItemVTable for ImageItem
     fn slint_get_ClippedImageVTable() -> ClippedImageVTable for ClippedImage
 }
 
-#[cfg(feature = "std")]
 declare_item_vtable! {
     fn slint_get_PathVTable() -> PathVTable for Path
 }
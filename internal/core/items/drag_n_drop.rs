@@ -0,0 +1,363 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! The `DragArea` and `DropArea` items, which implement drag and drop within the window,
+//! and the drop of files from the desktop
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton, RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PointLengths};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The distance the mouse must move while pressed before the drag starts
+const DRAG_DISTANCE_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+
+/// The payload of a drag and drop operation
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct DropEvent {
+    /// The type of the data, such as `"text/plain"`
+    pub mime_type: SharedString,
+    pub data: SharedString,
+}
+
+impl DropEvent {
+    /// The event for a file dropped from the desktop: the data is the `file://` URI of the
+    /// file, with the `text/uri-list` mime type.
+    pub fn from_file_path(path: &str) -> Self {
+        let mut uri = alloc::string::String::from("file://");
+        if !path.starts_with(['/', '\\']) {
+            uri.push('/');
+        }
+        for b in path.bytes() {
+            if b == b'\\' {
+                uri.push('/');
+            } else if b.is_ascii_alphanumeric() || b"-._~/:".contains(&b) {
+                uri.push(b as char);
+            } else {
+                uri.push_str(&alloc::format!("%{:02X}", b));
+            }
+        }
+        Self { mime_type: "text/uri-list".into(), data: uri.into() }
+    }
+}
+
+type DropEventArg = (DropEvent,);
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pub dragging: Property<bool>,
+    pub drag_x: Property<LogicalLength>,
+    pub drag_y: Property<LogicalLength>,
+    pressed: Cell<bool>,
+    pressed_position: Cell<LogicalPoint>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left } => {
+                self.pressed.set(true);
+                self.pressed_position.set(position);
+            }
+            MouseEvent::Moved { position } => {
+                // Take the mouse from the children once the drag starts
+                if self.pressed.get() && !self.dragging() && self.exceeds_threshold(position) {
+                    return InputEventFilterResult::Intercept;
+                }
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                if !self.dragging() {
+                    self.pressed.set(false);
+                }
+            }
+            _ => (),
+        }
+        InputEventFilterResult::ForwardAndInterceptGrab
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        if !self.enabled() {
+            if self.dragging() {
+                window_inner.cancel_drag();
+                self.end_drag();
+            }
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left } => {
+                self.pressed.set(true);
+                self.pressed_position.set(position);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } => {
+                if !self.pressed.get() {
+                    return InputEventResult::EventAccepted;
+                }
+                if !self.dragging() && !self.exceeds_threshold(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                Self::FIELD_OFFSETS.dragging.apply_pin(self).set(true);
+                Self::FIELD_OFFSETS.drag_x.apply_pin(self).set(position.x_length());
+                Self::FIELD_OFFSETS.drag_y.apply_pin(self).set(position.y_length());
+                window_inner.process_drag_move(
+                    &self.drop_event(),
+                    window_inner.map_item_to_window(self_rc, position),
+                );
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left } => {
+                if self.dragging() {
+                    window_inner.process_drop(
+                        &self.drop_event(),
+                        window_inner.map_item_to_window(self_rc, position),
+                    );
+                }
+                self.end_drag();
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                if self.dragging() {
+                    window_inner.cancel_drag();
+                }
+                self.end_drag();
+                InputEventResult::EventAccepted
+            }
+            _ => {
+                if self.pressed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventAccepted
+                }
+            }
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    fn drop_event(self: Pin<&Self>) -> DropEvent {
+        DropEvent { mime_type: self.mime_type(), data: self.data() }
+    }
+
+    fn exceeds_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        let delta = (position - self.pressed_position.get()).cast::<f32>();
+        delta.square_length()
+            >= (DRAG_DISTANCE_THRESHOLD.get() * DRAG_DISTANCE_THRESHOLD.get()) as f32
+    }
+
+    fn end_drag(self: Pin<&Self>) {
+        self.pressed.set(false);
+        Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+    }
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub drag_x: Property<LogicalLength>,
+    pub drag_y: Property<LogicalLength>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DropArea {
+    /// The payload of a drag entered this item, at `position` in the coordinates of the item.
+    /// Returns true if the drop is accepted.
+    pub(crate) fn drag_enter(self: Pin<&Self>, event: &DropEvent, position: LogicalPoint) -> bool {
+        self.drag_move(position);
+        let can_drop = Self::FIELD_OFFSETS.can_drop.apply_pin(self);
+        // Without handler, any payload is accepted
+        let accepted = !can_drop.has_handler() || can_drop.call(&(event.clone(),));
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(accepted);
+        accepted
+    }
+
+    pub(crate) fn drag_move(self: Pin<&Self>, position: LogicalPoint) {
+        Self::FIELD_OFFSETS.drag_x.apply_pin(self).set(position.x_length());
+        Self::FIELD_OFFSETS.drag_y.apply_pin(self).set(position.y_length());
+    }
+
+    pub(crate) fn drag_leave(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+    }
+
+    pub(crate) fn drop_payload(self: Pin<&Self>, event: &DropEvent) {
+        Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event.clone(),));
+    }
+}
+
+#[test]
+fn drop_event_from_file_path() {
+    assert_eq!(
+        DropEvent::from_file_path("/home/user/My Pictures/été.png").data,
+        "file:///home/user/My%20Pictures/%C3%A9t%C3%A9.png"
+    );
+    assert_eq!(DropEvent::from_file_path("C:\\Users\\a.txt").data, "file:///C:/Users/a.txt");
+    assert_eq!(DropEvent::from_file_path("/a").mime_type, "text/uri-list");
+}
@@ -9,26 +9,29 @@
 //! The `Flickable` item
 
 use super::{
-    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton, RenderingResult,
+    Item, ItemConsts, ItemRc, ItemRef, ItemRendererRef, KeyEventResult, KeyEventType,
+    PointerEventButton, RenderingResult, VoidArg,
 };
 use crate::animations::{EasingCurve, Instant};
 use crate::input::{
-    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+    key_codes, FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
 use crate::items::{Empty, PropertyAnimation};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{
-    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PointLengths,
+    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PointLengths, RectLengths,
 };
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
 use crate::window::WindowAdapter;
-use crate::Property;
+use crate::{Callback, Coord, Property};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use core::time::Duration;
 #[allow(unused)]
@@ -50,6 +53,10 @@ pub struct Flickable {
     pub height: Property<LogicalLength>,
     pub viewport: Empty,
     pub interactive: Property<bool>,
+    pub deceleration_duration: Property<i64>,
+    pub overscroll: Property<bool>,
+    pub scrolling: Property<bool>,
+    pub flicked: Callback<VoidArg>,
     data: FlickableDataBox,
 
     /// FIXME: remove this
@@ -78,7 +85,7 @@ fn input_event_filter_before_children(
         self: Pin<&Self>,
         event: MouseEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
     ) -> InputEventFilterResult {
         if let Some(pos) = event.position() {
             if pos.x < 0 as _
@@ -92,14 +99,14 @@ fn input_event_filter_before_children(
         if !self.interactive() && !matches!(event, MouseEvent::Wheel { .. }) {
             return InputEventFilterResult::ForwardAndIgnore;
         }
-        self.data.handle_mouse_filter(self, event)
+        self.data.handle_mouse_filter(self, event, self_rc)
     }
 
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
         if !self.interactive() && !matches!(event, MouseEvent::Wheel { .. }) {
             return InputEventResult::EventIgnored;
@@ -115,16 +122,16 @@ fn input_event(
             }
         }
 
-        self.data.handle_mouse(self, event)
+        self.data.handle_mouse(self, event, self_rc)
     }
 
     fn key_event(
         self: Pin<&Self>,
-        _: &KeyEvent,
+        event: &KeyEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
     ) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+        self.data.handle_key(self, event, self_rc)
     }
 
     fn focus_event(
@@ -151,6 +158,51 @@ fn render(
     }
 }
 
+impl Flickable {
+    /// Scrolls the viewport with an animation, so that the point `(x, y)` of the viewport ends
+    /// up in the top left corner of the Flickable.
+    pub fn scroll_to(self: Pin<&Self>, x: LogicalLength, y: LogicalLength, self_rc: &ItemRc) {
+        let new_pos = ensure_in_bound(self, LogicalPoint::from_lengths(-x, -y));
+        self.data.animate_to(self, new_pos, scroll_animation(), self_rc);
+    }
+
+    /// Scrolls the viewport with an animation, by the smallest amount that makes the given
+    /// rectangle (in viewport coordinates) visible.
+    pub fn ensure_visible(self: Pin<&Self>, rect: LogicalRect, self_rc: &ItemRc) {
+        let pos = viewport_position(self);
+        let new_pos = LogicalPoint::from_lengths(
+            visible_range_position(
+                pos.x_length(),
+                self.width(),
+                rect.origin.x_length(),
+                rect.width_length(),
+            ),
+            visible_range_position(
+                pos.y_length(),
+                self.height(),
+                rect.origin.y_length(),
+                rect.height_length(),
+            ),
+        );
+        self.data.animate_to(self, ensure_in_bound(self, new_pos), scroll_animation(), self_rc);
+    }
+
+    /// Scrolls the viewport with an animation, so that the element created for the model index
+    /// `index` by a `for` directly in the viewport is visible.
+    ///
+    /// A `ListView` only creates the elements that are visible. For the other elements, the
+    /// position is estimated from the height of the created ones, and corrected at the end of
+    /// the animation.
+    pub fn ensure_item_visible(self: Pin<&Self>, index: usize, self_rc: &ItemRc) {
+        if let Some((rect, estimated)) = repeated_item_geometry(self_rc, index) {
+            self.ensure_visible(rect, self_rc);
+            if estimated {
+                self.data.pending_item_index.set(Some(index));
+            }
+        }
+    }
+}
+
 impl ItemConsts for Flickable {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
         Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
@@ -195,9 +247,13 @@ struct FlickableDataInner {
     capture_events: bool,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default)]
 pub struct FlickableData {
     inner: RefCell<FlickableDataInner>,
+    /// Resets the `scrolling` property once the animation of the viewport is over
+    scroll_end_timer: Timer,
+    /// The index of the element passed to `ensure_item_visible`, when its position was estimated
+    pending_item_index: Cell<Option<usize>>,
 }
 
 impl FlickableData {
@@ -205,20 +261,14 @@ pub fn handle_mouse_filter(
         &self,
         flick: Pin<&Flickable>,
         event: MouseEvent,
+        self_rc: &ItemRc,
     ) -> InputEventFilterResult {
         let mut inner = self.inner.borrow_mut();
         match event {
             MouseEvent::Pressed { position, button: PointerEventButton::Left } => {
                 inner.pressed_pos = position;
                 inner.pressed_time = Some(crate::animations::current_tick());
-                inner.pressed_viewport_pos = LogicalPoint::from_lengths(
-                    (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
-                        .apply_pin(flick)
-                        .get(),
-                    (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
-                        .apply_pin(flick)
-                        .get(),
-                );
+                inner.pressed_viewport_pos = viewport_position(flick);
                 if inner.capture_events {
                     InputEventFilterResult::Intercept
                 } else {
@@ -227,7 +277,7 @@ pub fn handle_mouse_filter(
             }
             MouseEvent::Exit | MouseEvent::Released { button: PointerEventButton::Left, .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event);
+                self.mouse_released(&mut inner, flick, event, self_rc);
                 if was_capturing {
                     InputEventFilterResult::Intercept
                 } else {
@@ -284,7 +334,12 @@ pub fn handle_mouse_filter(
         }
     }
 
-    pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEventResult {
+    pub fn handle_mouse(
+        &self,
+        flick: Pin<&Flickable>,
+        event: MouseEvent,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
         let mut inner = self.inner.borrow_mut();
         match event {
             MouseEvent::Pressed { .. } => {
@@ -293,7 +348,7 @@ pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEv
             }
             MouseEvent::Exit | MouseEvent::Released { .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event);
+                self.mouse_released(&mut inner, flick, event, self_rc);
                 if was_capturing {
                     InputEventResult::EventAccepted
                 } else {
@@ -302,10 +357,14 @@ pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEv
             }
             MouseEvent::Moved { position } => {
                 if inner.pressed_time.is_some() {
-                    let new_pos = ensure_in_bound(
-                        flick,
-                        inner.pressed_viewport_pos + (position - inner.pressed_pos),
-                    );
+                    let pos = inner.pressed_viewport_pos + (position - inner.pressed_pos);
+                    let new_pos = if flick.overscroll() {
+                        // Follow the pointer with some resistance beyond the bounds
+                        let bounded = ensure_in_bound(flick, pos);
+                        bounded + (pos - bounded) / (2 as Coord)
+                    } else {
+                        ensure_in_bound(flick, pos)
+                    };
                     let x = (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
                         .apply_pin(flick);
                     let y = (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
@@ -317,6 +376,10 @@ pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEv
                         x.set(new_pos.x_length());
                         y.set(new_pos.y_length());
                         inner.capture_events = true;
+                        drop(inner);
+                        self.scroll_end_timer.stop();
+                        Flickable::FIELD_OFFSETS.scrolling.apply_pin(flick).set(true);
+                        Flickable::FIELD_OFFSETS.flicked.apply_pin(flick).call(&());
                         InputEventResult::GrabMouse
                     } else {
                         InputEventResult::EventIgnored
@@ -327,14 +390,7 @@ pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEv
                 }
             }
             MouseEvent::Wheel { delta_x, delta_y, .. } => {
-                let old_pos = LogicalPoint::from_lengths(
-                    (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
-                        .apply_pin(flick)
-                        .get(),
-                    (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
-                        .apply_pin(flick)
-                        .get(),
-                );
+                let old_pos = viewport_position(flick);
                 let new_pos = ensure_in_bound(
                     flick,
                     old_pos + LogicalVector::new(delta_x as _, delta_y as _),
@@ -345,23 +401,67 @@ pub fn handle_mouse(&self, flick: Pin<&Flickable>, event: MouseEvent) -> InputEv
                 (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
                     .apply_pin(flick)
                     .set(new_pos.y_length());
+                drop(inner);
+                if new_pos != old_pos {
+                    Flickable::FIELD_OFFSETS.flicked.apply_pin(flick).call(&());
+                }
                 InputEventResult::EventAccepted
             }
         }
     }
 
-    fn mouse_released(inner: &mut FlickableDataInner, flick: Pin<&Flickable>, event: MouseEvent) {
+    /// Scrolls vertically with the PageUp, PageDown, Home and End keys
+    pub fn handle_key(
+        &self,
+        flick: Pin<&Flickable>,
+        event: &KeyEvent,
+        self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        if event.event_type != KeyEventType::KeyPressed {
+            return KeyEventResult::EventIgnored;
+        }
+        let viewport_height = (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.height)
+            .apply_pin(flick)
+            .get();
+        if viewport_height <= flick.height() {
+            return KeyEventResult::EventIgnored;
+        }
+        let pos = viewport_position(flick);
+        let y = match event.text.chars().next() {
+            Some(key_codes::PageUp) => pos.y_length() + flick.height(),
+            Some(key_codes::PageDown) => pos.y_length() - flick.height(),
+            Some(key_codes::Home) => LogicalLength::zero(),
+            Some(key_codes::End) => flick.height() - viewport_height,
+            _ => return KeyEventResult::EventIgnored,
+        };
+        let new_pos = ensure_in_bound(flick, LogicalPoint::from_lengths(pos.x_length(), y));
+        if new_pos != pos {
+            self.animate_to(flick, new_pos, scroll_animation(), self_rc);
+            Flickable::FIELD_OFFSETS.flicked.apply_pin(flick).call(&());
+        }
+        KeyEventResult::EventAccepted
+    }
+
+    fn mouse_released(
+        &self,
+        inner: &mut FlickableDataInner,
+        flick: Pin<&Flickable>,
+        event: MouseEvent,
+        self_rc: &ItemRc,
+    ) {
+        let mut animated = false;
         if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
             let dist = (pos - inner.pressed_pos).cast::<f32>();
 
             let millis = (crate::animations::current_tick() - pressed_time).as_millis();
+            let duration = flick.deceleration_duration().clamp(0, i32::MAX as i64) as i32;
             if inner.capture_events
                 && dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
                 && millis > 1
+                && duration > 0
             {
                 let speed = dist / (millis as f32);
 
-                let duration = 250;
                 let final_pos = ensure_in_bound(
                     flick,
                     (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
@@ -371,17 +471,134 @@ fn mouse_released(inner: &mut FlickableDataInner, flick: Pin<&Flickable>, event:
                     easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
                     ..PropertyAnimation::default()
                 };
-                (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
-                    .apply_pin(flick)
-                    .set_animated_value(final_pos.x_length(), anim.clone());
-                (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
-                    .apply_pin(flick)
-                    .set_animated_value(final_pos.y_length(), anim);
+                self.animate_to(flick, final_pos, anim, self_rc);
+                animated = true;
+            } else if inner.capture_events && flick.overscroll() {
+                // Bounce back within the bounds
+                let current_pos = viewport_position(flick);
+                let final_pos = ensure_in_bound(flick, current_pos);
+                if final_pos != current_pos {
+                    self.animate_to(flick, final_pos, scroll_animation(), self_rc);
+                    animated = true;
+                }
             }
         }
+        if inner.capture_events && !animated {
+            Flickable::FIELD_OFFSETS.scrolling.apply_pin(flick).set(false);
+        }
         inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
         inner.pressed_time = None;
     }
+
+    /// Animates the viewport to the given position, and sets `scrolling` for the duration of the animation
+    fn animate_to(
+        &self,
+        flick: Pin<&Flickable>,
+        new_pos: LogicalPoint,
+        anim: PropertyAnimation,
+        self_rc: &ItemRc,
+    ) {
+        let duration = anim.duration;
+        self.pending_item_index.set(None);
+        (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
+            .apply_pin(flick)
+            .set_animated_value(new_pos.x_length(), anim.clone());
+        (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y)
+            .apply_pin(flick)
+            .set_animated_value(new_pos.y_length(), anim);
+
+        Flickable::FIELD_OFFSETS.scrolling.apply_pin(flick).set(true);
+        let self_weak = self_rc.downgrade();
+        self.scroll_end_timer.start(
+            TimerMode::SingleShot,
+            Duration::from_millis(duration.max(0) as u64),
+            move || {
+                if let Some(self_rc) = self_weak.upgrade() {
+                    if let Some(flick) = ItemRef::downcast_pin::<Flickable>(self_rc.borrow()) {
+                        Flickable::FIELD_OFFSETS.scrolling.apply_pin(flick).set(false);
+                        // The element is now created, so its actual geometry is known
+                        if let Some(index) = flick.data.pending_item_index.take() {
+                            if let Some((rect, false)) = repeated_item_geometry(&self_rc, index) {
+                                flick.ensure_visible(rect, &self_rc);
+                            }
+                        }
+                    }
+                }
+            },
+        );
+    }
+}
+
+/// The animation used when scrolling programmatically or with the keyboard
+fn scroll_animation() -> PropertyAnimation {
+    PropertyAnimation {
+        duration: 250,
+        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+        ..PropertyAnimation::default()
+    }
+}
+
+fn viewport_position(flick: Pin<&Flickable>) -> LogicalPoint {
+    LogicalPoint::from_lengths(
+        (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x).apply_pin(flick).get(),
+        (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.y).apply_pin(flick).get(),
+    )
+}
+
+/// Returns the position of the viewport along one axis, moved as little as possible so that the
+/// range of `size` starting at `start` (in viewport coordinates) is visible.
+/// If the range is bigger than the visible size, its start is shown.
+fn visible_range_position(
+    viewport_pos: LogicalLength,
+    visible_size: LogicalLength,
+    start: LogicalLength,
+    size: LogicalLength,
+) -> LogicalLength {
+    if start + viewport_pos < LogicalLength::zero() || size > visible_size {
+        -start
+    } else if start + size + viewport_pos > visible_size {
+        visible_size - start - size
+    } else {
+        viewport_pos
+    }
+}
+
+/// Returns the geometry, in viewport coordinates, of the element created for the model index
+/// `index` by the repeater in the viewport of the Flickable `flick_rc`, and whether it is estimated
+/// because that element is not created.
+fn repeated_item_geometry(flick_rc: &ItemRc, index: usize) -> Option<(LogicalRect, bool)> {
+    let viewport = flick_rc.first_child()?;
+    let mut first = None;
+    let mut last = None;
+    let mut child = viewport.first_child();
+    while let Some(item) = child {
+        // The root items of the repeated components are the only children in another component
+        if !vtable::VRc::ptr_eq(&item.component(), &viewport.component()) {
+            let item_index = vtable::VRc::borrow_pin(&item.component()).as_ref().subtree_index();
+            let geometry = item.geometry();
+            if item_index == index {
+                return Some((geometry, false));
+            }
+            first = first.or(Some((item_index, geometry)));
+            last = Some((item_index, geometry));
+        }
+        child = item.next_sibling();
+    }
+    let ((first_index, first_geometry), (last_index, last_geometry)) = first.zip(last)?;
+    let height = (last_geometry.max_y() - first_geometry.min_y())
+        / ((last_index + 1 - first_index) as Coord);
+    let y = if index < first_index {
+        first_geometry.min_y() - height * ((first_index - index) as Coord)
+    } else {
+        last_geometry.max_y() + height * ((index - last_index - 1) as Coord)
+    };
+    Some((
+        LogicalRect::new(
+            LogicalPoint::new(first_geometry.min_x(), y),
+            LogicalSize::new(first_geometry.width(), height),
+        ),
+        true,
+    ))
 }
 
 /// Make sure that the point is within the bounds
@@ -414,3 +631,39 @@ fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint) -> LogicalPoint {
 pub unsafe extern "C" fn slint_flickable_data_free(data: *mut FlickableDataBox) {
     core::ptr::drop_in_place(data);
 }
+
+/// Scrolls the Flickable `self_rc` so that the point `(x, y)` of the viewport is in its top left corner.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn slint_flickable_scroll_to(self_rc: &ItemRc, x: Coord, y: Coord) {
+    if let Some(flick) = ItemRef::downcast_pin::<Flickable>(self_rc.borrow()) {
+        flick.scroll_to(LogicalLength::new(x), LogicalLength::new(y), self_rc);
+    }
+}
+
+/// Scrolls the Flickable `self_rc` so that the element at the given index of the repeater in its viewport is visible.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn slint_flickable_ensure_item_visible(self_rc: &ItemRc, index: usize) {
+    if let Some(flick) = ItemRef::downcast_pin::<Flickable>(self_rc.borrow()) {
+        flick.ensure_item_visible(index, self_rc);
+    }
+}
+
+/// Scrolls the Flickable `self_rc` so that the given rectangle of the viewport is visible.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn slint_flickable_ensure_visible(
+    self_rc: &ItemRc,
+    x: Coord,
+    y: Coord,
+    width: Coord,
+    height: Coord,
+) {
+    if let Some(flick) = ItemRef::downcast_pin::<Flickable>(self_rc.borrow()) {
+        flick.ensure_visible(
+            LogicalRect::new(LogicalPoint::new(x, y), LogicalSize::new(width, height)),
+            self_rc,
+        );
+    }
+}
@@ -0,0 +1,518 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! The `PinchGestureHandler` and `SwipeGestureHandler` items, which recognize gestures made
+//! with the touch points of a touch screen
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRef, ItemRendererRef, KeyEventResult, RenderingResult, VoidArg,
+};
+use crate::animations::Instant;
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+    TouchEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PointLengths};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::{Callback, Coord, Property};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::time::Duration;
+use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
+
+/// The distance the touch point must move before a swipe is recognized
+const SWIPE_DISTANCE_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+/// When the touch point stays still for that long before being released, the swipe ends
+/// without velocity
+const SWIPE_VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The implementation of the `PinchGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub center_x: Property<LogicalLength>,
+    pub center_y: Property<LogicalLength>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    data: GestureDataBox,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    /// Handle the touch event, which is in the coordinates of this item.
+    /// Returns true while the pinch is active.
+    fn touch_event(self: Pin<&Self>, event: TouchEvent) -> bool {
+        let mut inner = self.data.inner.borrow_mut();
+        let index = match inner.update_points(event) {
+            // The pinch only follows the two first touch points
+            Some(index) if index < 2 && !inner.cancelled => index,
+            _ => return self.active(),
+        };
+        if !self.enabled() {
+            drop(inner);
+            self.end();
+            return false;
+        }
+        match event {
+            TouchEvent::Pressed { .. } => {
+                if index == 1 {
+                    inner.pinch_start = distance_and_angle(&inner.points);
+                    let center = center(&inner.points);
+                    drop(inner);
+                    Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+                    Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+                    Self::FIELD_OFFSETS.center_x.apply_pin(self).set(center.x_length());
+                    Self::FIELD_OFFSETS.center_y.apply_pin(self).set(center.y_length());
+                    Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                    Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                }
+            }
+            TouchEvent::Moved { .. } => {
+                if self.active() {
+                    let (start_distance, start_angle) = inner.pinch_start;
+                    let (distance, angle) = distance_and_angle(&inner.points);
+                    let center = center(&inner.points);
+                    drop(inner);
+                    let scale = if start_distance > 0. { distance / start_distance } else { 1. };
+                    // Between -180 and 180 degrees
+                    let rotation = (angle - start_angle + 540.) % 360. - 180.;
+                    Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+                    Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+                    Self::FIELD_OFFSETS.center_x.apply_pin(self).set(center.x_length());
+                    Self::FIELD_OFFSETS.center_y.apply_pin(self).set(center.y_length());
+                    Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                }
+            }
+            TouchEvent::Released { .. } => {
+                drop(inner);
+                self.end();
+            }
+        }
+        self.active()
+    }
+
+    /// Stop recognizing the gesture until all the touch points are released
+    fn cancel(self: Pin<&Self>) {
+        self.data.inner.borrow_mut().cancelled = true;
+        self.end();
+    }
+
+    fn end(self: Pin<&Self>) {
+        if self.active() {
+            Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+        }
+    }
+}
+
+/// The implementation of the `SwipeGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct SwipeGestureHandler {
+    pub x: Property<LogicalLength>,
+    pub y: Property<LogicalLength>,
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub enabled: Property<bool>,
+    pub swiping: Property<bool>,
+    pub pressed_x: Property<LogicalLength>,
+    pub pressed_y: Property<LogicalLength>,
+    pub delta_x: Property<LogicalLength>,
+    pub delta_y: Property<LogicalLength>,
+    pub velocity_x: Property<LogicalLength>,
+    pub velocity_y: Property<LogicalLength>,
+    pub started: Callback<VoidArg>,
+    pub moved: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    data: GestureDataBox,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for SwipeGestureHandler {
+    fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
+
+    fn geometry(self: Pin<&Self>) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPoint::from_lengths(self.x(), self.y()),
+            LogicalSize::from_lengths(self.width(), self.height()),
+        )
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for SwipeGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl SwipeGestureHandler {
+    /// Handle the touch event, which is in the coordinates of this item.
+    /// Returns true while swiping.
+    fn touch_event(self: Pin<&Self>, event: TouchEvent) -> bool {
+        let mut inner = self.data.inner.borrow_mut();
+        let previous_position = inner.points.first().map(|p| p.position);
+        let index = match inner.update_points(event) {
+            Some(index) if !inner.cancelled => index,
+            _ => return self.swiping(),
+        };
+        if !self.enabled() {
+            drop(inner);
+            self.end();
+            return false;
+        }
+        if index > 0 {
+            // A swipe is made with a single finger. Another finger cancels the swipe
+            // unless it is already recognized.
+            if !self.swiping() {
+                inner.cancelled = true;
+            }
+            return self.swiping();
+        }
+        let now = crate::animations::current_tick();
+        match event {
+            TouchEvent::Pressed { position, .. } => {
+                inner.last_move_time = Some(now);
+                drop(inner);
+                Self::FIELD_OFFSETS.pressed_x.apply_pin(self).set(position.x_length());
+                Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
+            }
+            TouchEvent::Moved { position, .. } => {
+                let point = inner.points[0];
+                let delta = point.position - point.pressed_position;
+                let elapsed = inner
+                    .last_move_time
+                    .replace(now)
+                    .map_or(Duration::ZERO, |last_move_time| now.duration_since(last_move_time));
+                if !self.swiping()
+                    && delta.cast::<f32>().square_length()
+                        < (SWIPE_DISTANCE_THRESHOLD.get() * SWIPE_DISTANCE_THRESHOLD.get()) as f32
+                {
+                    return false;
+                }
+                drop(inner);
+                if elapsed > Duration::ZERO {
+                    let distance = (position - previous_position.unwrap_or(position)).cast::<f32>();
+                    let velocity = distance / elapsed.as_secs_f32();
+                    Self::FIELD_OFFSETS
+                        .velocity_x
+                        .apply_pin(self)
+                        .set(LogicalLength::new(velocity.x as Coord));
+                    Self::FIELD_OFFSETS
+                        .velocity_y
+                        .apply_pin(self)
+                        .set(LogicalLength::new(velocity.y as Coord));
+                }
+                Self::FIELD_OFFSETS.delta_x.apply_pin(self).set(LogicalLength::new(delta.x));
+                Self::FIELD_OFFSETS.delta_y.apply_pin(self).set(LogicalLength::new(delta.y));
+                if !self.swiping() {
+                    Self::FIELD_OFFSETS.swiping.apply_pin(self).set(true);
+                    Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                }
+                Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
+            }
+            TouchEvent::Released { .. } => {
+                let stopped = inner.last_move_time.map_or(true, |last_move_time| {
+                    now.duration_since(last_move_time) > SWIPE_VELOCITY_TIMEOUT
+                });
+                drop(inner);
+                if stopped {
+                    Self::FIELD_OFFSETS.velocity_x.apply_pin(self).set(LogicalLength::default());
+                    Self::FIELD_OFFSETS.velocity_y.apply_pin(self).set(LogicalLength::default());
+                }
+                self.end();
+            }
+        }
+        self.swiping()
+    }
+
+    /// Stop recognizing the gesture until all the touch points are released
+    fn cancel(self: Pin<&Self>) {
+        self.data.inner.borrow_mut().cancelled = true;
+        self.end();
+    }
+
+    fn end(self: Pin<&Self>) {
+        if self.swiping() {
+            Self::FIELD_OFFSETS.swiping.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+        }
+    }
+}
+
+/// Returns true if the item is a gesture handler
+pub(crate) fn is_gesture_handler(item: &ItemRc) -> bool {
+    let item = item.borrow();
+    ItemRef::downcast_pin::<PinchGestureHandler>(item).is_some()
+        || ItemRef::downcast_pin::<SwipeGestureHandler>(item).is_some()
+}
+
+/// Send the touch event to the gesture handler. The event is in the coordinates of the item.
+/// Returns true if the gesture is recognized.
+pub(crate) fn gesture_touch_event(item: &ItemRc, event: TouchEvent) -> bool {
+    let item = item.borrow();
+    if let Some(pinch) = ItemRef::downcast_pin::<PinchGestureHandler>(item) {
+        pinch.touch_event(event)
+    } else if let Some(swipe) = ItemRef::downcast_pin::<SwipeGestureHandler>(item) {
+        swipe.touch_event(event)
+    } else {
+        false
+    }
+}
+
+/// Stop the gesture of the gesture handler, because another gesture was recognized
+pub(crate) fn gesture_cancel(item: &ItemRc) {
+    let item = item.borrow();
+    if let Some(pinch) = ItemRef::downcast_pin::<PinchGestureHandler>(item) {
+        pinch.cancel()
+    } else if let Some(swipe) = ItemRef::downcast_pin::<SwipeGestureHandler>(item) {
+        swipe.cancel()
+    }
+}
+
+/// The distance and the angle (in degrees) between the two first touch points
+fn distance_and_angle(points: &[TouchPoint]) -> (f32, f32) {
+    let v = (points[1].position - points[0].position).cast::<f32>();
+    (v.length(), v.y.atan2(v.x).to_degrees())
+}
+
+/// The middle of the two first touch points
+fn center(points: &[TouchPoint]) -> LogicalPoint {
+    points[0].position.cast::<f32>().lerp(points[1].position.cast(), 0.5).cast()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TouchPoint {
+    id: u64,
+    /// The position where the point was pressed, in the coordinates of the handler
+    pressed_position: LogicalPoint,
+    /// The current position, in the coordinates of the handler
+    position: LogicalPoint,
+}
+
+#[derive(Default, Debug)]
+struct GestureDataInner {
+    /// The touch points, in the order in which they were pressed
+    points: Vec<TouchPoint>,
+    /// Set when the gesture can't be recognized anymore, until all the touch points are released
+    cancelled: bool,
+    /// The distance and the angle between the two touch points when the pinch started
+    pinch_start: (f32, f32),
+    /// The time of the last move of the swipe, to compute the velocity
+    last_move_time: Option<Instant>,
+}
+
+impl GestureDataInner {
+    /// Update the touch points with the event.
+    /// Returns the index of the touch point, or None if the touch point is not known.
+    fn update_points(&mut self, event: TouchEvent) -> Option<usize> {
+        match event {
+            TouchEvent::Pressed { id, position } => {
+                self.points.push(TouchPoint { id, pressed_position: position, position });
+                Some(self.points.len() - 1)
+            }
+            TouchEvent::Moved { id, position } => {
+                let index = self.points.iter().position(|p| p.id == id)?;
+                self.points[index].position = position;
+                Some(index)
+            }
+            TouchEvent::Released { id, .. } => {
+                let index = self.points.iter().position(|p| p.id == id)?;
+                self.points.remove(index);
+                if self.points.is_empty() {
+                    self.cancelled = false;
+                }
+                Some(index)
+            }
+        }
+    }
+}
+
+/// The internal state of the gesture handlers
+#[derive(Default, Debug)]
+pub struct GestureData {
+    inner: RefCell<GestureDataInner>,
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the gesture handlers
+pub struct GestureDataBox(core::ptr::NonNull<GestureData>);
+
+impl Default for GestureDataBox {
+    fn default() -> Self {
+        GestureDataBox(Box::leak(Box::new(GestureData::default())).into())
+    }
+}
+impl Drop for GestureDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in GestureDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for GestureDataBox {
+    type Target = GestureData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in GestureDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a GestureDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_gesture_data_init(data: *mut GestureDataBox) {
+    core::ptr::write(data, GestureDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized GestureDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_gesture_data_free(data: *mut GestureDataBox) {
+    core::ptr::drop_in_place(data);
+}
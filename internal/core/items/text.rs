@@ -639,6 +639,49 @@ pub struct TextInputVisualRepresentation {
     pub cursor_position: Option<usize>,
 }
 
+impl TextInputVisualRepresentation {
+    /// If the `text_input` is a password field, replaces every character of the text with
+    /// `password_character` and maps the pre-edit range, the selection and the cursor to the
+    /// byte offsets of the masked text.
+    pub fn apply_password_character_substitution(
+        &mut self,
+        text_input: Pin<&TextInput>,
+        password_character: char,
+    ) {
+        if !matches!(text_input.input_type(), InputType::Password) {
+            return;
+        }
+        let map = |offset| password_masked_byte_offset(&self.text, offset, password_character);
+        self.preedit_range = map(self.preedit_range.start)..map(self.preedit_range.end);
+        self.selection_range = map(self.selection_range.start)..map(self.selection_range.end);
+        self.cursor_position = self.cursor_position.map(map);
+        self.text =
+            core::iter::repeat(password_character).take(self.text.chars().count()).collect();
+    }
+}
+
+/// Maps a byte offset in `text` to the byte offset in the same text where every character is
+/// replaced with `password_character`.
+pub(crate) fn password_masked_byte_offset(
+    text: &str,
+    byte_offset: usize,
+    password_character: char,
+) -> usize {
+    text[..byte_offset.min(text.len())].chars().count() * password_character.len_utf8()
+}
+
+/// Maps a byte offset in the text masked with `password_character` back to the byte offset in
+/// `text`. This is the inverse of [`password_masked_byte_offset`].
+pub(crate) fn password_unmasked_byte_offset(
+    text: &str,
+    masked_byte_offset: usize,
+    password_character: char,
+) -> usize {
+    text.char_indices()
+        .nth(masked_byte_offset / password_character.len_utf8())
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
 impl TextInput {
     fn show_cursor(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         WindowInner::from_pub(window_adapter.window())
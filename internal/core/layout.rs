@@ -389,13 +389,21 @@ pub struct GridLayoutCellData {
     pub constraint: LayoutInfo,
 }
 
-/// return, an array which is of size `data.cells.len() * 2` which for each cell we give the pos, size
-pub fn solve_grid_layout(data: &GridLayoutData) -> SharedVector<Coord> {
+/// return, an array which is of size `data.cells.len() * 2 + repeater_indexes.len()` which for each cell we give the pos, size
+///
+/// The cells of the repeaters are laid out the same way as in [`solve_box_layout`]
+pub fn solve_grid_layout(
+    data: &GridLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
+
     let mut layout_data =
         grid_internal::to_layout_data(data.cells.as_slice(), data.spacing, Some(data.size));
 
     if layout_data.is_empty() {
-        return Default::default();
+        return result;
     }
 
     grid_internal::layout_items(
@@ -405,16 +413,12 @@ pub fn solve_grid_layout(data: &GridLayoutData) -> SharedVector<Coord> {
         data.spacing,
     );
 
-    let mut result = SharedVector::with_capacity(4 * data.cells.len());
-    for cell in data.cells.iter() {
-        let cdata = &layout_data[cell.col_or_row as usize];
-        result.push(cdata.pos);
-        result.push({
-            let first_cell = &layout_data[cell.col_or_row as usize];
-            let last_cell = &layout_data[cell.col_or_row as usize + cell.span as usize - 1];
-            last_cell.pos + last_cell.size - first_cell.pos
-        });
-    }
+    let cells = data.cells.iter().map(|cell| {
+        let first_cell = &layout_data[cell.col_or_row as usize];
+        let last_cell = &layout_data[cell.col_or_row as usize + cell.span as usize - 1];
+        (first_cell.pos, last_cell.pos + last_cell.size - first_cell.pos)
+    });
+    fill_layout_cache(result.make_mut_slice(), cells, repeater_indexes);
     result
 }
 
@@ -435,6 +439,26 @@ pub fn grid_layout_info(
     LayoutInfo { min, max, min_percent: 0 as _, max_percent: 100 as _, preferred, stretch }
 }
 
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_solve_grid_layout_with_repeater() {
+    let cell = |row| GridLayoutCellData {
+        col_or_row: row,
+        span: 1,
+        constraint: LayoutInfo { stretch: 1., ..Default::default() },
+    };
+    // A static cell, then a repeater with two instances, then another static cell
+    let cells = [cell(0), cell(1), cell(2), cell(3)];
+    let data = GridLayoutData {
+        size: 100.,
+        spacing: 0.,
+        padding: Padding::default(),
+        cells: Slice::from_slice(&cells),
+    };
+    let result = solve_grid_layout(&data, Slice::from_slice(&[1, 2]));
+    assert_eq!(result.as_slice(), &[0., 25., 6., 7., 75., 25., 25., 25., 50., 25.]);
+}
+
 #[repr(C)]
 #[derive(Debug)]
 /// The BoxLayoutData is used to represent both a Horizontal and Vertical layout.
@@ -527,8 +551,24 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
         }
     }
 
-    let res = result.make_mut_slice();
+    fill_layout_cache(
+        result.make_mut_slice(),
+        layout_data.iter().map(|l| (l.pos, l.size)),
+        repeater_indexes,
+    );
+    result
+}
 
+/// Store the position and size of each cell in the layout cache `res`.
+///
+/// `repeater_indexes` contains, for each repeater, the index of its first cell and its number of cells.
+/// The two entries of a repeater in the cache contain the index at which the cells of that repeater
+/// are stored, at the end of the cache.
+fn fill_layout_cache(
+    res: &mut [Coord],
+    cells: impl Iterator<Item = (Coord, Coord)>,
+    repeater_indexes: Slice<u32>,
+) {
     // The index/2 in result in which we should add the next repeated item
     let mut repeat_offset =
         res.len() / 2 - repeater_indexes.iter().skip(1).step_by(2).sum::<u32>() as usize;
@@ -536,7 +576,7 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
     let mut next_rep = 0;
     // The index/2 in result in which we should add the next non-repeated item
     let mut current_offset = 0;
-    for (idx, layout) in layout_data.iter().enumerate() {
+    for (idx, (pos, size)) in cells.enumerate() {
         let o = loop {
             if let Some(nr) = repeater_indexes.get(next_rep * 2) {
                 let nr = *nr as usize;
@@ -558,10 +598,9 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
             current_offset += 1;
             break current_offset - 1;
         };
-        res[o * 2] = layout.pos;
-        res[o * 2 + 1] = layout.size;
+        res[o * 2] = pos;
+        res[o * 2 + 1] = size;
     }
-    result
 }
 
 /// Return the LayoutInfo for a BoxLayout with the given cells.
@@ -685,9 +724,10 @@ pub(crate) mod ffi {
     #[no_mangle]
     pub extern "C" fn slint_solve_grid_layout(
         data: &GridLayoutData,
+        repeater_indexes: Slice<u32>,
         result: &mut SharedVector<Coord>,
     ) {
-        *result = super::solve_grid_layout(data)
+        *result = super::solve_grid_layout(data, repeater_indexes)
     }
 
     #[no_mangle]
@@ -40,6 +40,7 @@
 pub mod tests;
 pub mod textlayout;
 pub mod timers;
+pub mod translations;
 pub mod window;
 
 #[cfg(feature = "rtti")]
@@ -69,7 +70,6 @@
 #[doc(inline)]
 pub use graphics::RgbaColor;
 
-#[cfg(feature = "std")]
 #[doc(inline)]
 pub use graphics::PathData;
 use platform::Platform;
@@ -626,6 +626,14 @@ fn box_layout_data(
     ) -> crate::layout::BoxLayoutCellData {
         crate::layout::BoxLayoutCellData::default()
     }
+
+    /// Returns what's needed to perform the layout if this component is in a grid layout
+    fn grid_layout_data(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+    ) -> crate::layout::GridLayoutCellData {
+        crate::layout::GridLayoutCellData::default()
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -188,6 +188,13 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 ///
 /// The pointer variants describe events originating from an input device such as a mouse
 /// or a contact point on a touch-enabled surface.
+/// The touch variants describe each of the contact points of a multi-touch screen, which is
+/// needed for gestures such as pinch to zoom. Backends that send the touch variants must not
+/// also send pointer events for the same contact points: the first contact point is already
+/// handled like a pointer.
+/// The file variants describe files dragged from the desktop over the window. They are delivered
+/// to the `DropArea` elements. They refer to the files registered with
+/// [`Window::register_dragged_file()`](`crate::api::Window::register_dragged_file()`).
 ///
 /// All position fields are in logical window coordinates.
 #[allow(missing_docs)]
@@ -218,6 +225,24 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger started touching a touch screen.
+    TouchPressed {
+        /// Identifies the touch point in the following events, as long as it touches the screen.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger that touches a touch screen was moved.
+    TouchMoved {
+        /// The identifier of the touch point, from the [`WindowEvent::TouchPressed`] event.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger was lifted from a touch screen, or the touch was cancelled.
+    TouchReleased {
+        /// The identifier of the touch point, from the [`WindowEvent::TouchPressed`] event.
+        id: u64,
+        position: LogicalPosition,
+    },
     /// A key was pressed.
     KeyPressed {
         // FIXME: use SharedString instead of char (breaking change)
@@ -242,6 +267,23 @@ pub enum WindowEvent {
         /// ```
         text: char,
     },
+    /// A file is dragged over the window. When several files are dragged, this event is sent
+    /// for each of them.
+    FileHovered {
+        /// The index of the file, as returned by
+        /// [`Window::register_dragged_file()`](`crate::api::Window::register_dragged_file()`).
+        file: usize,
+        position: LogicalPosition,
+    },
+    /// A file was dropped on the window.
+    FileDropped {
+        /// The index of the file, as returned by
+        /// [`Window::register_dragged_file()`](`crate::api::Window::register_dragged_file()`).
+        file: usize,
+        position: LogicalPosition,
+    },
+    /// The files that were dragged over the window left it without being dropped.
+    FileHoverCancelled,
 }
 
 impl WindowEvent {
@@ -252,6 +294,11 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
+            WindowEvent::FileHovered { position, .. } => Some(*position),
+            WindowEvent::FileDropped { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -1128,6 +1128,9 @@ fn property_two_ways_recurse_from_binding() {
 mod properties_animations;
 pub use properties_animations::*;
 
+mod change_tracker;
+pub use change_tracker::*;
+
 /// Value of the state property
 ///
 /// A state is just the current state, but also has information about the previous state and the moment it changed
@@ -0,0 +1,218 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! The ChangeTracker calls a callback when the value of an expression changes.
+//!
+//! The callback is not called while the bindings are evaluated, but later: when a property the
+//! expression depends on is changed, the tracker is queued and a timer runs the queued trackers
+//! from the event loop.
+
+use super::*;
+use alloc::rc::Weak;
+use alloc::vec::Vec;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+    /// The trackers for which a dependency changed, and that need to be run
+    static PENDING_CHANGE_TRACKERS : RefCell<Vec<Weak<ChangeTrackerInner>>> = RefCell::default()
+);
+
+pub(super) trait ChangeTrackerCallbacks {
+    /// Evaluate the expression and return true if its value changed since the last evaluation
+    fn evaluate(&self) -> bool;
+    /// Call the callback with the current value
+    fn notify(&self);
+}
+
+struct ChangeTrackerDirtyHandler(Weak<ChangeTrackerInner>);
+
+impl PropertyDirtyHandler for ChangeTrackerDirtyHandler {
+    fn notify(&self) {
+        PENDING_CHANGE_TRACKERS.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            if pending.is_empty() {
+                crate::timers::Timer::single_shot(
+                    core::time::Duration::ZERO,
+                    ChangeTracker::run_change_handlers,
+                );
+            }
+            pending.push(self.0.clone());
+        })
+    }
+}
+
+struct ChangeTrackerInner {
+    tracker: Pin<Box<PropertyTracker<ChangeTrackerDirtyHandler>>>,
+    callbacks: Box<dyn ChangeTrackerCallbacks>,
+}
+
+impl ChangeTrackerInner {
+    fn new(callbacks: Box<dyn ChangeTrackerCallbacks>) -> Rc<Self> {
+        let inner = Rc::new_cyclic(|weak| Self {
+            tracker: Box::pin(PropertyTracker::new_with_dirty_handler(ChangeTrackerDirtyHandler(
+                weak.clone(),
+            ))),
+            callbacks,
+        });
+        // Evaluate once to get the initial value and register the dependencies
+        inner.tracker.as_ref().evaluate_as_dependency_root(|| inner.callbacks.evaluate());
+        inner
+    }
+
+    fn run(&self) {
+        if self.tracker.is_dirty()
+            && self.tracker.as_ref().evaluate_as_dependency_root(|| self.callbacks.evaluate())
+        {
+            self.callbacks.notify();
+        }
+    }
+}
+
+/// Calls a callback when the value of an expression changes.
+///
+/// The callback is not called for the initial value, and it is never called from within the
+/// evaluation of a binding: the change is only detected when [`Self::run_change_handlers()`]
+/// is called, which happens from a timer started as soon as one of the dependencies changes.
+#[derive(Default)]
+#[repr(C)]
+pub struct ChangeTracker {
+    inner: Cell<Option<Rc<ChangeTrackerInner>>>,
+}
+
+impl ChangeTracker {
+    /// Initialize the tracker.
+    ///
+    /// `eval_fn` is evaluated now and each time one of the properties it depends on changes.
+    /// When the result is different from the previous value, `notify_fn` is called with it.
+    /// Calling `init` again replaces the previous functions.
+    pub fn init<Data, T, EF, NF>(&self, data: Data, eval_fn: EF, notify_fn: NF)
+    where
+        Data: 'static,
+        T: PartialEq + 'static,
+        EF: Fn(&Data) -> T + 'static,
+        NF: Fn(&Data, &T) + 'static,
+    {
+        struct Callbacks<Data, T, EF, NF> {
+            data: Data,
+            value: RefCell<Option<T>>,
+            eval_fn: EF,
+            notify_fn: NF,
+        }
+
+        impl<Data, T: PartialEq, EF: Fn(&Data) -> T, NF: Fn(&Data, &T)> ChangeTrackerCallbacks
+            for Callbacks<Data, T, EF, NF>
+        {
+            fn evaluate(&self) -> bool {
+                let new_value = (self.eval_fn)(&self.data);
+                let mut value = self.value.borrow_mut();
+                if value.as_ref() == Some(&new_value) {
+                    return false;
+                }
+                let is_initial_value = value.is_none();
+                *value = Some(new_value);
+                !is_initial_value
+            }
+
+            fn notify(&self) {
+                if let Some(value) = self.value.borrow().as_ref() {
+                    (self.notify_fn)(&self.data, value)
+                }
+            }
+        }
+
+        self.init_with_callbacks(Box::new(Callbacks {
+            data,
+            value: RefCell::new(None),
+            eval_fn,
+            notify_fn,
+        }));
+    }
+
+    pub(super) fn init_with_callbacks(&self, callbacks: Box<dyn ChangeTrackerCallbacks>) {
+        self.inner.set(Some(ChangeTrackerInner::new(callbacks)));
+    }
+
+    /// Run the callbacks of the trackers whose value has changed.
+    ///
+    /// This is called from a timer, and from the event loop when timers are processed.
+    pub fn run_change_handlers() {
+        let pending =
+            PENDING_CHANGE_TRACKERS.with(|pending| core::mem::take(&mut *pending.borrow_mut()));
+        for tracker in pending {
+            if let Some(tracker) = tracker.upgrade() {
+                tracker.run();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod change_tracker_tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn change_tracker() {
+        let prop = Rc::pin(Property::new(10));
+        let doubled = Rc::pin(Property::new(0));
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let tracker = ChangeTracker::default();
+        tracker.init(
+            (prop.clone(), changes.clone()),
+            |(prop, _)| prop.as_ref().get(),
+            |(_, changes), value| changes.borrow_mut().push(*value),
+        );
+        let prop2 = prop.clone();
+        doubled.as_ref().set_binding(move || prop2.as_ref().get() * 2);
+
+        ChangeTracker::run_change_handlers();
+        assert!(changes.borrow().is_empty());
+
+        prop.as_ref().set(11);
+        // Not called until the change handlers are run
+        assert!(changes.borrow().is_empty());
+        assert_eq!(doubled.as_ref().get(), 22);
+        ChangeTracker::run_change_handlers();
+        assert_eq!(*changes.borrow(), vec![11]);
+
+        // Setting the same value doesn't call the callback
+        prop.as_ref().set(12);
+        prop.as_ref().set(11);
+        ChangeTracker::run_change_handlers();
+        assert_eq!(*changes.borrow(), vec![11]);
+
+        prop.as_ref().set(13);
+        drop(tracker);
+        ChangeTracker::run_change_handlers();
+        assert_eq!(*changes.borrow(), vec![11]);
+    }
+
+    #[test]
+    fn change_tracker_chained() {
+        // A change callback that changes a property tracked by another tracker
+        let prop1 = Rc::pin(Property::new(1));
+        let prop2 = Rc::pin(Property::new(0));
+        let tracker1 = ChangeTracker::default();
+        tracker1.init(
+            (prop1.clone(), prop2.clone()),
+            |(prop1, _)| prop1.as_ref().get(),
+            |(_, prop2), value| prop2.as_ref().set(*value * 10),
+        );
+        let result = Rc::new(Cell::new(0));
+        let tracker2 = ChangeTracker::default();
+        tracker2.init(
+            (prop2.clone(), result.clone()),
+            |(prop2, _)| prop2.as_ref().get(),
+            |(_, result), value| result.set(*value),
+        );
+
+        prop1.as_ref().set(2);
+        ChangeTracker::run_change_handlers();
+        assert_eq!(prop2.as_ref().get(), 20);
+        assert_eq!(result.get(), 0);
+        ChangeTracker::run_change_handlers();
+        assert_eq!(result.get(), 20);
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
+use super::change_tracker::ChangeTrackerCallbacks;
 use super::*;
 use crate::graphics::{Brush, Color};
 use crate::items::PropertyAnimation;
@@ -456,3 +457,67 @@ pub struct PropertyTrackerOpaque {
 pub extern "C" fn slint_animation_tick() -> u64 {
     crate::animations::animation_tick()
 }
+
+#[repr(C)]
+/// Opaque type representing the ChangeTracker
+pub struct ChangeTrackerOpaque {
+    inner: *const c_void,
+}
+
+static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+struct FfiChangeTrackerCallbacks {
+    user_data: *mut c_void,
+    eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+    notify_fn: extern "C" fn(user_data: *mut c_void),
+    drop_user_data: extern "C" fn(user_data: *mut c_void),
+}
+
+impl ChangeTrackerCallbacks for FfiChangeTrackerCallbacks {
+    fn evaluate(&self) -> bool {
+        (self.eval_fn)(self.user_data)
+    }
+    fn notify(&self) {
+        (self.notify_fn)(self.user_data)
+    }
+}
+
+impl Drop for FfiChangeTrackerCallbacks {
+    fn drop(&mut self) {
+        (self.drop_user_data)(self.user_data)
+    }
+}
+
+/// Initialize the ChangeTracker.
+/// `out` is assumed to be uninitialized
+/// slint_change_tracker_drop need to be called after that
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+    core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+}
+
+/// Set the functions of the tracker, and evaluate `eval_fn` once.
+/// `eval_fn` must return true if the value changed since its previous call (false on the first call),
+/// and `notify_fn` is called after it returned true.
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_init(
+    handle: *const ChangeTrackerOpaque,
+    user_data: *mut c_void,
+    eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+    notify_fn: extern "C" fn(user_data: *mut c_void),
+    drop_user_data: extern "C" fn(user_data: *mut c_void),
+) {
+    (*(handle as *const ChangeTracker)).init_with_callbacks(Box::new(FfiChangeTrackerCallbacks {
+        user_data,
+        eval_fn,
+        notify_fn,
+        drop_user_data,
+    }));
+}
+
+/// Destroy handle
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_drop(handle: *mut ChangeTrackerOpaque) {
+    core::ptr::drop_in_place(handle as *mut ChangeTracker);
+}
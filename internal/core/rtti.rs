@@ -40,6 +40,7 @@ macro_rules! declare_ValueType_2 {
             crate::Brush,
             crate::graphics::Point,
             crate::items::PointerEvent,
+            crate::items::DropEvent,
             crate::lengths::LogicalLength,
             $(crate::items::$Name,)*
         ];
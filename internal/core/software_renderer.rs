@@ -5,19 +5,21 @@
 
 #![warn(missing_docs)]
 
+mod box_shadow;
 mod draw_functions;
 mod fonts;
+mod path;
 
 use crate::api::Window;
-use crate::graphics::{IntRect, PixelFormat, SharedImageBuffer};
+use crate::graphics::{GradientStop, IntRect, PixelFormat, SharedImageBuffer};
 use crate::item_rendering::ItemRenderer;
-use crate::items::{ImageFit, Item, ItemRc};
+use crate::items::{ImageFit, Item, ItemRc, RenderingResult};
 use crate::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PhysicalPx, PointLengths,
     RectLengths, ScaleFactor, SizeLengths,
 };
 use crate::renderer::Renderer;
-use crate::textlayout::{AbstractFont, TextParagraphLayout};
+use crate::textlayout::{AbstractFont, FontMetrics, TextParagraphLayout};
 use crate::window::{WindowAdapter, WindowInner};
 use crate::{Brush, Color, Coord, ImageInner, StaticTextures};
 use alloc::rc::{Rc, Weak};
@@ -25,6 +27,8 @@
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use euclid::num::Zero;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 
 pub use draw_functions::{PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
 
@@ -97,6 +101,7 @@ pub struct SoftwareRenderer<const MAX_BUFFER_AGE: usize> {
     /// generic parameters
     prev_frame_dirty: [Cell<DirtyRegion>; MAX_BUFFER_AGE],
     window: Weak<dyn crate::window::WindowAdapter>,
+    box_shadow_cache: RefCell<box_shadow::BoxShadowCache>,
 }
 
 impl<const MAX_BUFFER_AGE: usize> SoftwareRenderer<MAX_BUFFER_AGE> {
@@ -110,6 +115,7 @@ pub fn new(window: Weak<dyn crate::window::WindowAdapter>) -> Self {
             partial_cache: Default::default(),
             force_dirty: Default::default(),
             prev_frame_dirty: [DirtyRegion::default(); MAX_BUFFER_AGE].map(|x| x.into()),
+            box_shadow_cache: Default::default(),
         }
     }
 
@@ -169,6 +175,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], buffer_stride: usize) {
             size,
             factor,
             window_inner,
+            &self.box_shadow_cache,
             RenderToBuffer { buffer, stride: buffer_stride },
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
@@ -193,11 +200,16 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], buffer_stride: usize) {
             );
 
             if !background.is_transparent() {
-                // FIXME: gradient
-                renderer
-                    .actual_renderer
-                    .processor
-                    .process_rectangle(to_draw, background.color().into());
+                let processor = &mut renderer.actual_renderer.processor;
+                match GradientCommand::new(
+                    &background,
+                    PhysicalRect { origin: euclid::point2(0, 0), size },
+                    to_draw,
+                    1.,
+                ) {
+                    Some(gradient) => processor.process_gradient(to_draw, gradient),
+                    None => processor.process_rectangle(to_draw, background.color().into()),
+                }
             }
             for (component, origin) in components {
                 crate::item_rendering::render_component_items(component, &mut renderer, *origin);
@@ -273,17 +285,98 @@ fn text_size(
 
     fn text_input_byte_offset_for_position(
         &self,
-        _text_input: Pin<&crate::items::TextInput>,
-        _pos: LogicalPoint,
+        text_input: Pin<&crate::items::TextInput>,
+        pos: LogicalPoint,
     ) -> usize {
-        0
+        let window_adapter = match self.window.upgrade() {
+            Some(window) => window,
+            None => return 0,
+        };
+        let scale_factor = ScaleFactor::new(window_adapter.window().scale_factor());
+        let pos: PhysicalPoint = (pos.cast() * scale_factor).cast();
+        let size = (text_input.geometry().size.cast() * scale_factor).cast();
+
+        let text = text_input.text();
+        let displayed_text = text_input_displayed_text(text_input, &text);
+        let font_request = text_input.font_request(&window_adapter);
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        let byte_offset = match font {
+            fonts::Font::PixelFont(pf) => text_input_paragraph_layout(
+                text_input,
+                &displayed_text,
+                fonts::text_layout_for_font(&pf, &font_request, scale_factor),
+                size,
+            )
+            .byte_offset_for_position((pos.x_length(), pos.y_length())),
+            #[cfg(feature = "systemfonts")]
+            fonts::Font::VectorFont(vf) => text_input_paragraph_layout(
+                text_input,
+                &displayed_text,
+                fonts::text_layout_for_font(&vf, &font_request, scale_factor),
+                size,
+            )
+            .byte_offset_for_position((pos.x_length(), pos.y_length())),
+        };
+
+        if let crate::items::InputType::Password = text_input.input_type() {
+            crate::items::password_unmasked_byte_offset(&text, byte_offset, PASSWORD_CHARACTER)
+        } else {
+            byte_offset
+        }
     }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
-        _text_input: Pin<&crate::items::TextInput>,
-        _byte_offset: usize,
+        text_input: Pin<&crate::items::TextInput>,
+        byte_offset: usize,
     ) -> LogicalRect {
-        Default::default()
+        let window_adapter = match self.window.upgrade() {
+            Some(window) => window,
+            None => return Default::default(),
+        };
+        let scale_factor = ScaleFactor::new(window_adapter.window().scale_factor());
+        let size = (text_input.geometry().size.cast() * scale_factor).cast();
+
+        let text = text_input.text();
+        let displayed_text = text_input_displayed_text(text_input, &text);
+        let byte_offset = if let crate::items::InputType::Password = text_input.input_type() {
+            crate::items::password_masked_byte_offset(&text, byte_offset, PASSWORD_CHARACTER)
+        } else {
+            byte_offset
+        };
+        let font_request = text_input.font_request(&window_adapter);
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        let ((cursor_x, cursor_y), font_height) = match font {
+            fonts::Font::PixelFont(pf) => {
+                let paragraph = text_input_paragraph_layout(
+                    text_input,
+                    &displayed_text,
+                    fonts::text_layout_for_font(&pf, &font_request, scale_factor),
+                    size,
+                );
+                (paragraph.cursor_pos_for_byte_offset(byte_offset), pf.height())
+            }
+            #[cfg(feature = "systemfonts")]
+            fonts::Font::VectorFont(vf) => {
+                let paragraph = text_input_paragraph_layout(
+                    text_input,
+                    &displayed_text,
+                    fonts::text_layout_for_font(&vf, &font_request, scale_factor),
+                    size,
+                );
+                (paragraph.cursor_pos_for_byte_offset(byte_offset), vf.height())
+            }
+        };
+
+        LogicalRect::new(
+            (PhysicalPoint::from_lengths(cursor_x, cursor_y).cast() / scale_factor).cast(),
+            LogicalSize::from_lengths(
+                LogicalLength::new(1 as Coord),
+                (font_height.cast() / scale_factor).cast(),
+            ),
+        )
     }
 
     fn free_graphics_resources(
@@ -338,8 +431,14 @@ fn render_window_frame_by_line<const MAX_BUFFER_AGE: usize>(
 
     debug_assert!(scene.current_line >= dirty_region.origin.y_length());
 
-    // FIXME gradient
     let background_color = background.color().into();
+    let background_gradient = GradientCommand::new(
+        &background,
+        PhysicalRect { origin: euclid::point2(0, 0), size },
+        dirty_region,
+        1.,
+    );
+    let mut path_buffers = path::PathLineBuffers::default();
 
     while scene.current_line < dirty_region.origin.y_length() + dirty_region.size.height_length() {
         line_buffer.process_line(
@@ -348,7 +447,18 @@ fn render_window_frame_by_line<const MAX_BUFFER_AGE: usize>(
             |line_buffer| {
                 let offset = dirty_region.min_x() as usize;
 
-                TargetPixel::blend_slice(line_buffer, background_color);
+                match &background_gradient {
+                    Some(gradient) => draw_functions::draw_gradient_line(
+                        &PhysicalRect {
+                            origin: euclid::point2(0, dirty_region.origin.y),
+                            size: dirty_region.size,
+                        },
+                        scene.current_line,
+                        gradient,
+                        line_buffer,
+                    ),
+                    None => TargetPixel::blend_slice(line_buffer, background_color),
+                }
                 for span in scene.items[0..scene.current_items_index].iter().rev() {
                     debug_assert!(scene.current_line >= span.pos.y_length());
                     debug_assert!(
@@ -401,6 +511,31 @@ fn render_window_frame_by_line<const MAX_BUFFER_AGE: usize>(
                                 line_buffer,
                             );
                         }
+                        SceneCommand::Gradient { gradient_index } => {
+                            let gradient = &scene.gradients[gradient_index as usize];
+                            draw_functions::draw_gradient_line(
+                                &PhysicalRect {
+                                    origin: span.pos - euclid::vec2(offset as i16, 0),
+                                    size: span.size,
+                                },
+                                scene.current_line,
+                                gradient,
+                                line_buffer,
+                            );
+                        }
+                        SceneCommand::Path { path_index } => {
+                            let path = &scene.paths[path_index as usize];
+                            path::draw_path_line(
+                                &PhysicalRect {
+                                    origin: span.pos - euclid::vec2(offset as i16, 0),
+                                    size: span.size,
+                                },
+                                scene.current_line,
+                                path,
+                                &mut path_buffers,
+                                line_buffer,
+                            );
+                        }
                     }
                 }
             },
@@ -429,6 +564,8 @@ struct Scene {
     textures: Vec<SceneTexture<'static>>,
     rounded_rectangles: Vec<RoundedRectangle>,
     shared_buffers: Vec<SharedBufferCommand>,
+    gradients: Vec<GradientCommand>,
+    paths: Vec<path::PathCommand>,
     dirty_region: DirtyRegion,
 }
 
@@ -438,6 +575,8 @@ pub fn new(
         textures: Vec<SceneTexture<'static>>,
         rounded_rectangles: Vec<RoundedRectangle>,
         shared_buffers: Vec<SharedBufferCommand>,
+        gradients: Vec<GradientCommand>,
+        paths: Vec<path::PathCommand>,
         dirty_region: DirtyRegion,
     ) -> Self {
         let current_line = dirty_region.origin.y_length();
@@ -453,6 +592,8 @@ pub fn new(
             textures,
             rounded_rectangles,
             shared_buffers,
+            gradients,
+            paths,
             dirty_region,
         }
     }
@@ -607,6 +748,14 @@ enum SceneCommand {
     RoundedRectangle {
         rectangle_index: u16,
     },
+    /// gradient_index is an index in the Scene::gradients array
+    Gradient {
+        gradient_index: u16,
+    },
+    /// path_index is an index in the Scene::paths array
+    Path {
+        path_index: u16,
+    },
 }
 
 struct SceneTexture<'a> {
@@ -676,7 +825,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 }
             }
             SharedBufferData::AlphaMap { data, width } => SceneTexture {
-                data: &data[begin * 4..],
+                data: &data[begin..],
                 stride: *width,
                 format: PixelFormat::AlphaMap,
                 source_size: self.source_rect.size,
@@ -701,6 +850,114 @@ struct RoundedRectangle {
     right_clip: PhysicalLength,
     top_clip: PhysicalLength,
     bottom_clip: PhysicalLength,
+    /// When set, the inside is filled with this gradient instead of `inner_color`
+    inner_gradient: Option<GradientCommand>,
+    /// When set, the border is drawn with this gradient instead of `border_color`
+    border_gradient: Option<GradientCommand>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum GradientKind {
+    /// The position on the gradient is the projection of the pixel on the `start` + `direction` line,
+    /// where `direction` is scaled such that the end of the gradient is at 1.
+    Linear { start: euclid::Point2D<f32, PhysicalPx>, direction: euclid::Vector2D<f32, PhysicalPx> },
+    /// The position on the gradient is the distance from the `center`, divided by the `radius`
+    Radial { center: euclid::Point2D<f32, PhysicalPx>, radius: f32 },
+}
+
+/// A linear or radial gradient, evaluated for each pixel
+#[derive(Debug, Clone)]
+struct GradientCommand {
+    kind: GradientKind,
+    stops: Vec<GradientStop>,
+    /// The alpha the gradient colors are multiplied with
+    alpha: u8,
+    /// The amount of pixels of the gradient's shape that are clipped away on the left and on the top.
+    /// The coordinates passed to `color_at` are relative to the clipped geometry.
+    left_clip: PhysicalLength,
+    top_clip: PhysicalLength,
+}
+
+impl GradientCommand {
+    /// Returns the gradient command for the brush spread over the `geometry`, and drawn in `clipped`
+    /// (both in physical coordinates), or None if the brush is not a gradient.
+    fn new(
+        brush: &Brush,
+        geometry: PhysicalRect,
+        clipped: PhysicalRect,
+        alpha: f32,
+    ) -> Option<Self> {
+        let size = geometry.size.cast::<f32>();
+        let (kind, stops) = match brush {
+            Brush::SolidColor(_) => return None,
+            Brush::LinearGradient(g) => {
+                let (start, end) = crate::graphics::line_for_angle(g.angle());
+                let start = euclid::point2(start.x * size.width, start.y * size.height);
+                let end = euclid::point2(end.x * size.width, end.y * size.height);
+                let direction: euclid::Vector2D<f32, PhysicalPx> = end - start;
+                let len2 = direction.square_length();
+                let direction = if len2 > 0. { direction / len2 } else { direction };
+                (GradientKind::Linear { start, direction }, g.stops().cloned().collect())
+            }
+            Brush::RadialGradient(g) => (
+                GradientKind::Radial {
+                    center: (size / 2.).to_vector().to_point(),
+                    radius: (size.width + size.height) / 4.,
+                },
+                g.stops().cloned().collect(),
+            ),
+        };
+        Some(Self {
+            kind,
+            stops,
+            alpha: (alpha.clamp(0., 1.) * 255.) as u8,
+            left_clip: clipped.origin.x_length() - geometry.origin.x_length(),
+            top_clip: clipped.origin.y_length() - geometry.origin.y_length(),
+        })
+    }
+
+    /// The color of the pixel at position `x` and `y`, relative to the clipped geometry
+    fn color_at(&self, x: i16, y: i16) -> PremultipliedRgbaColor {
+        // use the center of the pixel
+        let p = euclid::point2::<f32, PhysicalPx>(
+            (x + self.left_clip.get()) as f32 + 0.5,
+            (y + self.top_clip.get()) as f32 + 0.5,
+        );
+        let pos = match self.kind {
+            GradientKind::Linear { start, direction } => (p - start).dot(direction),
+            GradientKind::Radial { center, radius } => {
+                if radius > 0. {
+                    (p - center).length() / radius
+                } else {
+                    1.
+                }
+            }
+        };
+        // Index of the first stop that is after pos
+        let idx = self.stops.partition_point(|s| s.position <= pos);
+        let color = if idx == 0 {
+            self.stops.first().map_or(Color::default(), |s| s.color)
+        } else if idx == self.stops.len() {
+            self.stops[idx - 1].color
+        } else {
+            let (s1, s2) = (&self.stops[idx - 1], &self.stops[idx]);
+            let f = (((pos - s1.position) / (s2.position - s1.position)) * 255.) as u32;
+            let f = f.min(255);
+            let mix = |a: u8, b: u8| ((a as u32 * (255 - f) + b as u32 * f) / 255) as u8;
+            Color::from_argb_u8(
+                mix(s1.color.alpha(), s2.color.alpha()),
+                mix(s1.color.red(), s2.color.red()),
+                mix(s1.color.green(), s2.color.green()),
+                mix(s1.color.blue(), s2.color.blue()),
+            )
+        };
+        let alpha = if self.alpha == 255 {
+            color.alpha()
+        } else {
+            (color.alpha() as u16 * self.alpha as u16 / 255) as u8
+        };
+        Color::from_argb_u8(alpha, color.red(), color.green(), color.blue()).into()
+    }
 }
 
 fn prepare_scene<const MAX_BUFFER_AGE: usize>(
@@ -709,7 +966,13 @@ fn prepare_scene<const MAX_BUFFER_AGE: usize>(
     software_renderer: &SoftwareRenderer<MAX_BUFFER_AGE>,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
-    let prepare_scene = SceneBuilder::new(size, factor, window, PrepareScene::default());
+    let prepare_scene = SceneBuilder::new(
+        size,
+        factor,
+        window,
+        &software_renderer.box_shadow_cache,
+        PrepareScene::default(),
+    );
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
         software_renderer.force_dirty.take(),
@@ -741,6 +1004,8 @@ fn prepare_scene<const MAX_BUFFER_AGE: usize>(
         prepare_scene.processor.textures,
         prepare_scene.processor.rounded_rectangles,
         prepare_scene.processor.shared_buffers,
+        prepare_scene.processor.gradients,
+        prepare_scene.processor.paths,
         dirty_region,
     )
 }
@@ -750,6 +1015,8 @@ trait ProcessScene {
     fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor);
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand);
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
@@ -801,6 +1068,30 @@ fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, rr: RoundedRecta
             );
         }
     }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        for line in geometry.min_y()..geometry.max_y() {
+            draw_functions::draw_gradient_line(
+                &geometry,
+                PhysicalLength::new(line),
+                &gradient,
+                &mut self.buffer[line as usize * self.stride..],
+            );
+        }
+    }
+
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand) {
+        let mut buffers = path::PathLineBuffers::default();
+        for line in geometry.min_y()..geometry.max_y() {
+            path::draw_path_line(
+                &geometry,
+                PhysicalLength::new(line),
+                &path,
+                &mut buffers,
+                &mut self.buffer[line as usize * self.stride..],
+            );
+        }
+    }
 }
 
 #[derive(Default)]
@@ -809,6 +1100,8 @@ struct PrepareScene {
     textures: Vec<SceneTexture<'static>>,
     rounded_rectangles: Vec<RoundedRectangle>,
     shared_buffers: Vec<SharedBufferCommand>,
+    gradients: Vec<GradientCommand>,
+    paths: Vec<path::PathCommand>,
 }
 
 impl ProcessScene for PrepareScene {
@@ -862,6 +1155,77 @@ fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRec
             });
         }
     }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let gradient_index = self.gradients.len() as u16;
+            self.gradients.push(gradient);
+            self.items.push(SceneItem {
+                pos: geometry.origin,
+                size,
+                z: self.items.len() as u16,
+                command: SceneCommand::Gradient { gradient_index },
+            });
+        }
+    }
+
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let path_index = self.paths.len() as u16;
+            self.paths.push(path);
+            self.items.push(SceneItem {
+                pos: geometry.origin,
+                size,
+                z: self.items.len() as u16,
+                command: SceneCommand::Path { path_index },
+            });
+        }
+    }
+}
+
+/// The character shown instead of each character of a `TextInput` with the `password` input type
+const PASSWORD_CHARACTER: char = '●';
+
+/// Returns the `text` of the `text_input` as it is displayed: masked if it is a password field
+fn text_input_displayed_text<'a>(
+    text_input: Pin<&crate::items::TextInput>,
+    text: &'a str,
+) -> alloc::borrow::Cow<'a, str> {
+    if let crate::items::InputType::Password = text_input.input_type() {
+        core::iter::repeat(PASSWORD_CHARACTER).take(text.chars().count()).collect()
+    } else {
+        text.into()
+    }
+}
+
+/// Returns the layout of the `string` in the given physical `size`, according to the properties of the `text_input`
+fn text_input_paragraph_layout<'a, Font: AbstractFont>(
+    text_input: Pin<&crate::items::TextInput>,
+    string: &'a str,
+    layout: crate::textlayout::TextLayout<'a, Font>,
+    size: PhysicalSize,
+) -> TextParagraphLayout<'a, Font> {
+    TextParagraphLayout {
+        string,
+        layout,
+        max_width: size.width_length(),
+        max_height: size.height_length(),
+        horizontal_alignment: text_input.horizontal_alignment(),
+        vertical_alignment: text_input.vertical_alignment(),
+        wrap: text_input.wrap(),
+        overflow: crate::items::TextOverflow::Clip,
+        single_line: text_input.single_line(),
+    }
+}
+
+/// The selection of a `TextInput` to be drawn by [`SceneBuilder::draw_text_paragraph`]
+struct SelectionInfo {
+    selection_color: Color,
+    selection_background: Color,
+    /// The byte range of the selected text
+    selection: core::ops::Range<usize>,
 }
 
 struct SceneBuilder<'a, T> {
@@ -870,6 +1234,7 @@ struct SceneBuilder<'a, T> {
     current_state: RenderState,
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
+    box_shadow_cache: &'a RefCell<box_shadow::BoxShadowCache>,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -877,6 +1242,7 @@ fn new(
         size: PhysicalSize,
         scale_factor: ScaleFactor,
         window: &'a WindowInner,
+        box_shadow_cache: &'a RefCell<box_shadow::BoxShadowCache>,
         processor: T,
     ) -> Self {
         Self {
@@ -892,6 +1258,7 @@ fn new(
             },
             scale_factor,
             window,
+            box_shadow_cache,
         }
     }
 
@@ -1057,69 +1424,201 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
         physical_clip: euclid::Rect<f32, PhysicalPx>,
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
+        selection: Option<SelectionInfo>,
     ) where
         Font: crate::textlayout::TextShaper<Length = PhysicalLength>,
         Font: GlyphRenderer,
     {
-        paragraph.layout_lines(|glyphs, line_x, line_y| {
-            let baseline_y = line_y + paragraph.layout.font.ascent();
-            while let Some(positioned_glyph) = glyphs.next() {
-                let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
-
-                let src_rect = PhysicalRect::new(
-                    PhysicalPoint::from_lengths(
-                        line_x + positioned_glyph.x + glyph.x,
-                        baseline_y - glyph.y - glyph.height,
-                    ),
-                    glyph.size(),
-                )
-                .cast();
-
-                if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
-                    let geometry = clipped_src.translate(offset).round();
-                    let origin = (geometry.origin - offset.round()).cast::<usize>();
-                    let actual_x = origin.x - src_rect.origin.x as usize;
-                    let actual_y = origin.y - src_rect.origin.y as usize;
-                    let stride = glyph.width.get() as u16;
-                    let geometry = geometry.cast();
-
-                    match &glyph.alpha_map {
-                        fonts::GlyphAlphaMap::Static(data) => {
-                            self.processor.process_texture(
-                                geometry,
-                                SceneTexture {
-                                    data: &data[actual_x + actual_y * stride as usize..],
-                                    stride,
-                                    source_size: geometry.size,
-                                    format: PixelFormat::AlphaMap,
-                                    color,
-                                    // color already is mixed with global alpha
-                                    alpha: color.alpha(),
-                                },
-                            );
-                        }
-                        fonts::GlyphAlphaMap::Shared(data) => {
-                            self.processor.process_shared_image_buffer(
-                                geometry,
-                                SharedBufferCommand {
-                                    buffer: SharedBufferData::AlphaMap {
-                                        data: data.clone(),
-                                        width: stride,
-                                    },
-                                    source_rect: PhysicalRect::new(
-                                        PhysicalPoint::new(actual_x as _, actual_y as _),
-                                        geometry.size,
-                                    ),
-                                    colorize: color,
-                                    // color already is mixed with global alpha
-                                    alpha: color.alpha(),
-                                },
-                            );
+        paragraph.layout_lines(
+            |glyphs, line_x, line_y, selection_range| {
+                let baseline_y = line_y + paragraph.layout.font.ascent();
+                if let (Some(selection), Some(selection_range)) = (&selection, selection_range) {
+                    let selection_rect = PhysicalRect::new(
+                        PhysicalPoint::from_lengths(line_x + selection_range.start, line_y),
+                        PhysicalSize::from_lengths(
+                            selection_range.end - selection_range.start,
+                            paragraph.layout.font.height(),
+                        ),
+                    );
+                    self.draw_physical_rect(
+                        selection_rect,
+                        physical_clip,
+                        offset,
+                        selection.selection_background,
+                    );
+                }
+                while let Some(positioned_glyph) = glyphs.next() {
+                    let color = match &selection {
+                        Some(selection)
+                            if selection.selection.contains(&positioned_glyph.text_byte_offset) =>
+                        {
+                            selection.selection_color
                         }
+                        _ => color,
                     };
+                    let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
+
+                    let src_rect = PhysicalRect::new(
+                        PhysicalPoint::from_lengths(
+                            line_x + positioned_glyph.x + glyph.x,
+                            baseline_y - glyph.y - glyph.height,
+                        ),
+                        glyph.size(),
+                    )
+                    .cast();
+
+                    if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
+                        let geometry = clipped_src.translate(offset).round();
+                        let origin = (geometry.origin - offset.round()).cast::<usize>();
+                        let actual_x = origin.x - src_rect.origin.x as usize;
+                        let actual_y = origin.y - src_rect.origin.y as usize;
+                        let stride = glyph.width.get() as u16;
+                        let geometry = geometry.cast();
+
+                        match &glyph.alpha_map {
+                            fonts::GlyphAlphaMap::Static(data) => {
+                                self.processor.process_texture(
+                                    geometry,
+                                    SceneTexture {
+                                        data: &data[actual_x + actual_y * stride as usize..],
+                                        stride,
+                                        source_size: geometry.size,
+                                        format: PixelFormat::AlphaMap,
+                                        color,
+                                        // color already is mixed with global alpha
+                                        alpha: color.alpha(),
+                                    },
+                                );
+                            }
+                            fonts::GlyphAlphaMap::Shared(data) => {
+                                self.processor.process_shared_image_buffer(
+                                    geometry,
+                                    SharedBufferCommand {
+                                        buffer: SharedBufferData::AlphaMap {
+                                            data: data.clone(),
+                                            width: stride,
+                                        },
+                                        source_rect: PhysicalRect::new(
+                                            PhysicalPoint::new(actual_x as _, actual_y as _),
+                                            geometry.size,
+                                        ),
+                                        colorize: color,
+                                        // color already is mixed with global alpha
+                                        alpha: color.alpha(),
+                                    },
+                                );
+                            }
+                        };
+                    }
                 }
+            },
+            selection.as_ref().map(|selection| selection.selection.clone()),
+        );
+    }
+
+    /// Draws a rectangle of the given color. The rectangle is in physical pixels, relative to the current item,
+    /// and is clipped by the `physical_clip`.
+    fn draw_physical_rect(
+        &mut self,
+        rect: PhysicalRect,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+        color: Color,
+    ) {
+        if color.alpha() == 0 {
+            return;
+        }
+        if let Some(clipped) = rect.cast().intersection(&physical_clip) {
+            let geometry = clipped.translate(offset).round().cast();
+            if !geometry.is_empty() {
+                self.processor.process_rectangle(geometry, color.into());
             }
+        }
+    }
+
+    /// Draws the text of a TextInput, with its selection, the underline of the preedit text and the cursor
+    fn draw_text_input_paragraph<'b, Font: AbstractFont>(
+        &mut self,
+        paragraph: TextParagraphLayout<'b, Font>,
+        text_input: Pin<&crate::items::TextInput>,
+        visual_representation: &crate::items::TextInputVisualRepresentation,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) where
+        Font: crate::textlayout::TextShaper<Length = PhysicalLength>,
+        Font: GlyphRenderer,
+    {
+        let color = self.alpha_color(&text_input.color());
+        let selection =
+            (!visual_representation.selection_range.is_empty()).then(|| SelectionInfo {
+                selection_color: self
+                    .alpha_color(&Brush::SolidColor(text_input.selection_foreground_color())),
+                selection_background: self
+                    .alpha_color(&Brush::SolidColor(text_input.selection_background_color())),
+                selection: visual_representation.selection_range.clone(),
+            });
+        let cursor_width: PhysicalLength =
+            (text_input.text_cursor_width().cast() * self.scale_factor).cast();
+        let font_height = paragraph.layout.font.height();
+
+        let preedit_underline = if visual_representation.preedit_range.is_empty() {
+            None
+        } else {
+            let (start_x, start_y) =
+                paragraph.cursor_pos_for_byte_offset(visual_representation.preedit_range.start);
+            let (end_x, end_y) =
+                paragraph.cursor_pos_for_byte_offset(visual_representation.preedit_range.end);
+            let thickness =
+                PhysicalLength::new((self.scale_factor.get() as i16).max(1)).min(font_height);
+            // FIXME: underline the preedit text when it spans over several lines
+            (start_y == end_y).then(|| {
+                PhysicalRect::new(
+                    PhysicalPoint::from_lengths(start_x, start_y + font_height - thickness),
+                    PhysicalSize::from_lengths(end_x - start_x, thickness),
+                )
+            })
+        };
+
+        let cursor = visual_representation.cursor_position.map(|cursor_position| {
+            let (cursor_x, cursor_y) = paragraph.cursor_pos_for_byte_offset(cursor_position);
+            PhysicalRect::new(
+                PhysicalPoint::from_lengths(cursor_x, cursor_y),
+                PhysicalSize::from_lengths(cursor_width, font_height),
+            )
         });
+
+        self.draw_text_paragraph(paragraph, physical_clip, offset, color, selection);
+
+        if let Some(underline) = preedit_underline {
+            self.draw_physical_rect(underline, physical_clip, offset, color);
+        }
+        if let Some(cursor) = cursor {
+            self.draw_physical_rect(cursor, physical_clip, offset, color);
+        }
+    }
+
+    /// Converts a rectangle relative to the current item to a rectangle in the physical coordinates of the window
+    fn to_physical(&self, rect: LogicalRect) -> PhysicalRect {
+        (rect.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor)
+            .round()
+            .cast()
+    }
+
+    /// Returns the command to draw the `brush` over `geometry`, when only the `clipped` part is drawn,
+    /// or None if the brush is not a gradient.
+    /// The rectangles are relative to the current item.
+    fn gradient_command(
+        &self,
+        brush: &Brush,
+        geometry: LogicalRect,
+        clipped: LogicalRect,
+    ) -> Option<GradientCommand> {
+        GradientCommand::new(
+            brush,
+            self.to_physical(geometry),
+            self.to_physical(clipped),
+            self.current_state.alpha,
+        )
     }
 
     /// Returns the color of the brush, mixed with the current_state's alpha
@@ -1137,6 +1636,77 @@ fn alpha_color(&self, brush: &Brush) -> Color {
 
         color
     }
+
+    /// Render the children of `item_rc` into a new layer that covers `bounds`, relative to the current item.
+    /// Returns the premultiplied pixels of the layer and its geometry in the physical coordinates of the window.
+    fn render_layer(
+        &self,
+        item_rc: &ItemRc,
+        bounds: LogicalRect,
+    ) -> Option<(Vec<PremultipliedRgbaColor>, PhysicalRect)> {
+        // Round outwards so that the children, once rounded, are always within the layer
+        let layer_rect: PhysicalRect =
+            (bounds.translate(self.current_state.offset.to_vector()).cast::<f32>()
+                * self.scale_factor)
+                .round_out()
+                .cast();
+        if layer_rect.is_empty() {
+            return None;
+        }
+        let stride = layer_rect.width() as usize;
+        let mut buffer =
+            vec![PremultipliedRgbaColor::default(); stride * layer_rect.height() as usize];
+        let mut layer_builder = SceneBuilder::new(
+            layer_rect.size,
+            self.scale_factor,
+            self.window,
+            self.box_shadow_cache,
+            RenderToBuffer { buffer: &mut buffer, stride },
+        );
+        // Keep the same sub-pixel position as if the children were drawn directly in the window
+        let offset = self.current_state.offset.cast::<f32>() * self.scale_factor
+            - layer_rect.origin.cast::<f32>().to_vector();
+        layer_builder.current_state =
+            RenderState { alpha: 1., offset: (offset / self.scale_factor).cast(), clip: bounds };
+        crate::item_rendering::render_item_children(
+            &mut layer_builder,
+            &item_rc.component(),
+            item_rc.index() as isize,
+        );
+        Some((buffer, layer_rect))
+    }
+
+    /// Draw the premultiplied pixels of a layer whose geometry in the physical coordinates of the window is `rect`
+    fn draw_layer(&mut self, layer: Vec<PremultipliedRgbaColor>, rect: PhysicalRect, alpha: f32) {
+        let clipped = match rect.intersection(&self.to_physical(self.current_state.clip)) {
+            Some(clipped) if !clipped.is_empty() => clipped,
+            _ => return,
+        };
+        let alpha = (alpha * 255.).round() as u8;
+        if alpha == 0 {
+            return;
+        }
+        let mut image = crate::graphics::SharedPixelBuffer::<crate::graphics::Rgba8Pixel>::new(
+            rect.width() as u32,
+            rect.height() as u32,
+        );
+        for (pixel, color) in image.make_mut_slice().iter_mut().zip(layer) {
+            *pixel =
+                crate::graphics::Rgba8Pixel::new(color.red, color.green, color.blue, color.alpha);
+        }
+        self.processor.process_shared_image_buffer(
+            clipped,
+            SharedBufferCommand {
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(image)),
+                source_rect: PhysicalRect::new(
+                    (clipped.origin - rect.origin).to_point(),
+                    clipped.size,
+                ),
+                colorize: Default::default(),
+                alpha,
+            },
+        );
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -1150,23 +1720,24 @@ impl<'a, T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'
     fn draw_rectangle(&mut self, rect: Pin<&crate::items::Rectangle>, _: &ItemRc) {
         let geom = LogicalRect::new(LogicalPoint::default(), rect.geometry().size_length());
         if self.should_draw(&geom) {
+            let full_geom = geom;
             let geom = match geom.intersection(&self.current_state.clip) {
                 Some(geom) => geom,
                 None => return,
             };
 
-            // FIXME: gradients
-            let color = self.alpha_color(&rect.background());
+            let background = rect.background();
+            let physical_geom = self.to_physical(geom);
+            if let Some(gradient) = self.gradient_command(&background, full_geom, geom) {
+                self.processor.process_gradient(physical_geom, gradient);
+                return;
+            }
+            let color = self.alpha_color(&background);
 
             if color.alpha() == 0 {
                 return;
             }
-            self.processor.process_rectangle(
-                (geom.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor)
-                    .round()
-                    .cast(),
-                color.into(),
-            );
+            self.processor.process_rectangle(physical_geom, color.into());
         }
     }
 
@@ -1175,39 +1746,26 @@ fn draw_border_rectangle(&mut self, rect: Pin<&crate::items::BorderRectangle>, _
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
-            // FIXME: gradients
-            let color = self.alpha_color(&rect.background());
-            let border_color = if border.get() as f32 > 0.01 {
-                self.alpha_color(&rect.border_color())
-            } else {
-                Color::default()
-            };
+            let background = rect.background();
+            let color = self.alpha_color(&background);
+            let border_brush =
+                if border.get() as f32 > 0.01 { rect.border_color() } else { Brush::default() };
+            let border_color = self.alpha_color(&border_brush);
+
+            let is_gradient = |brush: &Brush| !matches!(brush, Brush::SolidColor(_));
+            let has_gradient = is_gradient(&background) || is_gradient(&border_brush);
 
             let mut border_color = PremultipliedRgbaColor::from(border_color);
             let color = PremultipliedRgbaColor::from(color);
-            if border_color.alpha == 0 {
+            if border_color.alpha == 0 && !is_gradient(&border_brush) {
                 border = LogicalLength::new(0 as _);
-            } else if border_color.alpha < 255 {
+            } else if border_color.alpha < 255 && !has_gradient {
                 // Find a color for the border which is an equivalent to blend the background and then the border.
-                // In the end, the resulting of blending the background and the color is
-                // (A + B) + C, where A is the buffer color, B is the background, and C is the border.
-                // which expands to (A*(1-Bα) + B*Bα)*(1-Cα) + C*Cα = A*(1-(Bα+Cα-Bα*Cα)) + B*Bα*(1-Cα) + C*Cα
-                // so let the new alpha be: Nα = Bα+Cα-Bα*Cα, then this is A*(1-Nα) + N*Nα
-                // with N = (B*Bα*(1-Cα) + C*Cα)/Nα
-                // N being the equivalent color of the border that mixes the background and the border
-                // In pre-multiplied space, the formula simplifies further N' = B'*(1-Cα) + C'
-                let b = border_color;
-                let b_alpha_16 = b.alpha as u16;
-                border_color = PremultipliedRgbaColor {
-                    red: ((color.red as u16 * (255 - b_alpha_16)) / 255) as u8 + b.red as u8,
-                    green: ((color.green as u16 * (255 - b_alpha_16)) / 255) as u8 + b.green as u8,
-                    blue: ((color.blue as u16 * (255 - b_alpha_16)) / 255) as u8 + b.blue as u8,
-                    alpha: (color.alpha as u16 + b_alpha_16
-                        - (color.alpha as u16 * b_alpha_16) / 255) as u8,
-                }
+                // (With gradients, this is done for each pixel when drawing the line)
+                border_color = draw_functions::blend_over(color, border_color);
             }
 
-            if radius.get() > 0 as _ {
+            if radius.get() > 0 as _ || (has_gradient && border.get() > 0 as _) {
                 let radius = radius
                     .min(geom.width_length() / 2 as Coord)
                     .min(geom.height_length() / 2 as Coord);
@@ -1216,11 +1774,15 @@ fn draw_border_rectangle(&mut self, rect: Pin<&crate::items::BorderRectangle>, _
                     let clipped2 = clipped.cast() * self.scale_factor;
                     // Add a small value to make sure that the clip is always positive despite floating point shenanigans
                     const E: f32 = 0.00001;
+                    let inner_gradient = self.gradient_command(&background, geom, clipped);
+                    let border_gradient = if border.get() > 0 as _ {
+                        self.gradient_command(&border_brush, geom, clipped)
+                    } else {
+                        None
+                    };
+                    let physical_clipped = self.to_physical(clipped);
                     self.processor.process_rounded_rectangle(
-                        (clipped.translate(self.current_state.offset.to_vector()).cast()
-                            * self.scale_factor)
-                            .round()
-                            .cast(),
+                        physical_clipped,
                         RoundedRectangle {
                             radius: (radius.cast() * self.scale_factor).cast(),
                             width: (border.cast() * self.scale_factor).cast(),
@@ -1238,38 +1800,31 @@ fn draw_border_rectangle(&mut self, rect: Pin<&crate::items::BorderRectangle>, _
                             right_clip: PhysicalLength::new(
                                 (geom2.max_x() - clipped2.max_x() + E) as _,
                             ),
+                            inner_gradient,
+                            border_gradient,
                         },
                     );
                 }
                 return;
             }
 
-            if color.alpha > 0 {
-                if let Some(r) = geom
-                    .inflate(-border.get(), -border.get())
-                    .intersection(&self.current_state.clip)
-                {
-                    self.processor.process_rectangle(
-                        (r.translate(self.current_state.offset.to_vector()).cast()
-                            * self.scale_factor)
-                            .round()
-                            .cast(),
-                        color,
-                    );
+            // Without radius, and the border (if any) is not a gradient
+            if let Some(r) =
+                geom.inflate(-border.get(), -border.get()).intersection(&self.current_state.clip)
+            {
+                let physical_rect = self.to_physical(r);
+                if let Some(gradient) = self.gradient_command(&background, geom, r) {
+                    self.processor.process_gradient(physical_rect, gradient);
+                } else if color.alpha > 0 {
+                    self.processor.process_rectangle(physical_rect, color);
                 }
             }
 
-            // FIXME: gradients
             if border_color.alpha > 0 {
                 let mut add_border = |r: LogicalRect| {
                     if let Some(r) = r.intersection(&self.current_state.clip) {
-                        self.processor.process_rectangle(
-                            (r.translate(self.current_state.offset.to_vector()).cast()
-                                * self.scale_factor)
-                                .round()
-                                .cast(),
-                            border_color,
-                        );
+                        let r = self.to_physical(r);
+                        self.processor.process_rectangle(r, border_color);
                     }
                 };
                 let b = border.get();
@@ -1368,7 +1923,7 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(paragraph, physical_clip, offset, color);
+                self.draw_text_paragraph(paragraph, physical_clip, offset, color, None);
             }
             #[cfg(feature = "systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -1386,25 +1941,275 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(paragraph, physical_clip, offset, color);
+                self.draw_text_paragraph(paragraph, physical_clip, offset, color, None);
             }
         }
     }
 
     fn draw_text_input(&mut self, text_input: Pin<&crate::items::TextInput>, _: &ItemRc) {
-        text_input.geometry();
-        // TODO
+        let geom = LogicalRect::new(LogicalPoint::default(), text_input.geometry().size_length());
+        if !self.should_draw(&geom) {
+            return;
+        }
+
+        let font_request = text_input.font_request(&self.window.window_adapter());
+        let max_size = (geom.size.cast() * self.scale_factor).cast();
+
+        // Clip glyphs not only against the global clip but also against the TextInput's geometry to avoid drawing outside
+        // of its boundaries.
+        let physical_clip = if let Some(logical_clip) = self.current_state.clip.intersection(&geom)
+        {
+            logical_clip.cast() * self.scale_factor
+        } else {
+            return; // This should have been caught earlier already
+        };
+        let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+
+        let mut visual_representation = text_input.visual_representation();
+        visual_representation.apply_password_character_substitution(text_input, PASSWORD_CHARACTER);
+
+        let font = fonts::match_font(&font_request, self.scale_factor);
+
+        match font {
+            fonts::Font::PixelFont(pf) => {
+                let paragraph = text_input_paragraph_layout(
+                    text_input,
+                    &visual_representation.text,
+                    fonts::text_layout_for_font(&pf, &font_request, self.scale_factor),
+                    max_size,
+                );
+                self.draw_text_input_paragraph(
+                    paragraph,
+                    text_input,
+                    &visual_representation,
+                    physical_clip,
+                    offset,
+                );
+            }
+            #[cfg(feature = "systemfonts")]
+            fonts::Font::VectorFont(vf) => {
+                let paragraph = text_input_paragraph_layout(
+                    text_input,
+                    &visual_representation.text,
+                    fonts::text_layout_for_font(&vf, &font_request, self.scale_factor),
+                    max_size,
+                );
+                self.draw_text_input_paragraph(
+                    paragraph,
+                    text_input,
+                    &visual_representation,
+                    physical_clip,
+                    offset,
+                );
+            }
+        }
     }
 
-    #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&crate::items::Path>, _: &ItemRc) {
-        path.geometry();
-        // TODO
+        use lyon_path::iterator::PathIterator;
+        if self.current_state.alpha < 0.01 {
+            return;
+        }
+        let (offset, path_events) = match path.fitted_path_events() {
+            Some(x) => x,
+            None => return,
+        };
+        let geom = LogicalRect::new(LogicalPoint::default(), path.geometry().size_length());
+        let physical_clip = self.to_physical(self.current_state.clip);
+        let transform = lyon_path::math::Transform::translation(
+            (offset.x + self.current_state.offset.x) as f32,
+            (offset.y + self.current_state.offset.y) as f32,
+        )
+        .then_scale(self.scale_factor.get(), self.scale_factor.get());
+        // The tolerance is in the coordinate of the path, before the transformation
+        let tolerance = 0.1 / self.scale_factor.get();
+        let events = || path_events.iter().flattened(tolerance).map(|e| e.transformed(&transform));
+
+        let fill = path.fill();
+        let stroke = path.stroke();
+        let stroke_width = path.stroke_width().get() as f32 * self.scale_factor.get();
+        let mut commands = Vec::new();
+        if !fill.is_transparent() {
+            commands.push((path::fill_command(events(), path.fill_rule()), fill));
+        }
+        if !stroke.is_transparent() && stroke_width > 0. {
+            commands.push((path::stroke_command(events(), stroke_width), stroke));
+        }
+
+        for (mut command, brush) in commands {
+            let bounding_box = match command.bounding_box() {
+                Some(bounding_box) => bounding_box,
+                None => continue,
+            };
+            let geometry =
+                match bounding_box.round_out().to_rect().cast::<i16>().intersection(&physical_clip)
+                {
+                    Some(geometry) => geometry,
+                    None => continue,
+                };
+            command.finalize(-geometry.origin.to_vector().cast());
+            command.color = self.alpha_color(&brush).into();
+            command.gradient = GradientCommand::new(
+                &brush,
+                self.to_physical(geom),
+                geometry,
+                self.current_state.alpha,
+            );
+            self.processor.process_path(geometry, command);
+        }
     }
 
     fn draw_box_shadow(&mut self, box_shadow: Pin<&crate::items::BoxShadow>, _: &ItemRc) {
-        box_shadow.geometry();
-        // TODO
+        let offset = LogicalVector::from_lengths(box_shadow.offset_x(), box_shadow.offset_y());
+        if offset == LogicalVector::zero() && box_shadow.blur() <= LogicalLength::zero() {
+            // The shadow is entirely hidden behind the element
+            return;
+        }
+        let color = self.alpha_color(&Brush::SolidColor(box_shadow.color()));
+        if color.alpha() == 0 {
+            return;
+        }
+
+        let to_physical_length =
+            |l: LogicalLength| -> i16 { (l.cast() * self.scale_factor).get().round() as i16 };
+        let options = box_shadow::BoxShadowOptions {
+            width: to_physical_length(box_shadow.width()),
+            height: to_physical_length(box_shadow.height()),
+            radius: to_physical_length(box_shadow.border_radius()),
+            blur: to_physical_length(box_shadow.blur()).max(0),
+        };
+        if options.width <= 0 || options.height <= 0 {
+            return;
+        }
+
+        // The partial renderer includes the shadow's offset and blur in the item's dirty region
+        let texture_size = options.texture_size();
+        let origin: PhysicalPoint =
+            ((self.current_state.offset + offset).cast() * self.scale_factor).round().cast();
+        let texture_rect =
+            PhysicalRect::new(origin - euclid::vec2(options.blur, options.blur), texture_size);
+        let clipped = match texture_rect.intersection(&self.to_physical(self.current_state.clip)) {
+            Some(clipped) if !clipped.is_empty() => clipped,
+            _ => return,
+        };
+
+        let data = self.box_shadow_cache.borrow_mut().get_box_shadow(options);
+        self.processor.process_shared_image_buffer(
+            clipped,
+            SharedBufferCommand {
+                buffer: SharedBufferData::AlphaMap { data, width: texture_size.width as u16 },
+                source_rect: PhysicalRect::new(
+                    (clipped.origin - texture_rect.origin).to_point(),
+                    clipped.size,
+                ),
+                colorize: color,
+                // color already is mixed with global alpha
+                alpha: color.alpha(),
+            },
+        );
+    }
+
+    fn visit_opacity(
+        &mut self,
+        opacity_item: Pin<&crate::items::Opacity>,
+        self_rc: &ItemRc,
+    ) -> RenderingResult {
+        let opacity = opacity_item.opacity();
+        if !crate::items::Opacity::need_layer(self_rc, opacity) {
+            self.apply_opacity(opacity);
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        let alpha = self.current_state.alpha * opacity;
+        if alpha > 0.01 {
+            // Render the children in a layer so that overlapping children are not blended with each other
+            let bounds = crate::properties::evaluate_no_tracking(|| {
+                crate::item_rendering::item_children_bounding_rect(
+                    &self_rc.component(),
+                    self_rc.index() as isize,
+                    &self.current_state.clip,
+                )
+            });
+            if let Some((layer, layer_rect)) = self.render_layer(self_rc, bounds) {
+                self.draw_layer(layer, layer_rect, alpha);
+            }
+        }
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    fn visit_rotate(
+        &mut self,
+        rotate_item: Pin<&crate::items::Rotate>,
+        self_rc: &ItemRc,
+    ) -> RenderingResult {
+        let angle = rotate_item.rotation_angle() % 360.;
+        if angle == 0. {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        if self.current_state.alpha <= 0.01 {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        }
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let origin = LogicalPoint::from_lengths(
+            rotate_item.rotation_origin_x(),
+            rotate_item.rotation_origin_y(),
+        )
+        .cast::<f32>();
+        // Helpers to compute the bounding box of a rotated rectangle
+        let rotate_around =
+            |p: euclid::default::Point2D<f32>, pivot: euclid::default::Point2D<f32>, sin: f32| {
+                let v = p - pivot;
+                pivot + euclid::vec2(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+            };
+        let rotated_bounding_box =
+            |r: euclid::default::Rect<f32>, pivot: euclid::default::Point2D<f32>, sin: f32| {
+                euclid::default::Box2D::from_points(
+                    [
+                        r.min(),
+                        euclid::point2(r.max_x(), r.min_y()),
+                        euclid::point2(r.min_x(), r.max_y()),
+                        r.max(),
+                    ]
+                    .map(|p| rotate_around(p, pivot, sin)),
+                )
+                .to_rect()
+            };
+
+        // The children are rendered unrotated in a layer. Only the part of the children that is
+        // visible once rotated needs to be in the layer, which is the current clip rotated in the
+        // other direction.
+        let children_clip = rotated_bounding_box(
+            self.current_state.clip.cast::<f32>().to_untyped(),
+            origin.to_untyped(),
+            -sin,
+        );
+        let bounds = crate::properties::evaluate_no_tracking(|| {
+            crate::item_rendering::item_children_bounding_rect(
+                &self_rc.component(),
+                self_rc.index() as isize,
+                &LogicalRect::from_untyped(&children_clip.cast()),
+            )
+        });
+        let (layer, layer_rect) = match self.render_layer(self_rc, bounds) {
+            Some(layer) => layer,
+            None => return RenderingResult::ContinueRenderingWithoutChildren,
+        };
+
+        let pivot =
+            (self.current_state.offset.cast::<f32>() + origin.to_vector()) * self.scale_factor;
+        let target_rect = PhysicalRect::from_untyped(
+            &rotated_bounding_box(layer_rect.cast::<f32>().to_untyped(), pivot.to_untyped(), sin)
+                .round_out()
+                .cast(),
+        );
+        let target_rect = match target_rect.intersection(&self.to_physical(self.current_state.clip))
+        {
+            Some(r) if !r.is_empty() => r,
+            _ => return RenderingResult::ContinueRenderingWithoutChildren,
+        };
+        let rotated =
+            draw_functions::rotate_layer(&layer, layer_rect, target_rect, pivot, (sin, cos));
+        self.draw_layer(rotated, target_rect, self.current_state.alpha);
+        RenderingResult::ContinueRenderingWithoutChildren
     }
 
     fn combine_clip(
@@ -1436,7 +2241,7 @@ fn translate(&mut self, distance: LogicalVector) {
     }
 
     fn rotate(&mut self, _angle_in_degrees: f32) {
-        todo!()
+        unreachable!("the rotation is done by rendering a layer in visit_rotate")
     }
 
     fn apply_opacity(&mut self, opacity: f32) {
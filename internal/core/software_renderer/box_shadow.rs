@@ -0,0 +1,113 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! This module contains the cache of the textures used to draw the [`BoxShadow`](crate::items::BoxShadow) element.
+//!
+//! The shadow is rendered once into an alpha map which is then drawn with the color of the shadow.
+
+use super::PhysicalSize;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
+
+/// The maximum number of shadow textures kept in the cache
+const CACHE_SIZE: usize = 8;
+
+/// The properties of a box shadow that affect its texture, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct BoxShadowOptions {
+    pub width: i16,
+    pub height: i16,
+    pub radius: i16,
+    pub blur: i16,
+}
+
+impl BoxShadowOptions {
+    /// The size of the texture, which includes the blur on each side of the shadow
+    pub fn texture_size(&self) -> PhysicalSize {
+        PhysicalSize::new(self.width + 2 * self.blur, self.height + 2 * self.blur)
+    }
+}
+
+/// Cache of the shadow textures, the least recently used texture is discarded when the cache is full.
+#[derive(Default)]
+pub(super) struct BoxShadowCache(Vec<(BoxShadowOptions, Rc<[u8]>)>);
+
+impl BoxShadowCache {
+    /// Look up the alpha map for the given options, or render a new one.
+    /// The alpha map has the size of [`BoxShadowOptions::texture_size`]
+    pub fn get_box_shadow(&mut self, options: BoxShadowOptions) -> Rc<[u8]> {
+        let data = match self.0.iter().position(|(o, _)| *o == options) {
+            Some(index) => self.0.remove(index).1,
+            None => {
+                if self.0.len() >= CACHE_SIZE {
+                    self.0.remove(0);
+                }
+                render_box_shadow(&options)
+            }
+        };
+        self.0.push((options, data.clone()));
+        data
+    }
+}
+
+/// Render the alpha map of the shadow: a rounded rectangle blurred by a gaussian blur.
+fn render_box_shadow(options: &BoxShadowOptions) -> Rc<[u8]> {
+    let size = options.texture_size();
+    let (width, height) = (size.width.max(0) as usize, size.height.max(0) as usize);
+    let blur = options.blur.max(0) as f32;
+
+    // The half size of the rectangle and the radius of its corners
+    let half_width = options.width as f32 / 2.;
+    let half_height = options.height as f32 / 2.;
+    let radius = (options.radius as f32).min(half_width).min(half_height).max(0.);
+
+    let mut coverage = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            // Signed distance from the center of the pixel to the rounded rectangle
+            let qx = (x as f32 + 0.5 - blur - half_width).abs() - (half_width - radius);
+            let qy = (y as f32 + 0.5 - blur - half_height).abs() - (half_height - radius);
+            let distance = (qx.max(0.) * qx.max(0.) + qy.max(0.) * qy.max(0.)).sqrt()
+                + qx.max(qy).min(0.)
+                - radius;
+            coverage.push((0.5 - distance).clamp(0., 1.));
+        }
+    }
+
+    if options.blur > 0 {
+        // Same convention as the other renderers: the sigma is half of the blur radius
+        let sigma = blur / 2.;
+        let kernel_radius = options.blur as usize;
+        let mut kernel = (0..=2 * kernel_radius)
+            .map(|i| {
+                let d = i as f32 - kernel_radius as f32;
+                (-(d * d) / (2. * sigma * sigma)).exp()
+            })
+            .collect::<Vec<_>>();
+        let sum: f32 = kernel.iter().sum();
+        kernel.iter_mut().for_each(|k| *k /= sum);
+
+        let blur_pass = |source: &[f32], stride: usize, step: usize, count: usize| {
+            let mut result = alloc::vec![0f32; source.len()];
+            for (i, r) in result.iter_mut().enumerate() {
+                let pos = (i / stride) % count;
+                *r = kernel
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(k, weight)| {
+                        let p = (pos + k).checked_sub(kernel_radius).filter(|p| *p < count)?;
+                        Some(source[i - pos * step + p * step] * weight)
+                    })
+                    .sum();
+            }
+            result
+        };
+        // Horizontal pass, then vertical pass
+        coverage = blur_pass(&coverage, 1, 1, width);
+        coverage = blur_pass(&coverage, width, width, height);
+    }
+
+    coverage.into_iter().map(|c| (c * 255. + 0.5) as u8).collect()
+}
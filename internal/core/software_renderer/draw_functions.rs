@@ -6,12 +6,15 @@
 
 use super::{PhysicalLength, PhysicalRect};
 use crate::graphics::{PixelFormat, Rgb8Pixel};
-use crate::lengths::{PointLengths, SizeLengths};
+use crate::lengths::{PhysicalPx, PointLengths, SizeLengths};
 use crate::Color;
+use alloc::vec::Vec;
 use derive_more::{Add, Mul, Sub};
 #[cfg(feature = "embedded-graphics")]
 use embedded_graphics::prelude::RgbColor as _;
 use integer_sqrt::IntegerSquareRoot;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 
 /// Draw one line of the texture in the line buffer
 pub(super) fn draw_texture_line(
@@ -89,11 +92,11 @@ pub(super) fn draw_texture_line(
 }
 
 /// draw one line of the rounded rectangle in the line buffer
-pub(super) fn draw_rounded_rectangle_line(
+pub(super) fn draw_rounded_rectangle_line<T: TargetPixel>(
     span: &PhysicalRect,
     line: PhysicalLength,
     rr: &super::RoundedRectangle,
-    line_buffer: &mut [impl TargetPixel],
+    line_buffer: &mut [T],
 ) {
     /// This is an integer shifted by 4 bits.
     /// Note: this is not a "fixed point" because multiplication and sqrt operation operate to
@@ -132,6 +135,39 @@ fn mul(self, rhs: Self) -> Self::Output {
     debug_assert!(y.get() >= 0,);
     let border = Shifted::new(rr.width.get());
     const ONE: Shifted = Shifted::ONE;
+    // The colors of the inside and of the border, for the pixel x (relative to the span)
+    let y_in_span = (line - span.origin.y_length()).get();
+    let inner_color = |x: usize| match &rr.inner_gradient {
+        Some(gradient) => gradient.color_at(x as i16, y_in_span),
+        None => rr.inner_color,
+    };
+    let has_gradient = rr.inner_gradient.is_some() || rr.border_gradient.is_some();
+    let border_color = |x: usize| {
+        let c = match &rr.border_gradient {
+            Some(gradient) => gradient.color_at(x as i16, y_in_span),
+            None => rr.border_color,
+        };
+        // Without gradient, the border_color was already blended with the inner color
+        if has_gradient && c.alpha < 255 {
+            blend_over(inner_color(x), c)
+        } else {
+            c
+        }
+    };
+    // blend the pixels between begin and end (relative to the span)
+    let fill = |line_buffer: &mut [T],
+                begin: usize,
+                end: usize,
+                is_solid: bool,
+                color: &dyn Fn(usize) -> PremultipliedRgbaColor| {
+        if is_solid {
+            TargetPixel::blend_slice(&mut line_buffer[pos_x + begin..pos_x + end], color(begin))
+        } else {
+            for x in begin..end {
+                line_buffer[pos_x + x].blend(color(x));
+            }
+        }
+    };
     let anti_alias = |x1: Shifted, x2: Shifted, process_pixel: &mut dyn FnMut(usize, u32)| {
         // x1 and x2 are the coordinate on the top and bottom of the intersection of the pixel
         // line and the curve.
@@ -168,7 +204,7 @@ fn mul(self, rhs: Self) -> Self::Output {
             if x >= span.size.width as usize {
                 return;
             }
-            let c = if border == Shifted(0) { rr.inner_color } else { rr.border_color };
+            let c = if border == Shifted(0) { inner_color(x) } else { border_color(x) };
             let col = PremultipliedRgbaColor {
                 alpha: (((c.alpha as u32) * cov as u32) / 255) as u8,
                 red: (((c.red as u32) * cov as u32) / 255) as u8,
@@ -184,23 +220,20 @@ fn mul(self, rhs: Self) -> Self::Output {
             as usize;
         let r = rev(x2).floor().min(span.size.width as u32) as usize;
         if l < r {
-            TargetPixel::blend_slice(&mut line_buffer[pos_x + l..pos_x + r], rr.border_color)
+            fill(line_buffer, l, r, !has_gradient, &border_color)
         }
     } else {
         if border > Shifted(0) {
             // 3. draw the border (between x2 and x3)
             if ONE + x2 <= x3 {
-                TargetPixel::blend_slice(
-                    &mut line_buffer[pos_x
-                        + x2.ceil()
-                            .saturating_sub(rr.left_clip.get() as u32)
-                            .min(span.size.width as u32) as usize
-                        ..pos_x
-                            + x3.floor()
-                                .saturating_sub(rr.left_clip.get() as u32)
-                                .min(span.size.width as u32)
-                                as usize],
-                    rr.border_color,
+                fill(
+                    line_buffer,
+                    x2.ceil().saturating_sub(rr.left_clip.get() as u32).min(span.size.width as u32)
+                        as usize,
+                    x3.floor().saturating_sub(rr.left_clip.get() as u32).min(span.size.width as u32)
+                        as usize,
+                    !has_gradient,
+                    &border_color,
                 )
             }
             // 4. anti-aliasing for the contents (x3 .. x4)
@@ -211,20 +244,23 @@ fn mul(self, rhs: Self) -> Self::Output {
                     if x >= span.size.width as usize {
                         return;
                     }
-                    let col = interpolate_color(cov, rr.border_color, rr.inner_color);
+                    let col = interpolate_color(cov, border_color(x), inner_color(x));
                     line_buffer[pos_x + x].blend(col);
                 },
             );
         }
-        if rr.inner_color.alpha > 0 {
+        if rr.inner_color.alpha > 0 || rr.inner_gradient.is_some() {
             // 5. inside (x4 .. x4)
             let begin =
                 x4.ceil().saturating_sub(rr.left_clip.get() as u32).min(span.size.width as u32);
             let end = rev(x4).floor().min(span.size.width as u32);
             if begin < end {
-                TargetPixel::blend_slice(
-                    &mut line_buffer[pos_x + begin as usize..pos_x + end as usize],
-                    rr.inner_color,
+                fill(
+                    line_buffer,
+                    begin as usize,
+                    end as usize,
+                    rr.inner_gradient.is_none(),
+                    &inner_color,
                 )
             }
         }
@@ -234,15 +270,17 @@ fn mul(self, rhs: Self) -> Self::Output {
                 if x >= span.size.width as usize {
                     return;
                 }
-                let col = interpolate_color(cov, rr.inner_color, rr.border_color);
+                let col = interpolate_color(cov, inner_color(x), border_color(x));
                 line_buffer[pos_x + x].blend(col)
             });
             // 7. border x3 .. x2
             if ONE + x2 <= x3 {
-                TargetPixel::blend_slice(
-                    &mut line_buffer[pos_x + rev(x3).ceil().min(span.size.width as u32) as usize
-                        ..pos_x + rev(x2).floor().min(span.size.width as u32) as usize as usize],
-                    rr.border_color,
+                fill(
+                    line_buffer,
+                    rev(x3).ceil().min(span.size.width as u32) as usize,
+                    rev(x2).floor().min(span.size.width as u32) as usize,
+                    !has_gradient,
+                    &border_color,
                 )
             }
         }
@@ -251,7 +289,7 @@ fn mul(self, rhs: Self) -> Self::Output {
         if x >= span.size.width as usize {
             return;
         }
-        let c = if border == Shifted(0) { rr.inner_color } else { rr.border_color };
+        let c = if border == Shifted(0) { inner_color(x) } else { border_color(x) };
         let col = PremultipliedRgbaColor {
             alpha: (((c.alpha as u32) * (255 - cov) as u32) / 255) as u8,
             red: (((c.red as u32) * (255 - cov) as u32) / 255) as u8,
@@ -262,6 +300,95 @@ fn mul(self, rhs: Self) -> Self::Output {
     });
 }
 
+/// Draw one line of the gradient in the line buffer
+pub(super) fn draw_gradient_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    gradient: &super::GradientCommand,
+    line_buffer: &mut [impl TargetPixel],
+) {
+    let y = (line - span.origin.y_length()).get();
+    for (x, pix) in line_buffer
+        [span.origin.x as usize..(span.origin.x_length() + span.size.width_length()).get() as usize]
+        .iter_mut()
+        .enumerate()
+    {
+        pix.blend(gradient.color_at(x as i16, y));
+    }
+}
+
+/// Returns the color equivalent to blending `background` and then `foreground`.
+///
+/// The resulting of blending the background and the color is
+/// (A + B) + C, where A is the buffer color, B is the background, and C is the foreground.
+/// which expands to (A*(1-Bα) + B*Bα)*(1-Cα) + C*Cα = A*(1-(Bα+Cα-Bα*Cα)) + B*Bα*(1-Cα) + C*Cα
+/// so let the new alpha be: Nα = Bα+Cα-Bα*Cα, then this is A*(1-Nα) + N*Nα
+/// with N = (B*Bα*(1-Cα) + C*Cα)/Nα
+/// In pre-multiplied space, the formula simplifies further N' = B'*(1-Cα) + C'
+pub(super) fn blend_over(
+    background: PremultipliedRgbaColor,
+    foreground: PremultipliedRgbaColor,
+) -> PremultipliedRgbaColor {
+    let b = foreground;
+    let b_alpha_16 = b.alpha as u16;
+    PremultipliedRgbaColor {
+        red: ((background.red as u16 * (255 - b_alpha_16)) / 255) as u8 + b.red,
+        green: ((background.green as u16 * (255 - b_alpha_16)) / 255) as u8 + b.green,
+        blue: ((background.blue as u16 * (255 - b_alpha_16)) / 255) as u8 + b.blue,
+        alpha: (background.alpha as u16 + b_alpha_16 - (background.alpha as u16 * b_alpha_16) / 255)
+            as u8,
+    }
+}
+
+/// Rotate the `source` layer, whose geometry in the physical coordinates of the window is `source_rect`,
+/// by the angle given by its sine and cosine around the `pivot`.
+/// Returns the pixels of the rotated layer that are within `target_rect`, sampled with a bilinear interpolation.
+pub(super) fn rotate_layer(
+    source: &[PremultipliedRgbaColor],
+    source_rect: PhysicalRect,
+    target_rect: PhysicalRect,
+    pivot: euclid::Point2D<f32, PhysicalPx>,
+    (sin, cos): (f32, f32),
+) -> Vec<PremultipliedRgbaColor> {
+    let (width, height) = (source_rect.width() as isize, source_rect.height() as isize);
+    let source_pixel = |x: isize, y: isize| {
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return PremultipliedRgbaColor::default();
+        }
+        source[(y * width + x) as usize]
+    };
+
+    let mut result = Vec::with_capacity(target_rect.area() as usize);
+    for y in target_rect.min_y()..target_rect.max_y() {
+        for x in target_rect.min_x()..target_rect.max_x() {
+            // Map the center of the pixel back in the layer, with the inverse rotation
+            let dx = x as f32 + 0.5 - pivot.x;
+            let dy = y as f32 + 0.5 - pivot.y;
+            let sx = pivot.x + dx * cos + dy * sin - source_rect.origin.x as f32 - 0.5;
+            let sy = pivot.y - dx * sin + dy * cos - source_rect.origin.y as f32 - 0.5;
+            let (x0, y0) = (sx.floor(), sy.floor());
+            let (fx, fy) = (sx - x0, sy - y0);
+            let (x0, y0) = (x0 as isize, y0 as isize);
+
+            let mut color = [0f32; 4];
+            for (px, py, weight) in [
+                (x0, y0, (1. - fx) * (1. - fy)),
+                (x0 + 1, y0, fx * (1. - fy)),
+                (x0, y0 + 1, (1. - fx) * fy),
+                (x0 + 1, y0 + 1, fx * fy),
+            ] {
+                let p = source_pixel(px, py);
+                for (c, v) in color.iter_mut().zip([p.red, p.green, p.blue, p.alpha]) {
+                    *c += v as f32 * weight;
+                }
+            }
+            let [red, green, blue, alpha] = color.map(|c| (c + 0.5) as u8);
+            result.push(PremultipliedRgbaColor { red, green, blue, alpha });
+        }
+    }
+    result
+}
+
 // a is between 0 and 255. When 0, we get color1, when 255 we get color2
 fn interpolate_color(
     a: u32,
@@ -389,6 +516,16 @@ fn from_rgb(r: u8, g: u8, b: u8) -> Self {
     }
 }
 
+impl TargetPixel for PremultipliedRgbaColor {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        *self = blend_over(*self, color);
+    }
+
+    fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue, alpha: 255 }
+    }
+}
+
 /// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
@@ -468,3 +605,19 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn rotate_layer_90() {
+    let red = PremultipliedRgbaColor { red: 0xff, green: 0, blue: 0, alpha: 0xff };
+    let blue = PremultipliedRgbaColor { red: 0, green: 0, blue: 0x80, alpha: 0x80 };
+    let (sin, cos) = 90f32.to_radians().sin_cos();
+    let rotated = rotate_layer(
+        &[red, blue],
+        PhysicalRect::new(euclid::point2(0, 0), euclid::size2(2, 1)),
+        PhysicalRect::new(euclid::point2(0, 0), euclid::size2(2, 2)),
+        euclid::point2(1., 1.),
+        (sin, cos),
+    );
+    let components = rotated.iter().map(|c| (c.red, c.green, c.blue, c.alpha)).collect::<Vec<_>>();
+    assert_eq!(components, [(0, 0, 0, 0), (0xff, 0, 0, 0xff), (0, 0, 0, 0), (0, 0, 0x80, 0x80)]);
+}
@@ -0,0 +1,421 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! This module contains the rasterizer for the [`Path`](crate::items::Path) element.
+//!
+//! The path is flattened into a list of line segments (edges) when preparing the scene.
+//! Then, each line is rasterized independently by computing the coverage of a few sub-scanlines,
+//! so that it works with [`render_by_line`](super::SoftwareRenderer::render_by_line).
+
+use super::draw_functions::{PremultipliedRgbaColor, TargetPixel};
+use super::{GradientCommand, PhysicalLength, PhysicalRect};
+use crate::items::FillRule;
+use crate::lengths::{PointLengths, SizeLengths};
+use alloc::vec::Vec;
+use lyon_path::math::Point;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
+
+/// The number of sub-scanlines computed for each line, for the anti-aliasing
+const SUB_SCANLINES: usize = 4;
+
+/// A line segment of the outline of the shape, oriented from top to bottom
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    /// +1 if the original segment was going down, -1 if it was going up
+    winding: i8,
+}
+
+/// A shape to be filled, described by its outline.
+#[derive(Debug, Default)]
+pub(super) struct PathCommand {
+    /// The edges, relative to the origin of the command's geometry (in physical pixels),
+    /// sorted by their `y1`
+    edges: Vec<Edge>,
+    fill_rule: FillRule,
+    /// The color used when there is no gradient
+    pub color: PremultipliedRgbaColor,
+    pub gradient: Option<GradientCommand>,
+}
+
+impl PathCommand {
+    fn add_edge(&mut self, from: Point, to: Point) {
+        if from.y == to.y || !(from.y.is_finite() && to.y.is_finite()) {
+            // horizontal edges do not contribute to the winding
+            return;
+        }
+        self.edges.push(if from.y < to.y {
+            Edge { x1: from.x, y1: from.y, x2: to.x, y2: to.y, winding: 1 }
+        } else {
+            Edge { x1: to.x, y1: to.y, x2: from.x, y2: from.y, winding: -1 }
+        });
+    }
+
+    /// Add a closed polygon, making sure that it is oriented clockwise so that overlapping
+    /// polygons are merged when using the [`FillRule::Nonzero`] rule.
+    fn add_polygon(&mut self, points: &[Point]) {
+        let area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum();
+        let reverse = area < 0.;
+        for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+            if reverse {
+                self.add_edge(*b, *a);
+            } else {
+                self.add_edge(*a, *b);
+            }
+        }
+    }
+
+    /// Returns the bounding box of all the edges
+    pub fn bounding_box(&self) -> Option<euclid::Box2D<f32, crate::lengths::PhysicalPx>> {
+        let mut it = self.edges.iter();
+        let first = it.next()?;
+        let mut result = euclid::Box2D::new(
+            euclid::point2(first.x1.min(first.x2), first.y1),
+            euclid::point2(first.x1.max(first.x2), first.y2),
+        );
+        for e in it {
+            result.min.x = result.min.x.min(e.x1.min(e.x2));
+            result.max.x = result.max.x.max(e.x1.max(e.x2));
+            result.min.y = result.min.y.min(e.y1);
+            result.max.y = result.max.y.max(e.y2);
+        }
+        Some(result)
+    }
+
+    /// Move all the edges by the given offset and sort them.
+    /// Must be called once all the edges were added.
+    pub fn finalize(&mut self, offset: euclid::Vector2D<f32, crate::lengths::PhysicalPx>) {
+        for e in self.edges.iter_mut() {
+            e.x1 += offset.x;
+            e.x2 += offset.x;
+            e.y1 += offset.y;
+            e.y2 += offset.y;
+        }
+        self.edges
+            .sort_unstable_by(|a, b| a.y1.partial_cmp(&b.y1).unwrap_or(core::cmp::Ordering::Equal));
+    }
+}
+
+/// Build the command to fill the path described by the `events`, which are already flattened and
+/// in physical coordinates.
+pub(super) fn fill_command(
+    events: impl Iterator<Item = lyon_path::PathEvent>,
+    fill_rule: FillRule,
+) -> PathCommand {
+    let mut cmd = PathCommand { fill_rule, ..Default::default() };
+    // The first and the last point of the current sub-path.
+    // (Don't rely on the `End` event's `first` and `last` which are not always those of the sub-path)
+    let mut first = Point::default();
+    let mut last = Point::default();
+    for event in events {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                first = at;
+                last = at;
+            }
+            lyon_path::Event::Line { from, to }
+            | lyon_path::Event::Quadratic { from, to, .. }
+            | lyon_path::Event::Cubic { from, to, .. } => {
+                cmd.add_edge(from, to);
+                last = to;
+            }
+            // A shape is always implicitly closed when filled
+            lyon_path::Event::End { .. } => cmd.add_edge(last, first),
+        }
+    }
+    cmd
+}
+
+/// Build the command to stroke the path described by the `events`, which are already flattened and
+/// in physical coordinates.
+///
+/// Each segment is turned into a rectangle of the width of the stroke, and the gaps at the
+/// joins between segments are filled with a triangle (bevel join).
+pub(super) fn stroke_command(
+    events: impl Iterator<Item = lyon_path::PathEvent>,
+    stroke_width: f32,
+) -> PathCommand {
+    let mut cmd = PathCommand { fill_rule: FillRule::Nonzero, ..Default::default() };
+    let half_width = stroke_width / 2.;
+    let normal = |from: Point, to: Point| {
+        let v = to - from;
+        let len = v.length();
+        if len > 0. {
+            Some(lyon_path::math::vector(-v.y, v.x) / len * half_width)
+        } else {
+            None
+        }
+    };
+    // The start and the normal of the first segment of the current sub-path, and the end and
+    // normal of the previous segment
+    let mut first: Option<(Point, lyon_path::math::Vector)> = None;
+    let mut previous: Option<(Point, lyon_path::math::Vector)> = None;
+    let mut start = Point::default();
+    let mut last = Point::default();
+    fn add_join(
+        cmd: &mut PathCommand,
+        at: Point,
+        n1: lyon_path::math::Vector,
+        n2: lyon_path::math::Vector,
+    ) {
+        // Add the triangles on both side, only one of them is actually visible.
+        cmd.add_polygon(&[at, at + n1, at + n2]);
+        cmd.add_polygon(&[at, at - n1, at - n2]);
+    }
+    for event in events {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                first = None;
+                previous = None;
+                start = at;
+                last = at;
+            }
+            lyon_path::Event::Line { from, to }
+            | lyon_path::Event::Quadratic { from, to, .. }
+            | lyon_path::Event::Cubic { from, to, .. } => {
+                last = to;
+                if let Some(n) = normal(from, to) {
+                    cmd.add_polygon(&[from + n, to + n, to - n, from - n]);
+                    if let Some((_, prev_n)) = previous {
+                        add_join(&mut cmd, from, prev_n, n);
+                    }
+                    if first.is_none() {
+                        first = Some((from, n));
+                    }
+                    previous = Some((to, n));
+                }
+            }
+            lyon_path::Event::End { close, .. } => {
+                if close {
+                    if let Some(n) = normal(last, start) {
+                        cmd.add_polygon(&[last + n, start + n, start - n, last - n]);
+                        if let Some((_, prev_n)) = previous {
+                            add_join(&mut cmd, last, prev_n, n);
+                        }
+                        previous = Some((start, n));
+                    }
+                    if let (Some((at, first_n)), Some((_, prev_n))) = (first, previous) {
+                        add_join(&mut cmd, at, prev_n, first_n);
+                    }
+                }
+                first = None;
+                previous = None;
+            }
+        }
+    }
+    cmd
+}
+
+/// Scratch buffers for [`draw_path_line`], so that they are not re-allocated for every line.
+#[derive(Debug, Default)]
+pub(super) struct PathLineBuffers {
+    /// The coverage of each pixel, between 0 and 1
+    coverage: Vec<f32>,
+    /// The x position and the winding of the edges crossing the current sub-scanline
+    crossings: Vec<(f32, i8)>,
+}
+
+/// Draw one line of the path in the line buffer
+pub(super) fn draw_path_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    path: &PathCommand,
+    buffers: &mut PathLineBuffers,
+    line_buffer: &mut [impl TargetPixel],
+) {
+    let width = span.size.width as usize;
+    let y = (line - span.origin.y_length()).get();
+    let PathLineBuffers { coverage, crossings } = buffers;
+    coverage.clear();
+    coverage.resize(width, 0.);
+
+    let inside = |winding: i32| match path.fill_rule {
+        FillRule::Nonzero => winding != 0,
+        FillRule::Evenodd => winding % 2 != 0,
+    };
+
+    for sub_scanline in 0..SUB_SCANLINES {
+        let sample_y = y as f32 + (sub_scanline as f32 + 0.5) / SUB_SCANLINES as f32;
+        crossings.clear();
+        for e in path.edges.iter() {
+            if e.y1 > sample_y {
+                // The edges are sorted by y1, so none of the next edges are crossing this sub-scanline
+                break;
+            }
+            if sample_y < e.y2 {
+                let x = e.x1 + (sample_y - e.y1) * (e.x2 - e.x1) / (e.y2 - e.y1);
+                crossings.push((x, e.winding));
+            }
+        }
+        crossings
+            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+
+        let mut winding = 0i32;
+        for pair in crossings.windows(2) {
+            winding += pair[0].1 as i32;
+            if inside(winding) {
+                add_coverage(coverage, pair[0].0, pair[1].0);
+            }
+        }
+    }
+
+    for (x, (pix, cov)) in line_buffer
+        [span.origin.x as usize..(span.origin.x_length() + span.size.width_length()).get() as usize]
+        .iter_mut()
+        .zip(coverage.iter())
+        .enumerate()
+    {
+        let cov = (cov.min(1.) * 255.) as u32;
+        if cov == 0 {
+            continue;
+        }
+        let c = match &path.gradient {
+            Some(gradient) => gradient.color_at(x as i16, y),
+            None => path.color,
+        };
+        pix.blend(if cov == 255 {
+            c
+        } else {
+            PremultipliedRgbaColor {
+                red: (c.red as u32 * cov / 255) as u8,
+                green: (c.green as u32 * cov / 255) as u8,
+                blue: (c.blue as u32 * cov / 255) as u8,
+                alpha: (c.alpha as u32 * cov / 255) as u8,
+            }
+        });
+    }
+}
+
+/// Add the coverage of one sub-scanline between x1 and x2 to the coverage buffer
+fn add_coverage(coverage: &mut [f32], x1: f32, x2: f32) {
+    const WEIGHT: f32 = 1. / SUB_SCANLINES as f32;
+    let x1 = x1.max(0.);
+    let x2 = x2.min(coverage.len() as f32);
+    if x1 >= x2 {
+        return;
+    }
+    let (i1, i2) = (x1.floor() as usize, x2.floor() as usize);
+    if i1 == i2 {
+        coverage[i1] += (x2 - x1) * WEIGHT;
+        return;
+    }
+    coverage[i1] += (i1 as f32 + 1. - x1) * WEIGHT;
+    for c in &mut coverage[i1 + 1..i2] {
+        *c += WEIGHT;
+    }
+    if let Some(c) = coverage.get_mut(i2) {
+        *c += (x2 - i2 as f32) * WEIGHT;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lyon_path::math::point;
+
+    /// The events of a closed polygon going through the given points
+    fn polygon(points: &[(f32, f32)]) -> Vec<lyon_path::PathEvent> {
+        let points: Vec<Point> = points.iter().map(|(x, y)| point(*x, *y)).collect();
+        let mut events = vec![lyon_path::Event::Begin { at: points[0] }];
+        for pair in points.windows(2) {
+            events.push(lyon_path::Event::Line { from: pair[0], to: pair[1] });
+        }
+        events.push(lyon_path::Event::End {
+            last: *points.last().unwrap(),
+            first: points[0],
+            close: true,
+        });
+        events
+    }
+
+    fn square(x1: f32, y1: f32, x2: f32, y2: f32) -> Vec<lyon_path::PathEvent> {
+        polygon(&[(x1, y1), (x2, y1), (x2, y2), (x1, y2)])
+    }
+
+    /// Draw the line of the command in a buffer of the given width, and return the alpha of each pixel
+    fn render_line(mut cmd: PathCommand, width: i16, line: i16) -> Vec<u8> {
+        cmd.finalize(euclid::vec2(0., 0.));
+        cmd.color = PremultipliedRgbaColor { red: 255, green: 255, blue: 255, alpha: 255 };
+        let mut buffer = vec![PremultipliedRgbaColor::default(); width as usize];
+        draw_path_line(
+            &PhysicalRect::new(euclid::point2(0, 0), euclid::size2(width, width)),
+            PhysicalLength::new(line),
+            &cmd,
+            &mut PathLineBuffers::default(),
+            &mut buffer,
+        );
+        buffer.iter().map(|p| p.alpha).collect()
+    }
+
+    #[test]
+    fn add_coverage_partial() {
+        let mut coverage = [0f32; 4];
+        add_coverage(&mut coverage, 0.5, 2.25);
+        assert_eq!(coverage, [0.125, 0.25, 0.0625, 0.]);
+
+        let mut coverage = [0f32; 4];
+        add_coverage(&mut coverage, 1.25, 1.75);
+        assert_eq!(coverage, [0., 0.125, 0., 0.]);
+
+        // Out of bounds and empty ranges are clamped
+        let mut coverage = [0f32; 4];
+        add_coverage(&mut coverage, -3., 10.);
+        add_coverage(&mut coverage, 3., 1.);
+        assert_eq!(coverage, [0.25; 4]);
+    }
+
+    #[test]
+    fn fill_rules() {
+        // Two nested squares with the same orientation
+        let events = || square(0., 0., 8., 8.).into_iter().chain(square(2., 2., 6., 6.));
+
+        let nonzero = fill_command(events(), FillRule::Nonzero);
+        assert_eq!(render_line(nonzero, 8, 4), [255; 8]);
+
+        let evenodd = fill_command(events(), FillRule::Evenodd);
+        assert_eq!(render_line(evenodd, 8, 4), [255, 255, 0, 0, 0, 0, 255, 255]);
+        // Outside of the inner square, both rules fill the whole line
+        let evenodd = fill_command(events(), FillRule::Evenodd);
+        assert_eq!(render_line(evenodd, 8, 1), [255; 8]);
+    }
+
+    #[test]
+    fn fill_partial_coverage() {
+        let cmd = fill_command(square(0.5, 0., 2.5, 4.).into_iter(), FillRule::Nonzero);
+        assert_eq!(render_line(cmd, 4, 1), [127, 255, 127, 0]);
+
+        // Only the bottom half of the line is covered
+        let cmd = fill_command(square(0., 1.5, 4., 4.).into_iter(), FillRule::Nonzero);
+        assert_eq!(render_line(cmd, 4, 1), [127; 4]);
+    }
+
+    #[test]
+    fn stroke() {
+        let events = [
+            lyon_path::Event::Begin { at: point(1., 4.) },
+            lyon_path::Event::Line { from: point(1., 4.), to: point(7., 4.) },
+            lyon_path::Event::End { last: point(7., 4.), first: point(1., 4.), close: false },
+        ];
+        let cmd = stroke_command(events.into_iter(), 2.);
+        let bounding_box = cmd.bounding_box().unwrap();
+        assert_eq!(
+            bounding_box,
+            euclid::Box2D::new(point(1., 3.).cast_unit(), point(7., 5.).cast_unit())
+        );
+        assert_eq!(render_line(cmd, 8, 3), [0, 255, 255, 255, 255, 255, 255, 0]);
+        let cmd = stroke_command(events.into_iter(), 2.);
+        assert_eq!(render_line(cmd, 8, 5), [0; 8]);
+
+        // The inside of a stroked closed shape is not filled
+        let cmd = stroke_command(square(2., 2., 10., 10.).into_iter(), 2.);
+        assert_eq!(render_line(cmd, 12, 6), [0, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 0]);
+    }
+}
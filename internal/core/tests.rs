@@ -56,6 +56,26 @@ pub extern "C" fn slint_send_mouse_click(
     );
 }
 
+/// Simulate a drag with the left mouse button, from one position of the window to another.
+#[no_mangle]
+pub extern "C" fn slint_send_mouse_drag(
+    from_x: Coord,
+    from_y: Coord,
+    to_x: Coord,
+    to_y: Coord,
+    window_adapter: &crate::window::WindowAdapterRc,
+) {
+    let window = window_adapter.window();
+    let from = crate::api::LogicalPosition::new(from_x as f32, from_y as f32);
+    let to = crate::api::LogicalPosition::new(to_x as f32, to_y as f32);
+    let button = crate::items::PointerEventButton::Left;
+    window.dispatch_event(WindowEvent::PointerMoved { position: from });
+    window.dispatch_event(WindowEvent::PointerPressed { position: from, button });
+    slint_mock_elapsed_time(50);
+    window.dispatch_event(WindowEvent::PointerMoved { position: to });
+    window.dispatch_event(WindowEvent::PointerReleased { position: to, button });
+}
+
 /// Simulate a character input event (pressed or released).
 #[no_mangle]
 pub extern "C" fn slint_send_keyboard_char(
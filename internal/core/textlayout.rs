@@ -22,6 +22,7 @@
 //!
 
 use alloc::vec::Vec;
+use core::ops::{ControlFlow, Range};
 
 use euclid::num::{One, Zero};
 
@@ -81,7 +82,10 @@ pub fn text_size(
 pub struct PositionedGlyph<Length> {
     pub x: Length,
     pub y: Length,
+    pub advance: Length,
     pub glyph_id: core::num::NonZeroU16,
+    /// The byte offset in the paragraph's string of the character that produced this glyph.
+    pub text_byte_offset: usize,
 }
 
 pub struct TextParagraphLayout<'a, Font: AbstractFont> {
@@ -98,7 +102,8 @@ pub struct TextParagraphLayout<'a, Font: AbstractFont> {
 
 impl<'a, Font: AbstractFont> TextParagraphLayout<'a, Font> {
     /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
-    /// The signature of the `layout_line` function is: `(glyph_iterator, line_x, line_y)`.
+    /// The signature of the `layout_line` function is: `(glyph_iterator, line_x, line_y, selection)`, where
+    /// `selection` is the horizontal range (relative to `line_x`) covered by the `selection` byte range on that line.
     /// Returns the baseline y coordinate.
     pub fn layout_lines(
         &self,
@@ -106,9 +111,10 @@ pub fn layout_lines(
             &mut dyn Iterator<Item = PositionedGlyph<Font::Length>>,
             Font::Length,
             Font::Length,
+            Option<Range<Font::Length>>,
         ),
+        selection: Option<Range<usize>>,
     ) -> Font::Length {
-        let wrap = self.wrap == TextWrap::WordWrap;
         let elide_glyph = if self.overflow == TextOverflow::Elide {
             self.layout.font.glyph_for_char('…').filter(|glyph| glyph.glyph_id.is_some())
         } else {
@@ -119,10 +125,179 @@ pub fn layout_lines(
 
         let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
 
+        let result: Result<_, core::convert::Infallible> =
+            self.for_each_line(&shape_buffer, |line, x, y| {
+                let glyphs = &shape_buffer.glyphs[line.glyph_range.clone()];
+
+                let selection_range = selection.as_ref().and_then(|selection| {
+                    let mut glyph_x = Font::Length::zero();
+                    let mut range: Option<Range<Font::Length>> = None;
+                    for glyph in glyphs {
+                        if selection.contains(&glyph.text_byte_offset) {
+                            range.get_or_insert(glyph_x..glyph_x).end = glyph_x + glyph.advance;
+                        }
+                        glyph_x += glyph.advance;
+                    }
+                    range
+                });
+
+                let mut elide_glyph = elide_glyph.as_ref().clone();
+
+                let mut glyph_x = Font::Length::zero();
+                let mut positioned_glyph_it = glyphs.iter().filter_map(|glyph| {
+                    // TODO: cut off at grapheme boundaries
+                    if glyph_x > max_width_without_elision {
+                        if let Some(elide_glyph) = elide_glyph.take() {
+                            return Some(PositionedGlyph {
+                                x: glyph_x,
+                                y: Font::Length::zero(),
+                                advance: elide_glyph.advance,
+                                glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
+                                text_byte_offset: glyph.text_byte_offset,
+                            });
+                        } else {
+                            return None;
+                        }
+                    }
+                    let x = glyph_x;
+                    glyph_x += glyph.advance;
+
+                    if let Some(existing_glyph_id) = glyph.glyph_id {
+                        Some(PositionedGlyph {
+                            x,
+                            y: Font::Length::zero(),
+                            advance: glyph.advance,
+                            glyph_id: existing_glyph_id,
+                            text_byte_offset: glyph.text_byte_offset,
+                        })
+                    } else {
+                        None
+                    }
+                });
+
+                line_callback(&mut positioned_glyph_it, x, y, selection_range);
+                ControlFlow::Continue(())
+            });
+
+        match result {
+            Ok(baseline_y) => baseline_y,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Returns the position of the text cursor for the given byte offset in the string, as a
+    /// tuple of the x coordinate and the y coordinate of the top of the line.
+    pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, Font::Length) {
+        let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
+
+        // The position at the end of the previous line
+        let mut end_of_previous_line = (Font::Length::zero(), Font::Length::zero());
+
+        let result = self.for_each_line(&shape_buffer, |line, line_x, line_y| {
+            if line.glyph_range.is_empty() {
+                end_of_previous_line = (line_x, line_y);
+                return ControlFlow::Continue(());
+            }
+            let glyphs = &shape_buffer.glyphs[line.glyph_range.clone()];
+            if glyphs[0].text_byte_offset > byte_offset {
+                // The offset is between two lines, for example on an empty line that was skipped
+                return ControlFlow::Break(end_of_previous_line);
+            }
+
+            let mut glyph_x = Font::Length::zero();
+            for glyph in glyphs {
+                if glyph.text_byte_offset >= byte_offset {
+                    return ControlFlow::Break((line_x + glyph_x, line_y));
+                }
+                glyph_x += glyph.advance;
+            }
+            end_of_previous_line = (line_x + glyph_x, line_y);
+
+            // Only place the cursor at the end of the line if it is not at the beginning of the next one
+            let line_end = self.line_end_byte_offset(line, &shape_buffer);
+            if byte_offset <= line_end
+                && (line_end >= self.string.len()
+                    || self.string[line_end..].starts_with(is_line_or_paragraph_separator))
+            {
+                ControlFlow::Break(end_of_previous_line)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        match result {
+            Ok(_) => end_of_previous_line,
+            Err(pos) => pos,
+        }
+    }
+
+    /// Returns the byte offset in the string of the position where the text cursor should be placed when
+    /// clicking at the given position, relative to the paragraph.
+    pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Length)) -> usize {
+        let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+
+        let mut byte_offset = 0;
+
+        let result = self.for_each_line(&shape_buffer, |line, line_x, line_y| {
+            byte_offset = if line.glyph_range.is_empty() {
+                self.string.len()
+            } else {
+                self.line_end_byte_offset(line, &shape_buffer)
+            };
+            if pos_y >= line_y + self.layout.font.height() {
+                // Not on this line, but remember the end in case this is the last line.
+                return ControlFlow::Continue(());
+            }
+
+            let mut glyph_x = line_x;
+            for glyph in &shape_buffer.glyphs[line.glyph_range.clone()] {
+                if pos_x < glyph_x + glyph.advance / two
+                    || self.string[glyph.text_byte_offset..]
+                        .starts_with(is_line_or_paragraph_separator)
+                {
+                    return ControlFlow::Break(glyph.text_byte_offset);
+                }
+                glyph_x += glyph.advance;
+            }
+            ControlFlow::Break(byte_offset)
+        });
+
+        match result {
+            Ok(_) => byte_offset,
+            Err(offset) => offset,
+        }
+    }
+
+    /// Returns the byte offset of the end of the line, which is the offset of the first glyph after the line.
+    fn line_end_byte_offset(
+        &self,
+        line: &TextLine<Font::Length>,
+        shape_buffer: &ShapeBuffer<Font::Length>,
+    ) -> usize {
+        shape_buffer
+            .glyphs
+            .get(line.glyph_range.end)
+            .map_or(self.string.len(), |glyph| glyph.text_byte_offset)
+    }
+
+    /// Break the string in lines and call the `line_callback` with each line and its position `(line, line_x, line_y)`.
+    /// Returns the baseline y coordinate, or the value with which the callback broke the iteration.
+    fn for_each_line<R>(
+        &self,
+        shape_buffer: &ShapeBuffer<Font::Length>,
+        mut line_callback: impl FnMut(
+            &TextLine<Font::Length>,
+            Font::Length,
+            Font::Length,
+        ) -> ControlFlow<R>,
+    ) -> Result<Font::Length, R> {
+        let wrap = self.wrap == TextWrap::WordWrap;
+
         let new_line_break_iter = || {
             TextLineBreaker::<Font>::new(
                 self.string,
-                &shape_buffer,
+                shape_buffer,
                 if wrap { Some(self.max_width) } else { None },
             )
         };
@@ -147,7 +322,7 @@ pub fn layout_lines(
 
         let mut y = baseline_y;
 
-        let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
+        let mut process_line = |line: &TextLine<Font::Length>| {
             let x = match self.horizontal_alignment {
                 TextHorizontalAlignment::Left => Font::Length::zero(),
                 TextHorizontalAlignment::Center => {
@@ -159,55 +334,33 @@ pub fn layout_lines(
                 }
             };
 
-            let mut elide_glyph = elide_glyph.as_ref().clone();
-
-            let glyph_it = glyphs[line.glyph_range.clone()].iter();
-            let mut glyph_x = Font::Length::zero();
-            let mut positioned_glyph_it = glyph_it.filter_map(|glyph| {
-                // TODO: cut off at grapheme boundaries
-                if glyph_x > max_width_without_elision {
-                    if let Some(elide_glyph) = elide_glyph.take() {
-                        return Some(PositionedGlyph {
-                            x: glyph_x,
-                            y: Font::Length::zero(),
-                            glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
-                        });
-                    } else {
-                        return None;
-                    }
-                }
-                let x = glyph_x;
-                glyph_x += glyph.advance;
-
-                if let Some(existing_glyph_id) = glyph.glyph_id {
-                    Some(PositionedGlyph {
-                        x,
-                        y: Font::Length::zero(),
-                        glyph_id: existing_glyph_id,
-                    })
-                } else {
-                    None
-                }
-            });
-
-            line_callback(&mut positioned_glyph_it, x, y);
+            let result = line_callback(line, x, y);
             y += self.layout.font.height();
+            result
         };
 
         if let Some(lines_vec) = text_lines.take() {
             for line in lines_vec {
-                process_line(&line, &shape_buffer.glyphs);
+                if let ControlFlow::Break(r) = process_line(&line) {
+                    return Err(r);
+                }
             }
         } else {
             for line in new_line_break_iter() {
-                process_line(&line, &shape_buffer.glyphs);
+                if let ControlFlow::Break(r) = process_line(&line) {
+                    return Err(r);
+                }
             }
         }
 
-        baseline_y
+        Ok(baseline_y)
     }
 }
 
+fn is_line_or_paragraph_separator(ch: char) -> bool {
+    ch == '\n' || ch == '\u{2028}' || ch == '\u{2029}'
+}
+
 #[test]
 fn test_no_linebreak_opportunity_at_eot() {
     let mut it = LineBreakIterator::new("Hello World");
@@ -287,11 +440,16 @@ fn test_elision() {
         overflow: TextOverflow::Elide,
         single_line: true,
     };
-    paragraph.layout_lines(|glyphs, _, _| {
-        lines.push(
-            glyphs.map(|positioned_glyph| positioned_glyph.glyph_id.clone()).collect::<Vec<_>>(),
-        );
-    });
+    paragraph.layout_lines(
+        |glyphs, _, _, _| {
+            lines.push(
+                glyphs
+                    .map(|positioned_glyph| positioned_glyph.glyph_id.clone())
+                    .collect::<Vec<_>>(),
+            );
+        },
+        None,
+    );
 
     assert_eq!(lines.len(), 1);
     let rendered_text = lines[0]
@@ -323,11 +481,16 @@ fn test_exact_fit() {
         overflow: TextOverflow::Elide,
         single_line: true,
     };
-    paragraph.layout_lines(|glyphs, _, _| {
-        lines.push(
-            glyphs.map(|positioned_glyph| positioned_glyph.glyph_id.clone()).collect::<Vec<_>>(),
-        );
-    });
+    paragraph.layout_lines(
+        |glyphs, _, _, _| {
+            lines.push(
+                glyphs
+                    .map(|positioned_glyph| positioned_glyph.glyph_id.clone())
+                    .collect::<Vec<_>>(),
+            );
+        },
+        None,
+    );
 
     assert_eq!(lines.len(), 1);
     let rendered_text = lines[0]
@@ -359,11 +522,16 @@ fn test_no_line_separators_characters_rendered() {
         overflow: TextOverflow::Clip,
         single_line: true,
     };
-    paragraph.layout_lines(|glyphs, _, _| {
-        lines.push(
-            glyphs.map(|positioned_glyph| positioned_glyph.glyph_id.clone()).collect::<Vec<_>>(),
-        );
-    });
+    paragraph.layout_lines(
+        |glyphs, _, _, _| {
+            lines.push(
+                glyphs
+                    .map(|positioned_glyph| positioned_glyph.glyph_id.clone())
+                    .collect::<Vec<_>>(),
+            );
+        },
+        None,
+    );
 
     assert_eq!(lines.len(), 2);
     let rendered_text = lines
@@ -381,3 +549,55 @@ fn test_no_line_separators_characters_rendered() {
         .collect::<Vec<_>>();
     debug_assert_eq!(rendered_text, vec!["Hello", "World"]);
 }
+
+#[test]
+fn test_cursor_position() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 100.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(0), (0., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(3), (30., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(5), (50., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (0., 10.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(11), (50., 10.));
+
+    assert_eq!(paragraph.byte_offset_for_position((24., 5.)), 2);
+    assert_eq!(paragraph.byte_offset_for_position((26., 5.)), 3);
+    assert_eq!(paragraph.byte_offset_for_position((200., 5.)), 5);
+    assert_eq!(paragraph.byte_offset_for_position((0., 15.)), 6);
+    assert_eq!(paragraph.byte_offset_for_position((200., 50.)), 11);
+}
+
+#[test]
+fn test_selection_range() {
+    let font = FixedTestFont;
+    let text = "Hello World";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 60.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    let mut selections = Vec::new();
+    paragraph.layout_lines(|_, _, _, selection| selections.push(selection), Some(4..8));
+    assert_eq!(selections, vec![Some(40. ..60.), Some(0. ..20.)]);
+}
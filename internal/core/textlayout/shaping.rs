@@ -180,6 +180,11 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
 
                 layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
 
+                // The shaper produces byte offsets relative to the run, make them relative to the text
+                for glyph in &mut glyphs[glyphs_start..] {
+                    glyph.text_byte_offset += *run_start;
+                }
+
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
                         let mut last_byte_offset = glyphs[glyphs_start].text_byte_offset;
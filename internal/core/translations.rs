@@ -0,0 +1,462 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Runtime support for the `@tr(...)` macro and for the number formatting in string templates
+//! of the .slint language.
+//!
+//! When the `gettext-rs` feature is enabled, the strings are looked up in the catalog of
+//! [gettext](https://www.gnu.org/software/gettext/). Otherwise, the original string is used.
+
+use crate::{Property, SharedString};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::pin::Pin;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+/// The language selected with [`set_language`]. All the translated strings depend on it, so that
+/// their bindings are re-evaluated when the language changes.
+static CURRENT_LANGUAGE: Pin<Box<Property<SharedString>>> =
+    Box::pin(Property::new_named(SharedString::default(), "i_slint_core::translations::CURRENT_LANGUAGE"))
+);
+
+/// Formats the string by replacing the placeholders with the arguments.
+///
+/// `{}` is replaced by the next argument, `{0}`, `{1}`, ... by the argument at that position,
+/// and `{n}` by `n`. `{{` and `}}` are replaced by `{` and `}`.
+/// The compiler already made sure that the format string was valid, invalid placeholders are
+/// kept as is.
+fn format(format_str: &str, arguments: &[SharedString], n: i32) -> SharedString {
+    let mut output = String::with_capacity(format_str.len());
+    let mut next_positional = 0;
+    let mut rest = format_str;
+    while let Some(pos) = rest.find(|c| c == '{' || c == '}') {
+        output.push_str(&rest[..pos]);
+        let (brace, after) = rest[pos..].split_at(1);
+        if after.starts_with(brace) {
+            // escaped brace
+            output.push_str(brace);
+            rest = &after[1..];
+            continue;
+        }
+        if brace == "}" {
+            output.push_str(brace);
+            rest = after;
+            continue;
+        }
+        let end = match after.find('}') {
+            Some(end) => end,
+            None => {
+                // unterminated placeholder
+                output.push_str(&rest[pos..]);
+                rest = "";
+                break;
+            }
+        };
+        let placeholder = &after[..end];
+        let argument = if placeholder.is_empty() {
+            next_positional += 1;
+            arguments.get(next_positional - 1)
+        } else if placeholder == "n" {
+            output.push_str(&alloc::format!("{n}"));
+            rest = &after[end + 1..];
+            continue;
+        } else {
+            placeholder.parse::<usize>().ok().and_then(|i| arguments.get(i))
+        };
+        match argument {
+            Some(argument) => output.push_str(argument),
+            None => output.push_str(&rest[pos..pos + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    output.push_str(rest);
+    output.into()
+}
+
+/// Translate the string and format it with the arguments.
+///
+/// This is the implementation of the `@tr(...)` macro. `context` and `plural` are empty
+/// if they were not specified. `n` is the count used to select the plural form.
+pub fn translate(
+    original: &str,
+    context: &str,
+    domain: &str,
+    arguments: &[SharedString],
+    n: i32,
+    plural: &str,
+) -> SharedString {
+    // Register the dependency so that the binding is re-evaluated when the language changes
+    CURRENT_LANGUAGE.with(|language| language.as_ref().get());
+    #[cfg(feature = "gettext-rs")]
+    let translated = translate_gettext(original, context, domain, n, plural);
+    #[cfg(not(feature = "gettext-rs"))]
+    let translated = {
+        let _ = (context, domain);
+        if plural.is_empty() || n == 1 {
+            original
+        } else {
+            plural
+        }
+    };
+    format(&translated, arguments, n)
+}
+
+#[cfg(feature = "gettext-rs")]
+fn translate_gettext(string: &str, ctx: &str, domain: &str, n: i32, plural: &str) -> String {
+    // The context is prepended to the message id, separated with the EOT character, like
+    // what the pgettext macro from the C gettext header does
+    fn mangle_context(ctx: &str, s: &str) -> String {
+        alloc::format!("{ctx}\u{4}{s}")
+    }
+    // If the string was not translated, gettext returns the mangled string
+    fn demangle_context(r: String) -> String {
+        match r.split_once('\u{4}') {
+            Some((_, s)) => s.into(),
+            None => r,
+        }
+    }
+
+    let has_plural = !plural.is_empty();
+    let (string, plural) = if ctx.is_empty() {
+        (string.into(), plural.into())
+    } else {
+        (mangle_context(ctx, string), mangle_context(ctx, plural))
+    };
+    let n = n.unsigned_abs();
+    let result = match (domain.is_empty(), has_plural) {
+        (true, false) => gettextrs::gettext(string),
+        (true, true) => gettextrs::ngettext(string, plural, n),
+        (false, false) => gettextrs::dgettext(domain, string),
+        (false, true) => gettextrs::dngettext(domain, string, plural, n),
+    };
+    if ctx.is_empty() {
+        result
+    } else {
+        demangle_context(result)
+    }
+}
+
+/// Returns the language set with [`set_language`], or the locale of the environment if none was set.
+fn current_locale() -> SharedString {
+    // Register the dependency so that the binding is re-evaluated when the language changes
+    let language = CURRENT_LANGUAGE.with(|language| language.as_ref().get());
+    #[cfg(feature = "std")]
+    if language.is_empty() {
+        return ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|locale| !locale.is_empty())
+            .map_or_else(SharedString::default, |locale| locale.as_str().into());
+    }
+    language
+}
+
+/// Returns the decimal mark and the group separator used for the numbers in the given locale,
+/// such as `de_DE.UTF-8` or `fr-CH`.
+fn number_separators(locale: &str) -> (char, char) {
+    let mut parts = locale.split(|c| matches!(c, '_' | '-' | '.' | '@'));
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+    match (language.as_str(), region.as_str()) {
+        ("de" | "it" | "gsw", "CH" | "LI") => ('.', '\''),
+        (
+            "de" | "nl" | "it" | "es" | "pt" | "id" | "tr" | "el" | "da" | "ro" | "hr" | "sl"
+            | "sr" | "vi" | "ca" | "is",
+            _,
+        ) => (',', '.'),
+        (
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi" | "uk" | "hu"
+            | "bg" | "lt" | "lv" | "et" | "be" | "kk",
+            _,
+        ) => (',', '\u{a0}'),
+        _ => ('.', ','),
+    }
+}
+
+/// Format a number for a placeholder with a format specification in a string template,
+/// such as `"\{value:08,.2L}"`.
+///
+/// `width` is the minimum number of characters. The number is padded with spaces, or with zeros
+/// after the sign if `zero_pad` is true. `precision` is the number of digits after the decimal
+/// mark, or a negative number for the shortest representation. When `grouping` is true, the
+/// thousands are separated. When `localized` is true, the decimal mark and the group separator
+/// depend on the language set with [`set_language`], or on the locale of the environment.
+pub fn format_number(
+    value: f64,
+    width: i32,
+    precision: i32,
+    zero_pad: bool,
+    grouping: bool,
+    localized: bool,
+) -> SharedString {
+    let (decimal_mark, group_separator) =
+        if localized { number_separators(&current_locale()) } else { ('.', ',') };
+    let magnitude = value.abs();
+    let digits = if precision >= 0 {
+        alloc::format!("{:.*}", precision as usize, magnitude)
+    } else if magnitude as f32 as f64 == magnitude {
+        // Use the shortest representation of the f32, so that 0.1f32 is not shown as 0.10000000149011612
+        alloc::format!("{}", magnitude as f32)
+    } else {
+        alloc::format!("{}", magnitude)
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits.as_str(), ""));
+
+    let mut result = String::new();
+    // Don't show "-0" when the value is rounded to zero
+    if value.is_sign_negative()
+        && !value.is_nan()
+        && digits.bytes().any(|c| !matches!(c, b'0' | b'.'))
+    {
+        result.push('-');
+    }
+    let sign_len = result.len();
+    for (i, c) in integer.chars().enumerate() {
+        if grouping && i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push(group_separator);
+        }
+        result.push(c);
+    }
+    if !fraction.is_empty() {
+        result.push(decimal_mark);
+        result.push_str(fraction);
+    }
+
+    let len = result.chars().count();
+    let width = width.max(0) as usize;
+    if len < width {
+        if zero_pad {
+            result.insert_str(sign_len, &"0".repeat(width - len));
+        } else {
+            result.insert_str(0, &" ".repeat(width - len));
+        }
+    }
+    result.into()
+}
+
+/// Change the language of the translations, and mark all the translated strings as dirty.
+///
+/// See [`crate::api::set_language`]
+pub fn set_language(language: &str) -> Result<(), crate::api::SetLanguageError> {
+    #[cfg(feature = "gettext-rs")]
+    set_gettext_language(language)?;
+    CURRENT_LANGUAGE.with(|current| current.as_ref().set(language.into()));
+    Ok(())
+}
+
+/// Select the language of the messages looked up by gettext. An empty language resets it to
+/// the language of the environment.
+///
+/// The language is selected with the `LANGUAGE` environment variable, so that it doesn't need a
+/// locale installed on the system for that language. But gettext ignores that variable in the
+/// "C" locale, so the `LC_MESSAGES` locale is set to the one of the environment, or to `C.UTF-8`
+/// if that is "C".
+#[cfg(feature = "gettext-rs")]
+fn set_gettext_language(language: &str) -> Result<(), crate::api::SetLanguageError> {
+    use gettextrs::{setlocale, LocaleCategory};
+    // The LANGUAGE of the environment, restored when the language is reset
+    static ENV_LANGUAGE: once_cell::sync::OnceCell<Option<std::ffi::OsString>> =
+        once_cell::sync::OnceCell::new();
+    let env_language = ENV_LANGUAGE.get_or_init(|| std::env::var_os("LANGUAGE"));
+
+    if language.is_empty() {
+        match env_language {
+            Some(env_language) => std::env::set_var("LANGUAGE", env_language),
+            None => std::env::remove_var("LANGUAGE"),
+        }
+        setlocale(LocaleCategory::LcMessages, "");
+    } else {
+        let locale = setlocale(LocaleCategory::LcMessages, "");
+        let is_c_locale =
+            locale.as_deref().map_or(true, |locale| matches!(locale, b"C" | b"POSIX"));
+        if is_c_locale
+            && ["C.UTF-8", "C.utf8"]
+                .iter()
+                .all(|locale| setlocale(LocaleCategory::LcMessages, *locale).is_none())
+        {
+            return Err(crate::api::SetLanguageError::NoUsableLocale);
+        }
+        std::env::set_var("LANGUAGE", language);
+    }
+
+    // gettext caches the translations, and doesn't notice when LANGUAGE changes. Setting the
+    // text domain again invalidates that cache.
+    if let Ok(domain) = gettextrs::current_textdomain() {
+        let _ = gettextrs::textdomain(domain);
+    }
+    Ok(())
+}
+
+/// Bind the gettext domain to the directory containing the translations, and initialize the
+/// locale from the environment.
+///
+/// The translations are looked up in `<dirname>/<locale>/LC_MESSAGES/<domain>.mo`.
+#[cfg(feature = "gettext-rs")]
+pub fn gettext_bindtextdomain(domain: &str, dirname: std::path::PathBuf) -> std::io::Result<()> {
+    static START: std::sync::Once = std::sync::Once::new();
+    START.call_once(|| {
+        gettextrs::setlocale(gettextrs::LocaleCategory::LcAll, "");
+    });
+    gettextrs::bindtextdomain(domain, dirname)?;
+    gettextrs::bind_textdomain_codeset(domain, "UTF-8")?;
+    Ok(())
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+    use super::*;
+    use crate::slice::Slice;
+
+    #[no_mangle]
+    /// Translate the string in `to_translate` (replaced by the result) with the given context,
+    /// domain, arguments, count and plural form. This is the implementation of `@tr(...)`.
+    pub extern "C" fn slint_translate(
+        to_translate: &mut SharedString,
+        context: &SharedString,
+        domain: &SharedString,
+        arguments: Slice<SharedString>,
+        n: i32,
+        plural: &SharedString,
+    ) {
+        *to_translate =
+            translate(to_translate.as_str(), context, domain, arguments.as_slice(), n, plural)
+    }
+
+    #[no_mangle]
+    /// Format the number into `out`, for a placeholder with a format specification in a string
+    /// template. See [`format_number`]
+    pub extern "C" fn slint_format_number(
+        out: &mut SharedString,
+        value: f64,
+        width: i32,
+        precision: i32,
+        zero_pad: bool,
+        grouping: bool,
+        localized: bool,
+    ) {
+        *out = format_number(value, width, precision, zero_pad, grouping, localized)
+    }
+
+    #[no_mangle]
+    /// Change the language of the translations. See [`set_language`]
+    /// Returns false if the language could not be changed.
+    pub extern "C" fn slint_translations_set_language(language: &SharedString) -> bool {
+        set_language(language).is_ok()
+    }
+}
+
+#[test]
+fn format_test() {
+    let args = [SharedString::from("foo"), SharedString::from("bar")];
+    assert_eq!(format("Hello", &args, 1), "Hello");
+    assert_eq!(format("Hello {}, {}!", &args, 1), "Hello foo, bar!");
+    assert_eq!(format("{1} {0}", &args, 1), "bar foo");
+    assert_eq!(format("{} {0} {}", &args, 1), "foo foo bar");
+    assert_eq!(format("{n} items in {}", &args, 42), "42 items in foo");
+    assert_eq!(format("{{}} {{{}}} }}", &args, 1), "{} {foo} }");
+    assert_eq!(format("Unterminated {", &args, 1), "Unterminated {");
+    assert_eq!(format("{2} {xx} {", &args, 1), "{2} {xx} {");
+}
+
+#[test]
+fn translate_without_catalog() {
+    let args = [SharedString::from("5")];
+    assert_eq!(translate("Hello", "", "", &[], 1, ""), "Hello");
+    assert_eq!(translate("{} file", "ctx", "", &args, 1, "{} files"), "5 file");
+    assert_eq!(translate("{n} file", "ctx", "", &args, 5, "{n} files"), "5 files");
+    assert_eq!(translate("{n} file", "", "", &args, 0, "{n} files"), "0 files");
+}
+
+/// The language is global to the process when using gettext, so the tests that change it must
+/// not run at the same time.
+#[cfg(test)]
+static SET_LANGUAGE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[test]
+fn set_language_marks_translations_dirty() {
+    let _lock = SET_LANGUAGE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let tracker = Box::pin(crate::properties::PropertyTracker::default());
+    let result = tracker.as_ref().evaluate(|| translate("Hello", "", "", &[], 1, ""));
+    assert_eq!(result, "Hello");
+    assert!(!tracker.is_dirty());
+    set_language("fr").unwrap();
+    assert!(tracker.is_dirty());
+    let result = tracker.as_ref().evaluate(|| translate("Hello", "", "", &[], 1, ""));
+    assert_eq!(result, "Hello");
+    assert!(!tracker.is_dirty());
+}
+
+#[test]
+fn format_number_test() {
+    assert_eq!(format_number(42., 0, -1, false, false, false), "42");
+    assert_eq!(format_number(0.1f32 as f64, 0, -1, false, false, false), "0.1");
+    assert_eq!(format_number(1. / 3., 0, -1, false, false, false), "0.3333333333333333");
+    assert_eq!(format_number(3.14159, 0, 2, false, false, false), "3.14");
+    assert_eq!(format_number(2.75, 0, 0, false, false, false), "3");
+    assert_eq!(format_number(-0.001, 0, 2, false, false, false), "0.00");
+    assert_eq!(format_number(-42.5, 8, 2, false, false, false), "  -42.50");
+    assert_eq!(format_number(-42.5, 8, 2, true, false, false), "-0042.50");
+    assert_eq!(format_number(7., 3, -1, true, false, false), "007");
+    assert_eq!(format_number(1234567.891, 0, 2, false, true, false), "1,234,567.89");
+    assert_eq!(format_number(-123456., 0, -1, false, true, false), "-123,456");
+    assert_eq!(format_number(999., 0, -1, false, true, false), "999");
+    assert_eq!(format_number(f64::NEG_INFINITY, 0, 2, false, true, false), "-inf");
+}
+
+#[test]
+fn format_number_localized() {
+    assert_eq!(number_separators("de_DE.UTF-8"), (',', '.'));
+    assert_eq!(number_separators("de-CH"), ('.', '\''));
+    assert_eq!(number_separators("fr"), (',', '\u{a0}'));
+    assert_eq!(number_separators("en_US"), ('.', ','));
+    assert_eq!(number_separators("C"), ('.', ','));
+
+    let _lock = SET_LANGUAGE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let tracker = Box::pin(crate::properties::PropertyTracker::default());
+    set_language("de").unwrap();
+    let result = tracker.as_ref().evaluate(|| format_number(1234.5, 0, 2, false, true, true));
+    assert_eq!(result, "1.234,50");
+    set_language("en").unwrap();
+    assert!(tracker.is_dirty());
+    assert_eq!(format_number(1234.5, 0, 2, false, true, true), "1,234.50");
+    assert_eq!(format_number(1234.5, 0, 2, false, true, false), "1,234.50");
+}
+
+#[cfg(feature = "gettext-rs")]
+#[test]
+fn set_language_with_gettext() {
+    let _lock = SET_LANGUAGE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    // Only the "fr" and "de" catalogs are in that directory. No locale needs to be installed
+    // for these languages.
+    let domain = "slint-core-test";
+    gettext_bindtextdomain(
+        domain,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/translations").into(),
+    )
+    .unwrap();
+    let hello = || translate("Hello", "", domain, &[], 1, "");
+    let files = |n| translate("{n} file", "", domain, &[], n, "{n} files");
+
+    set_language("fr").unwrap();
+    assert_eq!(hello(), "Bonjour");
+    assert_eq!(files(0), "0 fichier");
+    assert_eq!(files(2), "2 fichiers");
+
+    let tracker = Box::pin(crate::properties::PropertyTracker::default());
+    assert_eq!(tracker.as_ref().evaluate(hello), "Bonjour");
+    set_language("de").unwrap();
+    assert!(tracker.is_dirty());
+    assert_eq!(tracker.as_ref().evaluate(hello), "Hallo");
+    assert_eq!(files(0), "0 Dateien");
+    assert_eq!(files(1), "1 Datei");
+
+    set_language("ja").unwrap();
+    assert_eq!(hello(), "Hello");
+    assert_eq!(files(2), "2 files");
+}
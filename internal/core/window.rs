@@ -12,15 +12,15 @@
 use crate::component::{ComponentRc, ComponentRef, ComponentVTable, ComponentWeak};
 use crate::graphics::Point;
 use crate::input::{
-    key_codes, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
-    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker,
+    key_codes, DragDropState, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
+    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker, TouchEvent, TouchInputState,
 };
-use crate::item_tree::ItemRc;
-use crate::items::{ItemRef, MouseCursor};
+use crate::item_tree::{ItemRc, ItemVisitorResult, TraversalOrder};
+use crate::items::{DropEvent, ItemRef, MouseCursor, Shortcut};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
-use crate::{Callback, Coord};
+use crate::{Callback, Coord, SharedString};
 use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
 use core::cell::{Cell, RefCell};
@@ -203,6 +203,15 @@ struct PopupWindow {
     location: PopupWindowLocation,
     /// The component that is responsible for providing the popup content.
     component: ComponentRc,
+    /// The item relative to which the popup was shown. The popup is closed when that item is destroyed.
+    parent_item: crate::item_tree::ItemWeak,
+    /// The index of the popup in the component of its parent item. Together with the parent item,
+    /// it identifies the PopupWindow element.
+    popup_index: usize,
+    /// When true, the popup is closed when the user clicks on it or outside of it.
+    close_on_click: bool,
+    /// Invoked when the user clicks outside of the popup.
+    close_requested: Option<Rc<dyn Fn()>>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -210,6 +219,12 @@ pub struct WindowInner {
     window_adapter_weak: Weak<dyn WindowAdapter>,
     component: RefCell<ComponentWeak>,
     mouse_input_state: Cell<MouseInputState>,
+    touch_input_state: Cell<TouchInputState>,
+    drag_drop_state: Cell<DragDropState>,
+    /// The paths of the files dragged from the desktop, see [`Self::register_dragged_file`]
+    dragged_files: RefCell<alloc::vec::Vec<SharedString>>,
+    /// Set when the dragged files were dropped: the next registered file starts a new list.
+    dragged_files_dropped: Cell<bool>,
     modifiers: Cell<InternalKeyboardModifierState>,
     redraw_tracker: Pin<Box<PropertyTracker<WindowRedrawTracker>>>,
     /// Gets dirty when the layout restrictions, or some other property of the windows change
@@ -260,6 +275,10 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             window_adapter_weak,
             component: Default::default(),
             mouse_input_state: Default::default(),
+            touch_input_state: Default::default(),
+            drag_drop_state: Default::default(),
+            dragged_files: Default::default(),
+            dragged_files_dropped: Default::default(),
             modifiers: Default::default(),
             redraw_tracker: Box::pin(redraw_tracker),
             window_properties_tracker: Box::pin(window_properties_tracker),
@@ -281,6 +300,8 @@ pub fn set_component(&self, component: &ComponentRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_input_state.replace(Default::default());
+        self.drag_drop_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ComponentRc::downgrade(component));
         self.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
@@ -320,35 +341,36 @@ pub fn try_component(&self) -> Option<ComponentRc> {
     /// * `component`: The Slint compiled component that provides the tree of items.
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::animations::update_animations();
-
-        let embedded_popup_component =
-            self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                PopupWindowLocation::TopLevel(_) => None,
-                PopupWindowLocation::ChildWindow(coordinates) => {
-                    Some((popup.component.clone(), coordinates))
-                }
-            });
-
-        let component = embedded_popup_component
-            .as_ref()
-            .and_then(|(popup_component, coordinates)| {
-                event.translate(-coordinates.to_vector());
-
-                if let MouseEvent::Pressed { position, .. } = &event {
-                    // close the popup if one press outside the popup
-                    let geom = ComponentRc::borrow_pin(popup_component)
-                        .as_ref()
-                        .get_item_ref(0)
-                        .as_ref()
-                        .geometry();
-                    if !geom.contains(*position) {
-                        self.close_popup();
-                        return None;
+        self.close_orphan_popup();
+
+        let embedded_popup_component = self.embedded_popup();
+
+        let mut popup_event = event;
+        let component = match &embedded_popup_component {
+            Some((popup_component, coordinates)) => {
+                popup_event.translate(-coordinates.to_vector());
+                let geom = ComponentRc::borrow_pin(popup_component)
+                    .as_ref()
+                    .get_item_ref(0)
+                    .as_ref()
+                    .geometry();
+                match &popup_event {
+                    MouseEvent::Pressed { position, .. } if !geom.contains(*position) => {
+                        self.request_popup_close();
+                        if self.active_popup.borrow().is_some() {
+                            // The popup stays open: the main component doesn't get the event.
+                            return;
+                        }
+                        self.component.borrow().upgrade()
+                    }
+                    _ => {
+                        event = popup_event;
+                        Some(popup_component.clone())
                     }
                 }
-                Some(popup_component.clone())
-            })
-            .or_else(|| self.component.borrow().upgrade());
+            }
+            None => self.component.borrow().upgrade(),
+        };
 
         let component = if let Some(component) = component {
             component
@@ -363,12 +385,172 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.mouse_input_state.take(),
         ));
 
-        if embedded_popup_component.is_some() {
-            //FIXME: currently the ComboBox is the only thing that uses the popup, and it should close automatically
-            // on release.  But ideally, there would be API to close the popup rather than always closing it on release
-            if matches!(event, MouseEvent::Released { .. }) {
-                self.close_popup();
+        if embedded_popup_component.is_some()
+            && matches!(event, MouseEvent::Released { .. })
+            && self.active_popup_closes_on_click()
+        {
+            self.close_popup();
+        }
+    }
+
+    /// Receive a touch event and pass it to the gesture handlers of the component.
+    /// The first touch point is also processed as a mouse event, until a gesture is recognized.
+    pub fn process_touch_input(&self, event: TouchEvent) {
+        crate::animations::update_animations();
+        self.close_orphan_popup();
+
+        let mut gesture_event = event;
+        let (component, popup_coordinates) = match self.embedded_popup() {
+            Some((popup_component, coordinates)) => {
+                gesture_event.translate(-coordinates.to_vector());
+                (popup_component, Some(coordinates))
+            }
+            None => match self.component.borrow().upgrade() {
+                Some(component) => (component, None),
+                None => return,
+            },
+        };
+
+        let (touch_input_state, mouse_event) = crate::input::process_touch_input(
+            component,
+            gesture_event,
+            self.touch_input_state.take(),
+        );
+        self.touch_input_state.set(touch_input_state);
+
+        match mouse_event {
+            Some(MouseEvent::Exit) => {
+                self.process_mouse_input(MouseEvent::Exit);
+                // A gesture was recognized: the item that grabbed the mouse must not get the
+                // next events
+                self.mouse_input_state.take();
+            }
+            Some(mut mouse_event) => {
+                if let Some(coordinates) = popup_coordinates {
+                    mouse_event.translate(coordinates.to_vector());
+                }
+                self.process_mouse_input(mouse_event);
+            }
+            None => (),
+        }
+    }
+
+    /// Returns the component that receives the drag and drop events, and `position` mapped
+    /// to its coordinates: like the mouse events, they go to the embedded popup when one is open.
+    fn drag_drop_component(&self, position: LogicalPoint) -> Option<(ComponentRc, LogicalPoint)> {
+        match self.embedded_popup() {
+            Some((popup_component, coordinates)) => {
+                Some((popup_component, position - coordinates.to_vector()))
             }
+            None => self.try_component().map(|component| (component, position)),
+        }
+    }
+
+    /// Maps `position`, in the coordinates of `item`, to window coordinates.
+    pub(crate) fn map_item_to_window(&self, item: &ItemRc, position: LogicalPoint) -> LogicalPoint {
+        let position = item.map_to_window(item.geometry().origin + position.to_vector());
+        // While a popup is open, only its items receive the mouse events.
+        match self.embedded_popup() {
+            Some((_, coordinates)) => position + coordinates.to_vector(),
+            None => position,
+        }
+    }
+
+    /// Move the payload of a drag and drop operation to `position`, in window coordinates.
+    /// The DropArea under that position is notified.
+    pub fn process_drag_move(&self, event: &DropEvent, position: LogicalPoint) {
+        let (component, position) = match self.drag_drop_component(position) {
+            Some(component) => component,
+            None => return,
+        };
+        let drag_drop_state = crate::input::process_drag_move(
+            component,
+            event,
+            position,
+            self.drag_drop_state.take(),
+        );
+        self.drag_drop_state.set(drag_drop_state);
+    }
+
+    /// Drop the payload of a drag and drop operation at `position`, in window coordinates.
+    /// Returns true if a DropArea accepted it.
+    pub fn process_drop(&self, event: &DropEvent, position: LogicalPoint) -> bool {
+        let drag_drop_state = self.drag_drop_state.take();
+        match self.drag_drop_component(position) {
+            Some((component, position)) => {
+                crate::input::process_drop(component, event, position, drag_drop_state)
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel the drag and drop operation in progress.
+    pub fn cancel_drag(&self) {
+        crate::input::process_drag_cancel(self.drag_drop_state.take());
+    }
+
+    /// Registers the path of a file dragged from the desktop and returns its index.
+    /// See [`crate::api::Window::register_dragged_file`].
+    pub fn register_dragged_file(&self, path: SharedString) -> usize {
+        let mut dragged_files = self.dragged_files.borrow_mut();
+        if self.dragged_files_dropped.take() {
+            dragged_files.clear();
+        }
+        dragged_files.push(path);
+        dragged_files.len() - 1
+    }
+
+    fn dragged_file_event(&self, file: usize) -> Option<DropEvent> {
+        self.dragged_files.borrow().get(file).map(|path| DropEvent::from_file_path(path))
+    }
+
+    /// A file registered with [`Self::register_dragged_file`] is dragged to `position`.
+    pub fn process_file_hover(&self, file: usize, position: LogicalPoint) {
+        if let Some(event) = self.dragged_file_event(file) {
+            self.process_drag_move(&event, position);
+        }
+    }
+
+    /// A file registered with [`Self::register_dragged_file`] is dropped at `position`.
+    pub fn process_file_drop(&self, file: usize, position: LogicalPoint) {
+        if let Some(event) = self.dragged_file_event(file) {
+            self.process_drop(&event, position);
+        }
+        // Several files may be dropped one after the other: keep the list until the next drag
+        self.dragged_files_dropped.set(true);
+    }
+
+    /// The files dragged from the desktop left the window.
+    pub fn cancel_file_drag(&self) {
+        self.cancel_drag();
+        self.dragged_files.borrow_mut().clear();
+        self.dragged_files_dropped.set(false);
+    }
+
+    /// Returns the component and the position of the active popup, if it is rendered within this window.
+    /// A popup whose parent item was destroyed is ignored, until [`Self::close_orphan_popup`] closes it.
+    fn embedded_popup(&self) -> Option<(ComponentRc, LogicalPoint)> {
+        self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
+            _ if popup.parent_item.upgrade().is_none() => None,
+            PopupWindowLocation::TopLevel(_) => None,
+            PopupWindowLocation::ChildWindow(coordinates) => {
+                Some((popup.component.clone(), coordinates))
+            }
+        })
+    }
+
+    /// Returns true if the item for which the active popup was shown was destroyed.
+    fn has_orphan_popup(&self) -> bool {
+        self.active_popup
+            .borrow()
+            .as_ref()
+            .map_or(false, |popup| popup.parent_item.upgrade().is_none())
+    }
+
+    /// Closes the active popup if the item it was shown for was destroyed.
+    fn close_orphan_popup(&self) {
+        if self.has_orphan_popup() {
+            self.close_popup();
         }
     }
 
@@ -416,6 +598,10 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
             item = focus_item.parent_item();
         }
 
+        if self.activate_shortcut(&event) {
+            return;
+        }
+
         // Make Tab/Backtab handle keyboard focus
         if event.text.starts_with(key_codes::Tab)
             && !event.modifiers.shift
@@ -430,6 +616,47 @@ pub fn process_key_input(&self, event: KeyInputEvent) {
         }
     }
 
+    /// Invokes the `activated` callback of the first visible `Shortcut` element that matches the
+    /// key event, looking in the embedded popup first. Returns true if one was found.
+    fn activate_shortcut(&self, event: &KeyEvent) -> bool {
+        if event.event_type != KeyEventType::KeyPressed {
+            return false;
+        }
+        let popup_component = self.embedded_popup().map(|(popup_component, _)| popup_component);
+        let mut shortcut = None;
+        for component in popup_component.into_iter().chain(self.try_component()) {
+            crate::item_tree::visit_items(
+                &component,
+                TraversalOrder::BackToFront,
+                |component, item, index, _| {
+                    if let Some(s) = ItemRef::downcast_pin::<Shortcut>(item) {
+                        if s.matches(event) {
+                            let item_rc = ItemRc::new(component.clone(), index);
+                            if item_rc.is_visible() {
+                                shortcut = Some(item_rc);
+                                return ItemVisitorResult::Abort;
+                            }
+                        }
+                    }
+                    ItemVisitorResult::Continue(())
+                },
+                (),
+            );
+            if shortcut.is_some() {
+                break;
+            }
+        }
+        match shortcut {
+            Some(item_rc) => {
+                if let Some(s) = ItemRef::downcast_pin::<Shortcut>(item_rc.borrow()) {
+                    s.activate();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Installs a binding on the specified property that's toggled whenever the text cursor is supposed to be visible or not.
     pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
         let existing_blinker = self.cursor_blinker.borrow().clone();
@@ -570,31 +797,58 @@ pub fn update_window_properties(&self) {
         // No `if !dirty { return; }` check here because the backend window may be newly mapped and not up-to-date, so force
         // an evaluation.
         self.window_properties_tracker.as_ref().evaluate_as_dependency_root(|| {
-            let component = self.component();
-            let component = ComponentRc::borrow_pin(&component);
-            self.window_adapter().apply_geometry_constraint(
-                component.as_ref().layout_info(crate::layout::Orientation::Horizontal),
-                component.as_ref().layout_info(crate::layout::Orientation::Vertical),
-            );
+            let (constraints_horizontal, constraints_vertical) = self.geometry_constraints();
+            self.window_adapter()
+                .apply_geometry_constraint(constraints_horizontal, constraints_vertical);
             if let Some(window_item) = self.window_item() {
                 self.window_adapter().apply_window_properties(window_item.as_pin_ref());
             }
         });
     }
 
+    /// Returns the horizontal and vertical constraints of the window: the layout constraints of the
+    /// component, or the current size of the window if the `Window` element is not resizable.
+    pub fn geometry_constraints(&self) -> (crate::layout::LayoutInfo, crate::layout::LayoutInfo) {
+        let component = self.component();
+        let component = ComponentRc::borrow_pin(&component);
+        let mut constraints_horizontal =
+            component.as_ref().layout_info(crate::layout::Orientation::Horizontal);
+        let mut constraints_vertical =
+            component.as_ref().layout_info(crate::layout::Orientation::Vertical);
+        if let Some(window_item) = self.window_item() {
+            let window_item = window_item.as_pin_ref();
+            let size = LogicalSize::from_lengths(window_item.width(), window_item.height());
+            if !window_item.resizable() && !size.is_empty() {
+                let fix_to = |info: &mut crate::layout::LayoutInfo, size: Coord| {
+                    let size = size.max(info.min.min(info.max)).min(info.max.max(info.min));
+                    info.min = size;
+                    info.max = size;
+                    info.preferred = size;
+                };
+                fix_to(&mut constraints_horizontal, size.width);
+                fix_to(&mut constraints_vertical, size.height);
+            }
+        }
+        (constraints_horizontal, constraints_vertical)
+    }
+
     /// Calls the render_components to render the main component and any sub-window components, tracked by a
     /// property dependency tracker.
     pub fn draw_contents(&self, render_components: impl FnOnce(&[(&ComponentRc, LogicalPoint)])) {
         let draw_fn = || {
             let component_rc = self.component();
 
-            let popup_component =
-                self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                    PopupWindowLocation::TopLevel(_) => None,
-                    PopupWindowLocation::ChildWindow(coordinates) => {
-                        Some((popup.component.clone(), coordinates))
+            let popup_component = self.embedded_popup();
+
+            if self.has_orphan_popup() {
+                // Closing the popup marks the area it covered as dirty, so do it after drawing
+                let window_adapter_weak = self.window_adapter_weak.clone();
+                crate::timers::Timer::single_shot(Default::default(), move || {
+                    if let Some(window_adapter) = window_adapter_weak.upgrade() {
+                        WindowInner::from_pub(window_adapter.window()).close_orphan_popup();
                     }
                 });
+            }
 
             if let Some((popup_component, popup_coordinates)) = popup_component {
                 render_components(&[
@@ -621,12 +875,17 @@ pub fn hide(&self) {
         self.window_adapter().hide();
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Show a popup at the given position relative to the item.
+    /// When `close_on_click` is true, the popup is closed when the user clicks on it or outside of it.
+    /// `popup_index` is the index of the popup in the component of `parent_item`, which identifies
+    /// the popup for [`Self::close_popup_if_active`].
     pub fn show_popup(
         &self,
         popup_componentrc: &ComponentRc,
         position: Point,
+        close_on_click: bool,
         parent_item: &ItemRc,
+        popup_index: usize,
     ) {
         let position = parent_item.map_to_window(
             parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
@@ -679,8 +938,51 @@ pub fn show_popup(
             }
         };
 
-        self.active_popup
-            .replace(Some(PopupWindow { location, component: popup_componentrc.clone() }));
+        self.active_popup.replace(Some(PopupWindow {
+            location,
+            component: popup_componentrc.clone(),
+            parent_item: parent_item.downgrade(),
+            popup_index,
+            close_on_click,
+            close_requested: None,
+        }));
+    }
+
+    /// Sets the callback that is invoked when the user clicks outside of the active popup.
+    pub fn on_popup_close_requested(&self, callback: impl Fn() + 'static) {
+        if let Some(popup) = self.active_popup.borrow_mut().as_mut() {
+            popup.close_requested = Some(Rc::new(callback));
+        }
+    }
+
+    /// Called when the user clicks outside of the active popup: invokes its `close-requested`
+    /// callback, and closes it if it closes on click.
+    pub fn request_popup_close(&self) {
+        let close_requested =
+            self.active_popup.borrow().as_ref().and_then(|popup| popup.close_requested.clone());
+        if let Some(close_requested) = close_requested {
+            close_requested();
+        }
+        if self.active_popup_closes_on_click() {
+            self.close_popup();
+        }
+    }
+
+    /// Returns true if the active popup is closed when the user clicks on it.
+    pub fn active_popup_closes_on_click(&self) -> bool {
+        self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
+    }
+
+    /// Closes the popup that was shown for `parent_item` with `popup_index`, if it is the active
+    /// popup. Another popup that is active is left open.
+    pub fn close_popup_if_active(&self, parent_item: &ItemRc, popup_index: usize) {
+        let is_active = self.active_popup.borrow().as_ref().map_or(false, |popup| {
+            popup.popup_index == popup_index
+                && popup.parent_item.upgrade().map_or(false, |item| item == *parent_item)
+        });
+        if is_active {
+            self.close_popup();
+        }
     }
 
     /// Removes any active popup.
@@ -887,15 +1189,57 @@ pub enum GraphicsAPI {
         handle: *const WindowAdapterRcOpaque,
         popup: &ComponentRc,
         position: crate::graphics::Point,
+        close_on_click: bool,
         parent_item: &ItemRc,
+        popup_index: usize,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).show_popup(popup, position, parent_item);
+        WindowInner::from_pub(window_adapter.window()).show_popup(
+            popup,
+            position,
+            close_on_click,
+            parent_item,
+            popup_index,
+        );
     }
-    /// Close the current popup
-    pub unsafe extern "C" fn slint_windowrc_close_popup(handle: *const WindowAdapterRcOpaque) {
+
+    /// C binding to the on_popup_close_requested() of WindowInner
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_on_popup_close_requested(
+        handle: *const WindowAdapterRcOpaque,
+        callback: extern "C" fn(user_data: *mut c_void),
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        user_data: *mut c_void,
+    ) {
+        struct WithUserData {
+            callback: extern "C" fn(user_data: *mut c_void),
+            drop_user_data: extern "C" fn(*mut c_void),
+            user_data: *mut c_void,
+        }
+
+        impl Drop for WithUserData {
+            fn drop(&mut self) {
+                (self.drop_user_data)(self.user_data)
+            }
+        }
+
+        let with_user_data = WithUserData { callback, drop_user_data, user_data };
+
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).close_popup();
+        WindowInner::from_pub(window_adapter.window())
+            .on_popup_close_requested(move || (with_user_data.callback)(with_user_data.user_data));
+    }
+
+    /// C binding to the close_popup_if_active() of WindowInner
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_close_popup(
+        handle: *const WindowAdapterRcOpaque,
+        parent_item: &ItemRc,
+        popup_index: usize,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window())
+            .close_popup_if_active(parent_item, popup_index);
     }
 
     /// C binding to the set_rendering_notifier() API of Window
@@ -1055,6 +1399,101 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().set_size(crate::api::LogicalSize::new(size.width, size.height));
     }
 
+    /// Returns whether the window covers the whole screen
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_fullscreen(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_fullscreen()
+    }
+
+    /// Makes the window cover the whole screen, or restores it
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_fullscreen(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_fullscreen(value)
+    }
+
+    /// Returns whether the window is maximized
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_maximized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_maximized()
+    }
+
+    /// Maximizes the window, or restores it
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_maximized(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_maximized(value)
+    }
+
+    /// Returns whether the window is minimized
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_minimized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_minimized()
+    }
+
+    /// Minimizes the window, or restores it
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_minimized(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_minimized(value)
+    }
+
+    /// Returns whether the user can resize the window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_resizable(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_resizable()
+    }
+
+    /// Sets whether the user can resize the window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_resizable(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_resizable(value)
+    }
+
+    /// Returns whether the window stays on top of other windows
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_always_on_top(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_always_on_top()
+    }
+
+    /// Sets whether the window stays on top of other windows
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_always_on_top(
+        handle: *const WindowAdapterRcOpaque,
+        value: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_always_on_top(value)
+    }
+
     /// Return wether the style is using a dark theme
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dark_color_scheme(
@@ -1074,6 +1513,16 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().0.process_key_input(event);
     }
 
+    /// Dispatch a touch event
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_dispatch_touch_event(
+        handle: *const WindowAdapterRcOpaque,
+        event: crate::input::TouchEvent,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().0.process_touch_input(event);
+    }
+
     /// Dispatch a mouse event
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dispatch_pointer_event(
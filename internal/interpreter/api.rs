@@ -258,6 +258,7 @@ fn try_from(v: Value) -> Result<$name, Self::Error> {
 declare_value_struct_conversion!(struct i_slint_core::layout::LayoutInfo { min, max, min_percent, max_percent, preferred, stretch });
 declare_value_struct_conversion!(struct i_slint_core::graphics::Point { x, y, ..Default::default()});
 declare_value_struct_conversion!(struct i_slint_core::items::PointerEvent { kind, button });
+declare_value_struct_conversion!(struct i_slint_core::items::DropEvent { mime_type, data });
 
 /// Implement From / TryFrom for Value that convert an `enum` to/from `Value::EnumerationValue`
 ///
@@ -479,6 +480,11 @@ pub fn style(&self) -> Option<&String> {
         self.config.style.as_ref()
     }
 
+    /// Sets the domain used for translations of the `@tr(...)` strings.
+    pub fn set_translation_domain(&mut self, domain: String) {
+        self.config.translation_domain = Some(domain);
+    }
+
     /// Sets the callback that will be invoked when loading imported .slint files. The specified
     /// `file_loader_callback` parameter will be called with a canonical file path as argument
     /// and is expected to return a future that, when resolved, provides the source code of the
@@ -1160,6 +1166,22 @@ pub fn send_mouse_click(comp: &super::ComponentInstance, x: f32, y: f32) {
             &WindowInner::from_pub(comp.window()).window_adapter(),
         );
     }
+    /// Wrapper around [`i_slint_core::tests::slint_send_mouse_drag`]
+    pub fn send_mouse_drag(
+        comp: &super::ComponentInstance,
+        from_x: f32,
+        from_y: f32,
+        to_x: f32,
+        to_y: f32,
+    ) {
+        i_slint_core::tests::slint_send_mouse_drag(
+            from_x,
+            from_y,
+            to_x,
+            to_y,
+            &WindowInner::from_pub(comp.window()).window_adapter(),
+        );
+    }
     /// Wrapper around [`i_slint_core::tests::slint_send_keyboard_char`]
     pub fn send_keyboard_char(
         comp: &super::ComponentInstance,
@@ -13,7 +13,9 @@
 use i_slint_compiler::object_tree::ElementRc;
 use i_slint_compiler::*;
 use i_slint_compiler::{diagnostics::BuildDiagnostics, object_tree::PropertyDeclaration};
-use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::accessibility::{
+    AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
+};
 use i_slint_core::component::{
     Component, ComponentRef, ComponentRefPin, ComponentVTable, ComponentWeak, IndexRange,
 };
@@ -22,7 +24,7 @@
     VisitChildrenResult,
 };
 use i_slint_core::items::{AccessibleRole, Flickable, ItemRef, ItemVTable, PropertyAnimation};
-use i_slint_core::layout::{BoxLayoutCellData, LayoutInfo, Orientation};
+use i_slint_core::layout::{BoxLayoutCellData, GridLayoutCellData, LayoutInfo, Orientation};
 use i_slint_core::lengths::LogicalLength;
 use i_slint_core::model::RepeatedComponent;
 use i_slint_core::model::Repeater;
@@ -150,6 +152,32 @@ fn listview_layout(
     fn box_layout_data(self: Pin<&Self>, o: Orientation) -> BoxLayoutCellData {
         BoxLayoutCellData { constraint: self.borrow().as_ref().layout_info(o) }
     }
+
+    fn grid_layout_data(self: Pin<&Self>, o: Orientation) -> GridLayoutCellData {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let instance = s.borrow_instance();
+        let constraint = self.borrow().as_ref().layout_info(o);
+        let cell = instance.component_type.original.grid_layout_cell.borrow().clone();
+        match cell {
+            Some(cell) => {
+                let load = |nr: &NamedReference| -> i32 {
+                    eval::load_property(instance, &nr.element(), nr.name())
+                        .unwrap()
+                        .try_into()
+                        .unwrap()
+                };
+                let (col_or_row, span) =
+                    cell.col_or_row_and_span(crate::eval_layout::from_runtime(o));
+                GridLayoutCellData {
+                    col_or_row: load(col_or_row).clamp(0, u16::MAX as i32) as u16,
+                    span: load(span).clamp(1, u16::MAX as i32) as u16,
+                    constraint,
+                }
+            }
+            None => GridLayoutCellData { constraint, ..Default::default() },
+        }
+    }
 }
 
 impl Component for ErasedComponentBox {
@@ -210,6 +238,17 @@ fn accessible_string_property(
     ) {
         self.borrow().as_ref().accessible_string_property(index, what, result)
     }
+
+    fn accessibility_action(self: Pin<&Self>, index: usize, action: &AccessibilityAction) {
+        self.borrow().as_ref().accessibility_action(index, action)
+    }
+
+    fn supported_accessibility_actions(
+        self: Pin<&Self>,
+        index: usize,
+    ) -> SupportedAccessibilityAction {
+        self.borrow().as_ref().supported_accessibility_actions(index)
+    }
 }
 
 i_slint_core::ComponentVTable_static!(static COMPONENT_BOX_VT for ErasedComponentBox);
@@ -239,6 +278,8 @@ pub(crate) struct ComponentExtraData {
         once_cell::unsync::OnceCell<vtable::VWeak<ComponentVTable, ErasedComponentBox>>,
     // resource id -> file path
     pub(crate) embedded_file_resources: HashMap<usize, String>,
+    /// One tracker for each of the ComponentDescription::change_callbacks
+    pub(crate) change_trackers: Vec<i_slint_core::properties::ChangeTracker>,
 }
 
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinComponent<'id, 'static>);
@@ -340,6 +381,8 @@ pub struct ComponentDescription<'id> {
     pub(crate) original_elements: Vec<ElementRc>,
     /// Copy of original.root_element.property_declarations, without a guarded refcell
     public_properties: BTreeMap<String, PropertyDeclaration>,
+    /// The `changed` callbacks of the elements of this component, and the property they watch
+    change_callbacks: Vec<(NamedReference, Expression)>,
 
     /// compiled globals
     compiled_globals: Vec<crate::global_component::CompiledGlobal>,
@@ -782,8 +825,13 @@ pub(crate) fn generate_component<'id>(
                 rtti_for::<BorderRectangle>(),
                 rtti_for::<TouchArea>(),
                 rtti_for::<FocusScope>(),
+                rtti_for::<Shortcut>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
+                rtti_for::<PinchGestureHandler>(),
+                rtti_for::<SwipeGestureHandler>(),
                 rtti_for::<WindowItem>(),
                 rtti_for::<TextInput>(),
                 rtti_for::<Clip>(),
@@ -1045,6 +1093,15 @@ macro_rules! match_enum_type {
 
     let public_properties = component.root_element.borrow().property_declarations.clone();
 
+    let mut change_callbacks = vec![];
+    object_tree::recurse_elem(&component.root_element, &(), &mut |elem: &ElementRc, _| {
+        for (name, handlers) in &elem.borrow().change_callbacks {
+            for expr in handlers {
+                change_callbacks.push((NamedReference::new(elem, name), expr.clone()));
+            }
+        }
+    });
+
     let mut exported_globals_by_name: BTreeMap<String, usize> = Default::default();
 
     let compiled_globals = component
@@ -1085,6 +1142,8 @@ macro_rules! match_enum_type {
         subtree_index,
         accessible_role,
         accessible_string_property,
+        accessibility_action,
+        supported_accessibility_actions,
         drop_in_place,
         dealloc,
     };
@@ -1104,6 +1163,7 @@ macro_rules! match_enum_type {
         window_adapter_offset,
         extra_data_offset,
         public_properties,
+        change_callbacks,
         compiled_globals,
         exported_globals_by_name,
     };
@@ -1228,6 +1288,8 @@ pub fn instantiate(
     }
     *component_type.window_adapter_offset.apply_mut(instance.as_mut()) =
         Some(window_adapter.clone());
+    component_type.extra_data_offset.apply_mut(instance.as_mut()).change_trackers =
+        component_type.change_callbacks.iter().map(|_| Default::default()).collect();
 
     let component_box = ComponentBox { instance, component_type: component_type.clone() };
     let instance_ref = component_box.borrow_instance();
@@ -1459,6 +1521,34 @@ pub fn run_setup_code(&self) {
         generativity::make_guard!(guard);
         let compo_box = self.unerase(guard);
         let instance_ref = compo_box.borrow_instance();
+        let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+        for ((nr, expr), tracker) in
+            instance_ref.component_type.change_callbacks.iter().zip(&extra_data.change_trackers)
+        {
+            let nr = nr.clone();
+            let expr = expr.clone();
+            tracker.init(
+                instance_ref.self_weak().get().unwrap().clone(),
+                move |self_weak| {
+                    let self_rc = self_weak.upgrade().unwrap();
+                    generativity::make_guard!(guard);
+                    let self_ = self_rc.unerase(guard);
+                    eval::load_property(self_.borrow_instance(), &nr.element(), nr.name()).unwrap()
+                },
+                move |self_weak, _| {
+                    if let Some(self_rc) = self_weak.upgrade() {
+                        generativity::make_guard!(guard);
+                        let self_ = self_rc.unerase(guard);
+                        eval::eval_expression(
+                            &expr,
+                            &mut eval::EvalLocalContext::from_component_instance(
+                                self_.borrow_instance(),
+                            ),
+                        );
+                    }
+                },
+            );
+        }
         for extra_init_code in self.0.component_type.original.init_code.borrow().iter() {
             eval::eval_expression(
                 extra_init_code,
@@ -1643,6 +1733,55 @@ extern "C" fn accessible_string_property(
     }
 }
 
+extern "C" fn accessibility_action(
+    component: ComponentRefPin,
+    item_index: usize,
+    action: &AccessibilityAction,
+) {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let (prop_name, args) = match action {
+        AccessibilityAction::Default => ("accessible-action-default", vec![]),
+        AccessibilityAction::Decrement => ("accessible-action-decrement", vec![]),
+        AccessibilityAction::Increment => ("accessible-action-increment", vec![]),
+        AccessibilityAction::SetValue(value) => {
+            ("accessible-action-set-value", vec![Value::String(value.clone())])
+        }
+    };
+    let nr = instance_ref.component_type.original_elements[item_index]
+        .borrow()
+        .accessibility_props
+        .0
+        .get(prop_name)
+        .cloned();
+    if let Some(nr) = nr {
+        crate::eval::invoke_callback(
+            eval::ComponentInstance::InstanceRef(instance_ref),
+            &nr.element(),
+            nr.name(),
+            &args,
+        );
+    }
+}
+
+extern "C" fn supported_accessibility_actions(
+    component: ComponentRefPin,
+    item_index: usize,
+) -> SupportedAccessibilityAction {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let element = instance_ref.component_type.original_elements[item_index].borrow();
+    [
+        ("accessible-action-default", SupportedAccessibilityAction::DEFAULT),
+        ("accessible-action-decrement", SupportedAccessibilityAction::DECREMENT),
+        ("accessible-action-increment", SupportedAccessibilityAction::INCREMENT),
+        ("accessible-action-set-value", SupportedAccessibilityAction::SET_VALUE),
+    ]
+    .into_iter()
+    .filter(|(prop_name, _)| element.accessibility_props.0.contains_key(*prop_name))
+    .fold(SupportedAccessibilityAction::default(), |acc, (_, flag)| acc | flag)
+}
+
 unsafe extern "C" fn drop_in_place(component: vtable::VRefMut<ComponentVTable>) -> vtable::Layout {
     let instance_ptr = component.as_ptr() as *mut Instance<'static>;
     let layout = (*instance_ptr).type_info().layout();
@@ -1734,9 +1873,11 @@ pub fn toplevel_instance(&self) -> InstanceRef<'a, 'id> {
 pub fn show_popup(
     popup: &object_tree::PopupWindow,
     pos: i_slint_core::graphics::Point,
+    close_on_click: bool,
     parent_comp: ComponentRefPin,
     parent_window_adapter: &Rc<dyn WindowAdapter>,
     parent_item: &ItemRc,
+    popup_index: usize,
 ) {
     generativity::make_guard!(guard);
     // FIXME: we should compile once and keep the cached compiled component
@@ -1746,6 +1887,8 @@ pub fn show_popup(
     WindowInner::from_pub(parent_window_adapter.window()).show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
+        close_on_click,
         parent_item,
+        popup_index,
     );
 }
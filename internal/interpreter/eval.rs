@@ -528,19 +528,9 @@ fn call_builtin_function(
                 }
             };
             if let Expression::ElementReference(popup_window) = &arguments[0] {
-                let popup_window = popup_window.upgrade().unwrap();
-                let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-                let parent_component = pop_comp
-                    .parent_element
-                    .upgrade()
-                    .unwrap()
-                    .borrow()
-                    .enclosing_component
-                    .upgrade()
-                    .unwrap();
+                let (parent_component, popup_index) = find_popup(&popup_window.upgrade().unwrap());
                 let popup_list = parent_component.popup_windows.borrow();
-                let popup =
-                    popup_list.iter().find(|p| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+                let popup = &popup_list[popup_index];
                 let x = load_property_helper(
                     local_context.component_instance,
                     &popup.x.element(),
@@ -553,18 +543,14 @@ fn call_builtin_function(
                     popup.y.name(),
                 )
                 .unwrap();
+                let close_on_click = load_property_helper(
+                    local_context.component_instance,
+                    &popup.close_on_click.element(),
+                    popup.close_on_click.name(),
+                )
+                .unwrap();
 
-                generativity::make_guard!(guard);
-                let enclosing_component =
-                    enclosing_component_for_element(&popup.parent_element, component, guard);
-                let parent_item_info = &enclosing_component.component_type.items
-                    [popup.parent_element.borrow().id.as_str()];
-                let parent_item_comp =
-                    enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
-                let parent_item = corelib::items::ItemRc::new(
-                    vtable::VRc::into_dyn(parent_item_comp),
-                    parent_item_info.item_index(),
-                );
+                let parent_item = item_rc_for_element(&popup.parent_element, component);
 
                 crate::dynamic_component::show_popup(
                     popup,
@@ -572,15 +558,138 @@ fn call_builtin_function(
                         x.try_into().unwrap(),
                         y.try_into().unwrap(),
                     ),
+                    close_on_click.try_into().unwrap(),
                     component.borrow(),
                     window_adapter_ref(component).unwrap(),
                     &parent_item,
+                    popup_index,
                 );
+
+                let close_requested = popup.close_requested.clone();
+                let self_weak = component.self_weak().get().unwrap().clone();
+                WindowInner::from_pub(window_adapter_ref(component).unwrap().window())
+                    .on_popup_close_requested(move || {
+                        if let Some(self_rc) = self_weak.upgrade() {
+                            generativity::make_guard!(guard);
+                            let self_ = self_rc.unerase(guard);
+                            invoke_callback(
+                                ComponentInstance::InstanceRef(self_.borrow_instance()),
+                                &close_requested.element(),
+                                close_requested.name(),
+                                &[],
+                            );
+                        }
+                    });
                 Value::Void
             } else {
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
+        BuiltinFunction::ClosePopupWindow => {
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot close popup from a global component")
+                }
+            };
+            if let [Expression::ElementReference(popup_window)] = arguments {
+                let (parent_component, popup_index) = find_popup(&popup_window.upgrade().unwrap());
+                let parent_element =
+                    parent_component.popup_windows.borrow()[popup_index].parent_element.clone();
+                let parent_item = item_rc_for_element(&parent_element, component);
+                WindowInner::from_pub(window_adapter_ref(component).unwrap().window())
+                    .close_popup_if_active(&parent_item, popup_index);
+                Value::Void
+            } else {
+                panic!("internal error: argument to ClosePopupWindow must be an element")
+            }
+        }
+        BuiltinFunction::FlickableScrollTo => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to FlickableScrollTo")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot scroll a Flickable from a global component")
+                }
+            };
+            if let Expression::ElementReference(flickable) = &arguments[0] {
+                let x: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+                let y: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+                let item_rc = item_rc_for_element(&flickable.upgrade().unwrap(), component);
+                if let Some(flick) =
+                    ItemRef::downcast_pin::<corelib::items::Flickable>(item_rc.borrow())
+                {
+                    flick.scroll_to(
+                        corelib::lengths::LogicalLength::new(x),
+                        corelib::lengths::LogicalLength::new(y),
+                        &item_rc,
+                    );
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to FlickableScrollTo must be an element")
+            }
+        }
+        BuiltinFunction::FlickableEnsureVisible => {
+            if arguments.len() != 5 {
+                panic!("internal error: incorrect argument count to FlickableEnsureVisible")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot scroll a Flickable from a global component")
+                }
+            };
+            if let Expression::ElementReference(flickable) = &arguments[0] {
+                let mut rect = [0f32; 4];
+                for (r, a) in rect.iter_mut().zip(&arguments[1..]) {
+                    *r = eval_expression(a, local_context).try_into().unwrap();
+                }
+                let [x, y, width, height] = rect;
+                let item_rc = item_rc_for_element(&flickable.upgrade().unwrap(), component);
+                if let Some(flick) =
+                    ItemRef::downcast_pin::<corelib::items::Flickable>(item_rc.borrow())
+                {
+                    flick.ensure_visible(
+                        corelib::lengths::LogicalRect::new(
+                            corelib::lengths::LogicalPoint::new(x, y),
+                            corelib::lengths::LogicalSize::new(width, height),
+                        ),
+                        &item_rc,
+                    );
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to FlickableEnsureVisible must be an element")
+            }
+        }
+        BuiltinFunction::FlickableEnsureItemVisible => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to FlickableEnsureItemVisible")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot scroll a Flickable from a global component")
+                }
+            };
+            if let Expression::ElementReference(flickable) = &arguments[0] {
+                let index: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+                let item_rc = item_rc_for_element(&flickable.upgrade().unwrap(), component);
+                if let Some(flick) =
+                    ItemRef::downcast_pin::<corelib::items::Flickable>(item_rc.borrow())
+                {
+                    if index >= 0 {
+                        flick.ensure_item_visible(index as usize, &item_rc);
+                    }
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to FlickableEnsureItemVisible must be an element")
+            }
+        }
         BuiltinFunction::StringIsFloat => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to StringIsFloat")
@@ -731,6 +840,42 @@ fn call_builtin_function(
         BuiltinFunction::RegisterCustomFontByMemory | BuiltinFunction::RegisterBitmapFont => {
             unimplemented!()
         }
+        BuiltinFunction::Translate => {
+            let original: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let context: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let domain: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let args = match eval_expression(&arguments[3], local_context) {
+                Value::Model(args) => args,
+                args => panic!("Args to translate not a model {args:?}"),
+            };
+            let args = args
+                .iter()
+                .map(|v| SharedString::try_from(v).unwrap_or_default())
+                .collect::<Vec<_>>();
+            let n: i32 = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let plural: SharedString =
+                eval_expression(&arguments[5], local_context).try_into().unwrap();
+            Value::String(corelib::translations::translate(
+                &original, &context, &domain, &args, n, &plural,
+            ))
+        }
+        BuiltinFunction::FormatNumber => {
+            if arguments.len() != 6 {
+                panic!("internal error: incorrect argument count to FormatNumber")
+            }
+            let value: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let width: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let precision: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let zero_pad: bool = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let grouping: bool = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let localized: bool = eval_expression(&arguments[5], local_context).try_into().unwrap();
+            Value::String(corelib::translations::format_number(
+                value, width, precision, zero_pad, grouping, localized,
+            ))
+        }
     }
 }
 
@@ -1118,6 +1263,30 @@ pub fn window_ref<'a>(
         .map(|window_adapter| WindowInner::from_pub(window_adapter.window()))
 }
 
+/// Returns the component that contains the popup whose root element is `popup_window`, and the
+/// index of the popup in the popup windows of that component
+fn find_popup(popup_window: &ElementRc) -> (Rc<i_slint_compiler::object_tree::Component>, usize) {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_component =
+        pop_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+    let popup_index = parent_component
+        .popup_windows
+        .borrow()
+        .iter()
+        .position(|p| Rc::ptr_eq(&p.component, &pop_comp))
+        .unwrap();
+    (parent_component, popup_index)
+}
+
+/// Returns the ItemRc of the item created for the given element
+fn item_rc_for_element(element: &ElementRc, component: InstanceRef) -> corelib::items::ItemRc {
+    generativity::make_guard!(guard);
+    let enclosing_component = enclosing_component_for_element(element, component, guard);
+    let item_info = &enclosing_component.component_type.items[element.borrow().id.as_str()];
+    let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+    corelib::items::ItemRc::new(vtable::VRc::into_dyn(item_comp), item_info.item_index())
+}
+
 /// Return the component instance which hold the given element.
 /// Does not take in account the global component.
 pub fn enclosing_component_for_element<'a, 'old_id, 'new_id>(
@@ -1224,12 +1393,32 @@ fn convert_from_lyon_path<'a>(
 
 pub fn convert_path(path: &ExprPath, local_context: &mut EvalLocalContext) -> PathData {
     match path {
-        ExprPath::Elements(elements) => PathData::Elements(
-            elements
-                .iter()
-                .map(|element| convert_path_element(element, local_context))
-                .collect::<SharedVector<PathElement>>(),
-        ),
+        ExprPath::Elements(elements) => {
+            let mut result = SharedVector::<PathElement>::default();
+            for element in elements {
+                match &element.repeated {
+                    None => result.push(convert_path_element(element, local_context)),
+                    Some(repeated) => {
+                        let model = crate::value_model::ValueModel::new(eval_expression(
+                            &repeated.model,
+                            local_context,
+                        ));
+                        model.model_tracker().track_row_count_changes();
+                        for row in 0..model.row_count() {
+                            local_context.local_variables.insert(
+                                repeated.model_data_variable.clone(),
+                                model.row_data_tracked(row).unwrap_or_default(),
+                            );
+                            local_context
+                                .local_variables
+                                .insert(repeated.index_variable.clone(), Value::Number(row as f64));
+                            result.push(convert_path_element(element, local_context));
+                        }
+                    }
+                }
+            }
+            PathData::Elements(result)
+        }
         ExprPath::Events(events, points) => {
             convert_from_lyon_path(events.iter(), points.iter(), local_context)
         }
@@ -46,7 +46,7 @@ pub(crate) fn compute_layout_info(
     };
     match lay {
         Layout::GridLayout(grid_layout) => {
-            let cells = grid_layout_data(grid_layout, orientation, component, &expr_eval);
+            let cells = grid_layout_data(grid_layout, orientation, component, &expr_eval, None);
             let (padding, spacing) =
                 padding_and_spacing(&grid_layout.geometry, orientation, &expr_eval);
             core_layout::grid_layout_info(Slice::from(cells.as_slice()), spacing, &padding).into()
@@ -86,7 +86,14 @@ pub(crate) fn solve_layout(
 
     match lay {
         Layout::GridLayout(grid_layout) => {
-            let mut cells = grid_layout_data(grid_layout, orientation, component, &expr_eval);
+            let mut repeated_indices = Vec::new();
+            let mut cells = grid_layout_data(
+                grid_layout,
+                orientation,
+                component,
+                &expr_eval,
+                Some(&mut repeated_indices),
+            );
             if let (Some(buttons_roles), Orientation::Horizontal) =
                 (&grid_layout.dialog_button_roles, orientation)
             {
@@ -101,12 +108,15 @@ pub(crate) fn solve_layout(
                 padding_and_spacing(&grid_layout.geometry, orientation, &expr_eval);
 
             let size_ref = grid_layout.geometry.rect.size_reference(orientation);
-            core_layout::solve_grid_layout(&core_layout::GridLayoutData {
-                size: size_ref.map(expr_eval).unwrap_or(0.),
-                spacing,
-                padding,
-                cells: Slice::from(cells.as_slice()),
-            })
+            core_layout::solve_grid_layout(
+                &core_layout::GridLayoutData {
+                    size: size_ref.map(expr_eval).unwrap_or(0.),
+                    spacing,
+                    padding,
+                    cells: Slice::from(cells.as_slice()),
+                },
+                Slice::from(repeated_indices.as_slice()),
+            )
             .into()
         }
         Layout::BoxLayout(box_layout) => {
@@ -151,23 +161,31 @@ fn padding_and_spacing(
     (padding, spacing)
 }
 
-/// return the celldata, the padding, and the spacing of a grid layout
+/// return the celldata of a grid layout
 fn grid_layout_data(
     grid_layout: &i_slint_compiler::layout::GridLayout,
     orientation: Orientation,
     component: InstanceRef,
     expr_eval: &impl Fn(&NamedReference) -> f32,
+    mut repeater_indices: Option<&mut Vec<u32>>,
 ) -> Vec<core_layout::GridLayoutCellData> {
-    let cells = grid_layout
-        .elems
-        .iter()
-        .map(|cell| {
-            let mut layout_info = get_layout_info(
-                &cell.item.element,
-                component,
-                eval::window_adapter_ref(component).unwrap(),
-                orientation,
+    let window_adapter = eval::window_adapter_ref(component).unwrap();
+    let mut cells = Vec::with_capacity(grid_layout.elems.len());
+    for cell in &grid_layout.elems {
+        if cell.item.element.borrow().repeated.is_some() {
+            let component_vec = ensure_repeater_updated(&cell.item.element, component);
+            if let Some(ri) = repeater_indices.as_mut() {
+                ri.push(cells.len() as _);
+                ri.push(component_vec.len() as _);
+            }
+            cells.extend(
+                component_vec
+                    .iter()
+                    .map(|x| x.as_pin_ref().grid_layout_data(to_runtime(orientation))),
             );
+        } else {
+            let mut layout_info =
+                get_layout_info(&cell.item.element, component, window_adapter, orientation);
             fill_layout_info_constraints(
                 &mut layout_info,
                 &cell.item.constraints,
@@ -175,12 +193,40 @@ fn grid_layout_data(
                 &expr_eval,
             );
             let (col_or_row, span) = cell.col_or_row_and_span(orientation);
-            core_layout::GridLayoutCellData { col_or_row, span, constraint: layout_info }
-        })
-        .collect::<Vec<_>>();
+            cells.push(core_layout::GridLayoutCellData {
+                col_or_row,
+                span,
+                constraint: layout_info,
+            });
+        }
+    }
     cells
 }
 
+/// Make sure the instances of the repeater are created, and return them
+fn ensure_repeater_updated(
+    element: &ElementRc,
+    component: InstanceRef,
+) -> Vec<crate::dynamic_component::DynamicComponentVRc> {
+    let window_adapter = eval::window_adapter_ref(component).unwrap();
+    generativity::make_guard!(guard);
+    let rep = crate::dynamic_component::get_repeater_by_name(
+        component,
+        element.borrow().id.as_str(),
+        guard,
+    );
+    rep.0.as_ref().ensure_updated(|| {
+        let instance = crate::dynamic_component::instantiate(
+            rep.1.clone(),
+            Some(component.borrow()),
+            window_adapter,
+            Default::default(),
+        );
+        instance
+    });
+    rep.0.as_ref().components_vec()
+}
+
 fn box_layout_data(
     box_layout: &i_slint_compiler::layout::BoxLayout,
     orientation: Orientation,
@@ -192,22 +238,7 @@ fn box_layout_data(
     let mut cells = Vec::with_capacity(box_layout.elems.len());
     for cell in &box_layout.elems {
         if cell.element.borrow().repeated.is_some() {
-            generativity::make_guard!(guard);
-            let rep = crate::dynamic_component::get_repeater_by_name(
-                component,
-                cell.element.borrow().id.as_str(),
-                guard,
-            );
-            rep.0.as_ref().ensure_updated(|| {
-                let instance = crate::dynamic_component::instantiate(
-                    rep.1.clone(),
-                    Some(component.borrow()),
-                    window_adapter,
-                    Default::default(),
-                );
-                instance
-            });
-            let component_vec = rep.0.as_ref().components_vec();
+            let component_vec = ensure_repeater_updated(&cell.element, component);
             if let Some(ri) = repeater_indices.as_mut() {
                 ri.push(cells.len() as _);
                 ri.push(component_vec.len() as _);
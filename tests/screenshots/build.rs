@@ -87,6 +87,29 @@ fn main() -> std::io::Result<()> {
 
         generate_source(source.as_str(), &mut output, testcase).unwrap();
 
+        // Test cases with a rust block run that code instead of comparing with a reference image
+        if let Some(test_function) =
+            test_driver_lib::extract_test_functions(&source).find(|x| x.language_id == "rust")
+        {
+            write!(
+                output,
+                r"
+    #[test] fn t_{}() -> Result<(), Box<dyn std::error::Error>> {{
+    use crate::testing;
+    use i_slint_backend_testing as slint_testing;
+
+    let window = testing::init_swr();
+    window.set_size(slint::PhysicalSize::new(64, 64));
+
+    {}
+    Ok(())
+    }}",
+                i,
+                test_function.source.replace('\n', "\n    ")
+            )?;
+            continue;
+        }
+
         write!(
             output,
             r"
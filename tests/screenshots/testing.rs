@@ -17,7 +17,7 @@
 };
 
 pub struct SwrTestingBackend {
-    window: Rc<MinimalSoftwareWindow<0>>,
+    window: Rc<MinimalSoftwareWindow<1>>,
 }
 
 impl i_slint_core::platform::Platform for SwrTestingBackend {
@@ -30,7 +30,9 @@ fn duration_since_start(&self) -> core::time::Duration {
     }
 }
 
-pub fn init_swr() -> std::rc::Rc<MinimalSoftwareWindow<0>> {
+/// The window only redraws what changed since the previous frame, so that the test cases can check
+/// the partial rendering. The screenshot functions redraw the whole window.
+pub fn init_swr() -> std::rc::Rc<MinimalSoftwareWindow<1>> {
     let window = MinimalSoftwareWindow::new();
 
     i_slint_core::platform::set_platform(Box::new(SwrTestingBackend { window: window.clone() }))
@@ -50,7 +52,7 @@ pub fn image_buffer(path: &str) -> Result<SharedPixelBuffer<Rgb8Pixel>, image::I
     })
 }
 
-pub fn screenshot(window: std::rc::Rc<MinimalSoftwareWindow<0>>) -> SharedPixelBuffer<Rgb8Pixel> {
+pub fn screenshot(window: std::rc::Rc<MinimalSoftwareWindow<1>>) -> SharedPixelBuffer<Rgb8Pixel> {
     let size = window.size();
     let width = size.width;
     let height = size.height;
@@ -165,14 +167,14 @@ fn compare_images(
     result
 }
 
-pub fn assert_with_render(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<0>>) {
+pub fn assert_with_render(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<1>>) {
     let rendering = screenshot(window);
     if let Err(reason) = compare_images(path, &rendering) {
         panic!("Image comparison failure for {path}: {reason}");
     }
 }
 
-pub fn assert_with_render_by_line(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<0>>) {
+pub fn assert_with_render_by_line(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<1>>) {
     let rendering = screenshot_render_by_line(window);
     if let Err(reason) = compare_images(path, &rendering) {
         panic!("Image comparison failure for line-by-line rendering for {path}: {reason}");
@@ -180,7 +182,7 @@ pub fn assert_with_render_by_line(path: &str, window: std::rc::Rc<MinimalSoftwar
 }
 
 pub fn screenshot_render_by_line(
-    window: std::rc::Rc<MinimalSoftwareWindow<0>>,
+    window: std::rc::Rc<MinimalSoftwareWindow<1>>,
 ) -> SharedPixelBuffer<Rgb8Pixel> {
     let size = window.size();
     let width = size.width;
@@ -201,7 +203,7 @@ pub fn screenshot_render_by_line(
     buffer
 }
 
-pub fn save_screenshot(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<0>>) {
+pub fn save_screenshot(path: &str, window: std::rc::Rc<MinimalSoftwareWindow<1>>) {
     let buffer = screenshot(window.clone());
     image::save_buffer(
         path,
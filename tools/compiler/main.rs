@@ -32,6 +32,10 @@ struct Cli {
     /// Sets the output file ('-' for stdout)
     #[arg(name = "file to generate", short = 'o', default_value = "-", action)]
     output: std::path::PathBuf,
+
+    /// Translation domain
+    #[arg(long = "translation-domain", action)]
+    translation_domain: Option<String>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -49,6 +53,7 @@ fn main() -> std::io::Result<()> {
     if let Some(style) = args.style {
         compiler_config.style = Some(style);
     }
+    compiler_config.translation_domain = args.translation_domain;
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag) = spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
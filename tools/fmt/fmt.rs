@@ -4,7 +4,8 @@
 use crate::writer::TokenWriter;
 use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
 
-pub(crate) fn format_document(
+/// Format the document by passing all its tokens, with their new content, to the writer
+pub fn format_document(
     doc: syntax_nodes::Document,
     writer: &mut impl TokenWriter,
 ) -> Result<(), std::io::Error> {
@@ -465,7 +466,7 @@ fn format_children_placeholder(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::writer::FileWriter;
+    use crate::writer::TextEditWriter;
     use i_slint_compiler::diagnostics::BuildDiagnostics;
     use i_slint_compiler::parser::syntax_nodes;
 
@@ -479,9 +480,35 @@ fn assert_formatting(unformatted: &str, formatted: &str) {
         );
         // Turn the syntax node into a document
         let doc = syntax_nodes::Document::new(syntax_node).unwrap();
-        let mut file = Vec::new();
-        format_document(doc, &mut FileWriter { file: &mut file }).unwrap();
-        assert_eq!(String::from_utf8(file).unwrap(), formatted);
+
+        // Apply the text edits
+        let mut writer = TextEditWriter::default();
+        format_document(doc, &mut writer).unwrap();
+        let mut result = String::from(unformatted);
+        for edit in writer.into_edits().iter().rev() {
+            result.replace_range(edit.range.clone(), &edit.new_text);
+        }
+        assert_eq!(result, formatted);
+    }
+
+    #[test]
+    fn minimal_text_edits() {
+        let edits = |source: &str| {
+            let syntax_node = i_slint_compiler::parser::parse(
+                String::from(source),
+                None,
+                &mut BuildDiagnostics::default(),
+            );
+            let mut writer = TextEditWriter::default();
+            format_document(syntax_nodes::Document::new(syntax_node).unwrap(), &mut writer)
+                .unwrap();
+            writer.into_edits()
+        };
+        assert_eq!(edits("A := Text { }"), vec![]);
+        assert_eq!(
+            edits("A := Text {\n    x:   3px;\n}"),
+            vec![crate::writer::TextEdit { range: 18..21, new_text: " ".into() }]
+        );
     }
 
     #[test]
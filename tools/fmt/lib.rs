@@ -0,0 +1,25 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+    The formatter for .slint files, shared by the `slint-fmt` binary and the LSP.
+
+    **NOTE**: This library is an **internal** crate of the [Slint project](https://slint-ui.com).
+    Its API may change at any time.
+*/
+
+mod fmt;
+mod writer;
+
+use i_slint_compiler::parser::syntax_nodes;
+pub use writer::TextEdit;
+
+/// Format the document, and return the changes to apply to its source.
+///
+/// The ranges of the edits are byte offsets in the source of the document. They are sorted and
+/// don't overlap, so they can be applied in reverse order.
+pub fn format_document(doc: syntax_nodes::Document) -> Vec<TextEdit> {
+    let mut writer = writer::TextEditWriter::default();
+    fmt::format_document(doc, &mut writer).expect("collecting the edits cannot fail");
+    writer.into_edits()
+}
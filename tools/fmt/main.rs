@@ -11,8 +11,7 @@
     Some code in this main.rs file is duplicated with the slint-updater, i guess it could
     be refactored in a separate utility crate or module or something.
 
-    The [`writer::TokenWriter`] trait is meant to be able to support the LSP later as the
-    LSP wants just the edits, not the full file
+    The formatting itself is in the library part of this crate, which is also used by the LSP.
 */
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
@@ -22,9 +21,6 @@
 
 use clap::Parser;
 
-mod fmt;
-mod writer;
-
 #[derive(clap::Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -176,8 +172,11 @@ fn process_file(
 
 fn visit_node(node: SyntaxNode, file: &mut impl Write) -> std::io::Result<()> {
     if let Some(doc) = syntax_nodes::Document::new(node) {
-        let mut writer = writer::FileWriter { file };
-        fmt::format_document(doc, &mut writer)
+        let mut text = doc.text().to_string();
+        for edit in slint_fmt::format_document(doc).iter().rev() {
+            text.replace_range(edit.range.clone(), &edit.new_text);
+        }
+        file.write_all(text.as_bytes())
     } else {
         Err(std::io::Error::new(std::io::ErrorKind::Other, "Not a Document"))
     }
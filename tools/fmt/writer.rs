@@ -1,11 +1,10 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 use i_slint_compiler::parser::SyntaxToken;
-use std::io::Write;
 
 /// The idea is that each token need to go through this, either with no changes,
 /// or with a new content.
-pub(crate) trait TokenWriter {
+pub trait TokenWriter {
     /// Write token to the writer without any change.
     fn no_change(&mut self, token: SyntaxToken) -> std::io::Result<()>;
 
@@ -16,22 +15,62 @@ pub(crate) trait TokenWriter {
     fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()>;
 }
 
-/// Just write the token stream to a file
-pub(crate) struct FileWriter<'a, W> {
-    pub(super) file: &'a mut W,
+/// A change in the source file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    /// The range in the source file that is replaced, as byte offsets
+    pub range: std::ops::Range<usize>,
+    /// The text that replaces the range
+    pub new_text: String,
 }
 
-impl<'a, W: Write> TokenWriter for FileWriter<'a, W> {
-    fn no_change(&mut self, token: SyntaxToken) -> std::io::Result<()> {
-        self.file.write_all(token.text().as_bytes())
+/// Collect the changes made to the tokens as text edits, instead of writing the whole file.
+///
+/// Adjacent changes are merged, so that only the edits that actually modify the text are
+/// returned by [`TextEditWriter::into_edits`].
+#[derive(Default)]
+pub struct TextEditWriter {
+    /// The edits, along with the text they replace
+    edits: Vec<(TextEdit, String)>,
+}
+
+impl TextEditWriter {
+    fn add(&mut self, offset: usize, old_text: &str, new_text: &str) {
+        match self.edits.last_mut() {
+            Some((last, last_old_text)) if last.range.end == offset => {
+                last.range.end += old_text.len();
+                last.new_text += new_text;
+                *last_old_text += old_text;
+            }
+            _ => self.edits.push((
+                TextEdit { range: offset..offset + old_text.len(), new_text: new_text.into() },
+                old_text.into(),
+            )),
+        }
+    }
+
+    /// Return the edits that modify the text
+    pub fn into_edits(self) -> Vec<TextEdit> {
+        self.edits
+            .into_iter()
+            .filter(|(e, old_text)| e.new_text != *old_text)
+            .map(|(e, _)| e)
+            .collect()
+    }
+}
+
+impl TokenWriter for TextEditWriter {
+    fn no_change(&mut self, _token: SyntaxToken) -> std::io::Result<()> {
+        Ok(())
     }
 
-    fn with_new_content(&mut self, _token: SyntaxToken, contents: &str) -> std::io::Result<()> {
-        self.file.write_all(contents.as_bytes())
+    fn with_new_content(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        self.add(token.text_range().start().into(), token.text(), contents);
+        Ok(())
     }
 
     fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
-        self.file.write_all(contents.as_bytes())?;
-        self.file.write_all(token.text().as_bytes())
+        self.add(token.text_range().start().into(), "", contents);
+        Ok(())
     }
 }
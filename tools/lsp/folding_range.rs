@@ -0,0 +1,92 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::parser::SyntaxKind;
+use lsp_types::{FoldingRange, Url};
+
+/// Return the folding ranges for the blocks of the elements, the states and the transitions.
+///
+/// The line of the closing brace is not part of the range, so that it stays visible.
+pub fn folding_ranges(document_cache: &mut DocumentCache, uri: &Url) -> Option<Vec<FoldingRange>> {
+    let path = uri.to_file_path().ok()?;
+    let doc = document_cache.documents.get_document(&path)?;
+    let doc_node = doc.node.clone()?;
+
+    let mut blocks = Vec::new();
+    for node in doc_node.node.descendants() {
+        if !matches!(
+            node.kind(),
+            SyntaxKind::Element
+                | SyntaxKind::States
+                | SyntaxKind::State
+                | SyntaxKind::Transitions
+                | SyntaxKind::Transition
+        ) {
+            continue;
+        }
+        let mut tokens = node.children_with_tokens().filter_map(|t| t.into_token());
+        let open = tokens.find(|t| matches!(t.kind(), SyntaxKind::LBrace | SyntaxKind::LBracket));
+        let close =
+            tokens.filter(|t| matches!(t.kind(), SyntaxKind::RBrace | SyntaxKind::RBracket)).last();
+        if let (Some(open), Some(close)) = (open, close) {
+            blocks.push((open.text_range().start(), close.text_range().start()));
+        }
+    }
+
+    let mapper = document_cache.offset_to_position_mapper(uri).ok()?;
+    Some(
+        blocks
+            .into_iter()
+            .filter_map(|(open, close)| {
+                let start_line = mapper.map_u32(open.into()).line;
+                let end_line = mapper.map_u32(close.into()).line.checked_sub(1)?;
+                (end_line > start_line).then(|| FoldingRange {
+                    start_line,
+                    end_line,
+                    ..Default::default()
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::loaded_document_cache;
+
+    #[test]
+    fn test_folding_ranges() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            r#"export component Demo inherits Rectangle {
+    Rectangle { }
+    ta := TouchArea {
+        width: 10px;
+    }
+    states [
+        pressed when ta.pressed : {
+            background: red;
+        }
+    ]
+    transitions [
+        in pressed : {
+            animate background { duration: 100ms; }
+        }
+    ]
+}
+"#
+            .into(),
+        );
+        let ranges = folding_ranges(&mut dc, &url)
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![(0, 14), (2, 3), (5, 8), (6, 7), (10, 13), (11, 12)]);
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Format the documents with the same engine as the `slint-fmt` tool.
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::parser::syntax_nodes;
+use lsp_types::{Range, TextEdit, Url};
+
+/// Return the edits that format the document, or only the part of it within `range`.
+///
+/// Returns None if the document is not a `.slint` file, or if it has syntax errors: the
+/// formatter would otherwise mangle the parts it cannot understand.
+pub fn format_document(
+    document_cache: &mut DocumentCache,
+    uri: &Url,
+    range: Option<Range>,
+) -> Option<Vec<TextEdit>> {
+    let path = uri.to_file_path().ok()?;
+    if path.extension().map_or(true, |e| e != "slint") {
+        return None;
+    }
+    let doc = document_cache.documents.get_document(&path)?;
+    let source = doc.node.as_ref()?.source_file()?.source()?.to_owned();
+
+    let mut diag = BuildDiagnostics::default();
+    let node = i_slint_compiler::parser::parse(source, Some(&path), &mut diag);
+    if diag.has_error() {
+        return None;
+    }
+
+    let edits = slint_fmt::format_document(syntax_nodes::Document::new(node)?);
+
+    let mapper = document_cache.offset_to_position_mapper(uri).ok()?;
+    Some(
+        edits
+            .into_iter()
+            .map(|edit| TextEdit {
+                range: Range::new(
+                    mapper.map_u32(edit.range.start as u32),
+                    mapper.map_u32(edit.range.end as u32),
+                ),
+                new_text: edit.new_text,
+            })
+            .filter(|edit| {
+                range.map_or(true, |r| edit.range.start >= r.start && edit.range.end <= r.end)
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::loaded_document_cache;
+
+    #[test]
+    fn test_format_document() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            "export component Foo inherits Rectangle {\n    width:   3px;\n    height: 4px;\n}\n"
+                .into(),
+        );
+        let edits = format_document(&mut dc, &url, None).unwrap();
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                range: Range::new(lsp_types::Position::new(1, 10), lsp_types::Position::new(1, 13)),
+                new_text: " ".into(),
+            }]
+        );
+
+        // Formatting a range that doesn't contain the edit
+        let range = Range::new(lsp_types::Position::new(2, 0), lsp_types::Position::new(3, 0));
+        assert_eq!(format_document(&mut dc, &url, Some(range)).unwrap(), vec![]);
+
+        // Documents with syntax errors are not formatted
+        let (mut dc, url, _) =
+            loaded_document_cache("fluent", "export component Foo {\n  width:: 3px;\n}\n".into());
+        assert!(format_document(&mut dc, &url, None).is_none());
+    }
+}
@@ -43,28 +43,7 @@ pub fn goto_definition(
                     }
                 }
                 SyntaxKind::Expression => {
-                    if token.kind() != SyntaxKind::Identifier {
-                        return None;
-                    }
-                    let lr = crate::util::with_lookup_ctx(document_cache, node, |ctx| {
-                        let mut it = n
-                            .children_with_tokens()
-                            .filter_map(|t| t.into_token())
-                            .filter(|t| t.kind() == SyntaxKind::Identifier);
-                        let mut cur_tok = it.next()?;
-                        let first_str =
-                            i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                        let global = i_slint_compiler::lookup::global_lookup();
-                        let mut expr_it = global.lookup(ctx, &first_str)?;
-                        while cur_tok.token != token.token {
-                            cur_tok = it.next()?;
-                            let str =
-                                i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                            expr_it = expr_it.lookup(ctx, &str)?;
-                        }
-                        Some(expr_it)
-                    })?;
-                    let gn = match lr? {
+                    let gn = match lookup_expression_at(document_cache, &n, &token)? {
                         LookupResult::Expression {
                             expression: Expression::ElementReference(e),
                             ..
@@ -171,6 +150,34 @@ pub fn goto_definition(
     }
 }
 
+/// Lookup what the identifier `token`, part of the QualifiedName `n` in an expression, refers to.
+/// For `foo.bar.baz`, if `token` is `bar`, this returns the lookup result of `foo.bar`
+pub fn lookup_expression_at(
+    document_cache: &DocumentCache,
+    n: &syntax_nodes::QualifiedName,
+    token: &SyntaxToken,
+) -> Option<LookupResult> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    crate::util::with_lookup_ctx(document_cache, (**n).clone(), |ctx| {
+        let mut it = n
+            .children_with_tokens()
+            .filter_map(|t| t.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Identifier);
+        let mut cur_tok = it.next()?;
+        let first_str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut expr_it = global.lookup(ctx, &first_str)?;
+        while cur_tok.token != token.token {
+            cur_tok = it.next()?;
+            let str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+            expr_it = expr_it.lookup(ctx, &str)?;
+        }
+        Some(expr_it)
+    })?
+}
+
 /// Try to lookup the property `prop_name` in the base of the given Element
 fn find_property_declaration_in_base(
     document_cache: &DocumentCache,
@@ -0,0 +1,386 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{BuiltinPropertyInfo, ElementType, Type};
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::object_tree::{ElementRc, PropertyVisibility, QualifiedTypeName};
+use i_slint_compiler::parser::{
+    doc_comment, identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode,
+    SyntaxToken,
+};
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// Return the hover information for the given token: the signature of the element, property,
+/// callback or type it refers to, followed by its documentation.
+pub fn get_tooltip(document_cache: &mut DocumentCache, token: SyntaxToken) -> Option<Hover> {
+    let mut node = token.parent();
+    let value = loop {
+        if let Some(n) = syntax_nodes::QualifiedName::new(node.clone()) {
+            let parent = n.parent()?;
+            let doc = document_cache.documents.get_document(node.source_file.path())?;
+            break match parent.kind() {
+                SyntaxKind::Type => {
+                    let qual = QualifiedTypeName::from_node(n);
+                    type_tooltip(&doc.local_registry.lookup_qualified(&qual.members))?
+                }
+                SyntaxKind::Element => element_type_tooltip(
+                    &crate::util::lookup_current_element_type(parent, &doc.local_registry)?,
+                )?,
+                SyntaxKind::Expression => expression_tooltip(document_cache, &n, &token)?,
+                _ => return None,
+            };
+        } else if let Some(n) = syntax_nodes::DeclaredIdentifier::new(node.clone()) {
+            let declaration = n.parent()?;
+            let name = identifier_text(&n)?;
+            break match declaration.kind() {
+                SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function => {
+                    let element = syntax_nodes::Element::new(declaration.parent()?)?;
+                    property_tooltip(&find_element(document_cache, &element)?, &name)?
+                }
+                SyntaxKind::Component => {
+                    let doc = document_cache.documents.get_document(node.source_file.path())?;
+                    element_type_tooltip(&doc.local_registry.lookup_element(&name).ok()?)?
+                }
+                SyntaxKind::StructDeclaration => {
+                    let doc = document_cache.documents.get_document(node.source_file.path())?;
+                    type_tooltip(&doc.local_registry.lookup(&name))?
+                }
+                _ => return None,
+            };
+        } else if syntax_nodes::BindingExpression::new(node.clone()).is_some() {
+            // don't fallback to the Binding
+            return None;
+        } else if matches!(
+            node.kind(),
+            SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection
+        ) {
+            // Only the name of the property, not what is in the code block or the expression
+            if token.kind() != SyntaxKind::Identifier
+                || token.token.parent().as_ref() != Some(&node.node)
+            {
+                return None;
+            }
+            let element = syntax_nodes::Element::new(node.parent()?)?;
+            break property_tooltip(
+                &find_element(document_cache, &element)?,
+                &normalize_identifier(token.text()),
+            )?;
+        }
+        node = node.parent()?;
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range: None,
+    })
+}
+
+/// Format the signature as a slint code block, followed by the documentation
+fn markdown(signature: &str, documentation: Option<String>) -> String {
+    let mut value = format!("```slint\n{signature}\n```");
+    if let Some(documentation) = documentation {
+        value += "\n\n";
+        value += &documentation;
+    }
+    value
+}
+
+/// Find the element of the object tree that was created from this syntax node
+pub fn find_element(
+    document_cache: &DocumentCache,
+    element: &syntax_nodes::Element,
+) -> Option<ElementRc> {
+    fn find_in(e: &ElementRc, element: &syntax_nodes::Element) -> Option<ElementRc> {
+        if e.borrow().node.as_ref().map_or(false, |n| n.node == element.node) {
+            return Some(e.clone());
+        }
+        e.borrow().children.iter().find_map(|c| find_in(c, element))
+    }
+    let doc = document_cache.documents.get_document(element.source_file.path())?;
+    doc.inner_components.iter().find_map(|c| find_in(&c.root_element, element))
+}
+
+fn expression_tooltip(
+    document_cache: &DocumentCache,
+    n: &syntax_nodes::QualifiedName,
+    token: &SyntaxToken,
+) -> Option<String> {
+    match crate::goto::lookup_expression_at(document_cache, n, token)? {
+        LookupResult::Expression { expression, .. } => match &expression {
+            Expression::PropertyReference(nr)
+            | Expression::CallbackReference(nr, _)
+            | Expression::FunctionReference(nr, _) => property_tooltip(&nr.element(), nr.name()),
+            Expression::ElementReference(e) => element_tooltip(&e.upgrade()?),
+            Expression::StructFieldAccess { base, name } => Some(markdown(
+                &format!("{name}: {}", expression.ty()),
+                struct_field_documentation(&base.ty(), name),
+            )),
+            Expression::EnumerationValue(value) => Some(markdown(
+                &format!("{}.{}", value.enumeration.name, value.enumeration.values[value.value]),
+                None,
+            )),
+            _ => None,
+        },
+        LookupResult::Enumeration(e) => type_tooltip(&Type::Enumeration(e)),
+        LookupResult::Namespace(_) => None,
+    }
+}
+
+fn type_tooltip(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Struct { fields, name: Some(name), node } => {
+            let fields = fields.iter().map(|(k, v)| format!("    {k}: {v},\n")).collect::<String>();
+            Some(markdown(
+                &format!("struct {name} {{\n{fields}}}"),
+                node.as_ref().and_then(|n| doc_comment(&n.parent()?)),
+            ))
+        }
+        Type::Enumeration(e) => {
+            let values = e.values.iter().map(|v| format!("    {v},\n")).collect::<String>();
+            Some(markdown(&format!("enum {} {{\n{values}}}", e.name), None))
+        }
+        _ => None,
+    }
+}
+
+fn struct_field_documentation(ty: &Type, field: &str) -> Option<String> {
+    match ty {
+        Type::Struct { node: Some(node), .. } => node
+            .ObjectTypeMember()
+            .find(|m| {
+                m.child_text(SyntaxKind::Identifier)
+                    .map_or(false, |n| normalize_identifier(&n) == field)
+            })
+            .and_then(|m| doc_comment(&m)),
+        _ => None,
+    }
+}
+
+fn element_type_documentation(ty: &ElementType) -> Option<String> {
+    match ty {
+        ElementType::Component(c) => c.node.as_ref().and_then(doc_comment),
+        ElementType::Builtin(b) => b.documentation.clone(),
+        _ => None,
+    }
+}
+
+fn element_type_tooltip(ty: &ElementType) -> Option<String> {
+    let signature = match ty {
+        ElementType::Component(c) if c.is_global() => format!("global {}", c.id),
+        ElementType::Component(c) => match &c.root_element.borrow().base_type {
+            base @ (ElementType::Component(_) | ElementType::Builtin(_)) => {
+                format!("component {} inherits {}", c.id, base)
+            }
+            _ => format!("component {}", c.id),
+        },
+        ElementType::Builtin(b) if b.is_global => format!("global {}", b.name),
+        ElementType::Builtin(b) => b.name.clone(),
+        _ => return None,
+    };
+    Some(markdown(&signature, element_type_documentation(ty)))
+}
+
+fn element_tooltip(element: &ElementRc) -> Option<String> {
+    let e = element.borrow();
+    let component = e.enclosing_component.upgrade()?;
+    if Rc::ptr_eq(&component.root_element, element) {
+        // `root`, or a global
+        return element_type_tooltip(&ElementType::Component(component));
+    }
+    let signature = if e.id.is_empty() {
+        e.base_type.to_string()
+    } else {
+        format!("{} := {}", e.id, e.base_type)
+    };
+    Some(markdown(&signature, element_type_documentation(&e.base_type)))
+}
+
+/// Find where the property, callback or function `name` is declared: in the element itself or
+/// in one of its base components (the syntax node of the declaration), or in its builtin base.
+pub fn property_declaration(
+    element: &ElementRc,
+    name: &str,
+) -> (Option<SyntaxNode>, Option<BuiltinPropertyInfo>) {
+    let mut el = element.clone();
+    loop {
+        if let Some(d) = el.borrow().property_declarations.get(name) {
+            return (d.node.clone(), None);
+        }
+        let base = el.borrow().base_type.clone();
+        match base {
+            ElementType::Component(c) => el = c.root_element.clone(),
+            ElementType::Builtin(b) => return (None, b.properties.get(name).cloned()),
+            _ => return (None, None),
+        }
+    }
+}
+
+/// The tooltip of the property, callback or function `name` of the given element.
+fn property_tooltip(element: &ElementRc, name: &str) -> Option<String> {
+    let lookup = element.borrow().lookup_property(name);
+    if lookup.property_type == Type::Invalid {
+        return None;
+    }
+    let name = lookup.resolved_name.to_string();
+    let (declaration, builtin) = property_declaration(element, &name);
+
+    let join_args =
+        |args: &[Type]| args.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(", ");
+    let pure = if lookup.declared_pure == Some(true) { "pure " } else { "" };
+    let mut signature = match &lookup.property_type {
+        Type::Callback { args, return_type } => {
+            let mut s = format!("{pure}callback {name}({})", join_args(args));
+            if let Some(return_type) = return_type {
+                write!(s, " -> {return_type}").ok()?;
+            }
+            s
+        }
+        Type::Function { args, return_type } => {
+            let public = if lookup.property_visibility == PropertyVisibility::Public {
+                "public "
+            } else {
+                ""
+            };
+            let mut s = format!("{public}{pure}function {name}({})", join_args(args));
+            if **return_type != Type::Void {
+                write!(s, " -> {return_type}").ok()?;
+            }
+            s
+        }
+        ty => {
+            let visibility = match lookup.property_visibility {
+                PropertyVisibility::Input => "in ",
+                PropertyVisibility::Output => "out ",
+                PropertyVisibility::InOut => "in-out ",
+                PropertyVisibility::Private | PropertyVisibility::Public => "",
+            };
+            format!("{visibility}property <{ty}> {name}")
+        }
+    };
+
+    // The default value
+    if let Some(p) = declaration.clone().and_then(syntax_nodes::PropertyDeclaration::new) {
+        if let Some(b) = p.BindingExpression() {
+            write!(signature, ": {}", b.text().to_string().trim()).ok()?;
+        } else if let Some(twb) = p.TwoWayBinding() {
+            write!(signature, " <=> {}", twb.Expression().text().to_string().trim()).ok()?;
+        }
+    } else if let Some(default_value) = builtin.as_ref().and_then(|b| b.default_value.as_ref()) {
+        signature += ": ";
+        i_slint_compiler::expression_tree::pretty_print(&mut signature, default_value).ok()?;
+    }
+
+    let documentation = declaration
+        .as_ref()
+        .and_then(doc_comment)
+        .or_else(|| builtin.and_then(|b| b.documentation))
+        .or_else(|| reserved_property_documentation(&name).map(Into::into));
+    Some(markdown(&signature, documentation))
+}
+
+/// The documentation of the properties that are available on every element
+fn reserved_property_documentation(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "x" | "y" => "The position of the element relative to its parent.",
+        "width" | "height" => "The size of the element.",
+        "z" => "Allows to change the stacking order of the element relative to its siblings.",
+        "min-width" | "min-height" => "The minimum size of the element when it is in a layout.",
+        "max-width" | "max-height" => "The maximum size of the element when it is in a layout.",
+        "preferred-width" | "preferred-height" => {
+            "The size the element should have in a layout, when there is enough space."
+        }
+        "horizontal-stretch" | "vertical-stretch" => {
+            "How much the element stretches relative to the other elements of the layout when there is extra space."
+        }
+        "col" | "row" | "colspan" | "rowspan" => "The cell of the element in a GridLayout.",
+        "padding" | "padding-left" | "padding-right" | "padding-top" | "padding-bottom" => {
+            "The space between the border of the layout and its elements."
+        }
+        "opacity" => {
+            "A value between 0 and 1 used to draw the element and its children with transparency."
+        }
+        "visible" => "When set to `false`, the element and its children are not drawn.",
+        "clip" => "When set to `true`, the children are clipped to the bounds of the element.",
+        "accessible-role" => "The role of the element, used by assistive technologies.",
+        "accessible-label" => "The label of an interactive element, for assistive technologies.",
+        "accessible-description" => "The description of the element, for assistive technologies.",
+        "accessible-value" => "The current value of the element, for assistive technologies.",
+        "dialog-button-role" => "The role of the button in a Dialog.",
+        "forward-focus" => "The element that receives the focus when this element gets it.",
+        "init" => "Invoked when the element is created.",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::loaded_document_cache;
+
+    fn tooltip_at(
+        dc: &mut DocumentCache,
+        url: &lsp_types::Url,
+        line: u32,
+        character: u32,
+    ) -> String {
+        let (token, _) =
+            crate::server_loop::token_descr(dc, url, &lsp_types::Position { line, character })
+                .expect("no token at position");
+        match get_tooltip(dc, token).expect("no tooltip").contents {
+            HoverContents::Markup(m) => m.value,
+            _ => panic!("expected markdown"),
+        }
+    }
+
+    #[test]
+    fn test_tooltip() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            r#"/// A nice button
+component MyButton inherits Rectangle {
+    /// The text of the button
+    in property <string> text: "Ok";
+    callback clicked(int) -> bool;
+}
+export component Demo {
+    b := MyButton {
+        text: "Hello";
+        clicked(x) => { true }
+        width: self.preferred-width;
+    }
+    TouchArea { clicked => { b.clicked(42); } }
+}
+"#
+            .into(),
+        );
+        assert_eq!(
+            tooltip_at(&mut dc, &url, 7, 10),
+            "```slint\ncomponent MyButton inherits Rectangle\n```\n\nA nice button"
+        );
+        assert_eq!(
+            tooltip_at(&mut dc, &url, 8, 9),
+            "```slint\nin property <string> text: \"Ok\"\n```\n\nThe text of the button"
+        );
+        assert_eq!(tooltip_at(&mut dc, &url, 9, 9), "```slint\ncallback clicked(int) -> bool\n```");
+        assert_eq!(
+            tooltip_at(&mut dc, &url, 10, 27),
+            "```slint\nin-out property <length> preferred-width\n```\n\nThe size the element should have in a layout, when there is enough space."
+        );
+        assert!(tooltip_at(&mut dc, &url, 12, 6)
+            .starts_with("```slint\nTouchArea\n```\n\nAn invisible element"));
+        assert!(tooltip_at(&mut dc, &url, 12, 18)
+            .starts_with("```slint\ncallback clicked()\n```\n\nInvoked when clicked"));
+        assert_eq!(
+            tooltip_at(&mut dc, &url, 12, 33),
+            "```slint\ncallback clicked(int) -> bool\n```"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Show the types that are inferred by the compiler: the type of the properties declared with a
+//! two-way binding, and the type of the model data in `for` loops.
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::parser::{identifier_text, syntax_nodes, SyntaxKind, SyntaxNode};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range, Url};
+use std::rc::Rc;
+
+pub fn inlay_hints(
+    document_cache: &mut DocumentCache,
+    uri: &Url,
+    range: &Range,
+) -> Option<Vec<InlayHint>> {
+    let path = uri.to_file_path().ok()?;
+    let doc = document_cache.documents.get_document(&path)?;
+    let doc_node = doc.node.clone()?;
+
+    // (offset, label, whether the hint is before the text)
+    let mut hints = Vec::new();
+    for node in doc_node.node.descendants() {
+        let node = SyntaxNode { node, source_file: doc_node.source_file.clone() };
+        match node.kind() {
+            SyntaxKind::PropertyDeclaration => {
+                let decl = syntax_nodes::PropertyDeclaration::from(node);
+                if decl.Type().is_some() || decl.TwoWayBinding().is_none() {
+                    continue;
+                }
+                let identifier = decl.DeclaredIdentifier();
+                let element = decl.parent().and_then(syntax_nodes::Element::new);
+                let ty = element
+                    .and_then(|e| crate::hover::find_element(document_cache, &e))
+                    .zip(identifier_text(&identifier))
+                    .and_then(|(e, name)| {
+                        e.borrow().property_declarations.get(&name).map(|d| d.property_type.clone())
+                    });
+                if let Some(ty) = ty.filter(|ty| *ty != Type::Invalid) {
+                    hints.push((identifier.text_range().start(), format!("<{ty}>"), true));
+                }
+            }
+            SyntaxKind::RepeatedElement => {
+                let repeated = syntax_nodes::RepeatedElement::from(node);
+                let identifier = match repeated.DeclaredIdentifier() {
+                    Some(identifier) => identifier,
+                    None => continue,
+                };
+                let ty =
+                    crate::hover::find_element(document_cache, &repeated.SubElement().Element())
+                        .map(|e| {
+                            Expression::RepeaterModelReference { element: Rc::downgrade(&e) }.ty()
+                        });
+                if let Some(ty) = ty.filter(|ty| *ty != Type::Invalid) {
+                    hints.push((identifier.text_range().end(), format!(": {ty}"), false));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mapper = document_cache.offset_to_position_mapper(uri).ok()?;
+    Some(
+        hints
+            .into_iter()
+            .map(|(offset, label, before)| InlayHint {
+                position: mapper.map_u32(offset.into()),
+                label: InlayHintLabel::String(label),
+                kind: Some(InlayHintKind::TYPE),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: before.then_some(true),
+                data: None,
+            })
+            .filter(|hint| hint.position >= range.start && hint.position <= range.end)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::loaded_document_cache;
+    use lsp_types::Position;
+
+    #[test]
+    fn test_inlay_hints() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            r#"struct Item { name: string }
+export component Demo {
+    property <[Item]> model;
+    property foo <=> t.text;
+    t := Text {}
+    for item[idx] in model : Text { text: item.name; }
+    for i in 3 : Rectangle {}
+}
+"#
+            .into(),
+        );
+        let hints = |dc: &mut DocumentCache, range| {
+            inlay_hints(dc, &url, &range)
+                .unwrap()
+                .into_iter()
+                .map(|h| match h.label {
+                    InlayHintLabel::String(label) => (h.position, label),
+                    _ => panic!("expected a string label"),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            hints(&mut dc, Range::new(Position::new(0, 0), Position::new(8, 0))),
+            vec![
+                (Position::new(3, 13), "<string>".into()),
+                (Position::new(5, 12), ": Item".into()),
+                (Position::new(6, 9), ": int".into()),
+            ]
+        );
+        assert_eq!(
+            hints(&mut dc, Range::new(Position::new(6, 0), Position::new(7, 0))),
+            vec![(Position::new(6, 9), ": int".into())]
+        );
+    }
+}
@@ -4,13 +4,19 @@
 #![cfg(not(target_arch = "wasm32"))]
 
 mod completion;
+mod folding_range;
+mod formatting;
 mod goto;
+mod hover;
+mod inlay_hints;
 mod lsp_ext;
 #[cfg(feature = "preview")]
 mod preview;
 mod properties;
+mod references;
 mod semantic_tokens;
 mod server_loop;
+mod signature_help;
 #[cfg(test)]
 mod test;
 mod util;
@@ -219,10 +225,18 @@ fn main_loop(connection: &Connection, init_param: InitializeParams) -> Result<()
 
     let request_queue = OutgoingRequestQueue::default();
     let server_notifier = ServerNotifier(connection.sender.clone(), request_queue.clone());
+    let mut document_cache = DocumentCache::new(compiler_config);
+    document_cache.workspace_roots = init_param
+        .workspace_folders
+        .iter()
+        .flatten()
+        .filter_map(|folder| folder.uri.to_file_path().ok())
+        .collect();
     let ctx = Rc::new(Context {
-        document_cache: RefCell::new(DocumentCache::new(compiler_config)),
+        document_cache: RefCell::new(document_cache),
         server_notifier: server_notifier.clone(),
         init_param,
+        latest_versions: Default::default(),
         #[cfg(feature = "preview-api")]
         preview: server_loop::PreviewApi {
             highlight: Box::new(
@@ -241,44 +255,67 @@ fn main_loop(connection: &Connection, init_param: InitializeParams) -> Result<()
     let mut futures = Vec::<Pin<Box<dyn Future<Output = Result<(), Error>>>>>::new();
     let mut first_future = Box::pin(load_configuration(&ctx));
 
-    // We are waiting in this loop for two kind of futures:
+    // We are waiting in this loop for three kind of futures:
     //  - The compiler future should always be ready immediately because we do not set a callback to load files
     //  - the future from `send_request` are blocked waiting for a response from the client.
-    //    Responses are sent on the `connection.receiver` which will wake the loop, so there
-    //    is no need to do anything in the Waker.
-    struct DummyWaker;
-    impl std::task::Wake for DummyWaker {
-        fn wake(self: Arc<Self>) {}
+    //    Responses are sent on the `connection.receiver` which will wake the loop.
+    //  - the document changes yield once before compiling, so that all the messages that were
+    //    already received are handled first. Those wake the waker, and the loop then polls the
+    //    futures again without waiting for a new message.
+    #[derive(Default)]
+    struct FlagWaker(atomic::AtomicBool);
+    impl std::task::Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, atomic::Ordering::Relaxed)
+        }
     }
-    let waker = Arc::new(DummyWaker).into();
+    let flag_waker = Arc::new(FlagWaker::default());
+    let waker = flag_waker.clone().into();
     match first_future.as_mut().poll(&mut std::task::Context::from_waker(&waker)) {
         Poll::Ready(x) => x?,
         Poll::Pending => futures.push(first_future),
     };
 
-    for msg in &connection.receiver {
-        match msg {
-            Message::Request(req) => {
-                if connection.handle_shutdown(&req)? {
-                    return Ok(());
-                }
-                futures.push(Box::pin(rh.handle_request(req, &ctx)));
+    loop {
+        let first_msg = if flag_waker.0.swap(false, atomic::Ordering::Relaxed) {
+            match connection.receiver.try_recv() {
+                Ok(msg) => Some(msg),
+                Err(crossbeam_channel::TryRecvError::Empty) => None,
+                Err(crossbeam_channel::TryRecvError::Disconnected) => break,
             }
-            Message::Response(resp) => {
-                if let Some(q) = request_queue.lock().unwrap().get_mut(&resp.id) {
-                    match q {
-                        OutgoingRequest::Done(_) => {
-                            return Err("Response to unknown request".into())
-                        }
-                        OutgoingRequest::Pending(x) => x.wake_by_ref(),
-                    };
-                    *q = OutgoingRequest::Done(resp)
-                } else {
-                    return Err("Response to unknown request".into());
-                }
+        } else {
+            match connection.receiver.recv() {
+                Ok(msg) => Some(msg),
+                Err(_) => break,
             }
-            Message::Notification(notification) => {
-                futures.push(Box::pin(handle_notification(notification, &ctx)))
+        };
+
+        // Handle all the messages that are already there before polling the futures
+        let pending_msgs = std::iter::from_fn(|| connection.receiver.try_recv().ok());
+        for msg in first_msg.into_iter().chain(pending_msgs) {
+            match msg {
+                Message::Request(req) => {
+                    if connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+                    futures.push(Box::pin(rh.handle_request(req, &ctx)));
+                }
+                Message::Response(resp) => {
+                    if let Some(q) = request_queue.lock().unwrap().get_mut(&resp.id) {
+                        match q {
+                            OutgoingRequest::Done(_) => {
+                                return Err("Response to unknown request".into())
+                            }
+                            OutgoingRequest::Pending(x) => x.wake_by_ref(),
+                        };
+                        *q = OutgoingRequest::Done(resp)
+                    } else {
+                        return Err("Response to unknown request".into());
+                    }
+                }
+                Message::Notification(notification) => {
+                    futures.push(Box::pin(handle_notification(notification, &ctx)))
+                }
             }
         }
 
@@ -300,6 +337,21 @@ fn wake(self: Arc<Self>) {}
     Ok(())
 }
 
+/// Returns Pending the first time it is polled, so that the other futures run before it resumes
+async fn yield_now() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
 async fn handle_notification(
     req: lsp_server::Notification,
     ctx: &Rc<Context>,
@@ -307,6 +359,7 @@ async fn handle_notification(
     match &*req.method {
         DidOpenTextDocument::METHOD => {
             let params: DidOpenTextDocumentParams = serde_json::from_value(req.params)?;
+            ctx.set_latest_version(&params.text_document.uri, params.text_document.version);
             reload_document(
                 &ctx.server_notifier,
                 params.text_document.text,
@@ -317,12 +370,23 @@ async fn handle_notification(
             .await?;
         }
         DidChangeTextDocument::METHOD => {
-            let mut params: DidChangeTextDocumentParams = serde_json::from_value(req.params)?;
+            let params: DidChangeTextDocumentParams = serde_json::from_value(req.params)?;
+            let uri = params.text_document.uri;
+            let version = params.text_document.version;
+            let content =
+                ctx.document_cache.borrow_mut().apply_content_changes(&uri, params.content_changes);
+            ctx.set_latest_version(&uri, version);
+
+            // Skip the compilation if the document was changed again in the meantime
+            yield_now().await;
+            if ctx.is_outdated(&uri, version) {
+                return Ok(());
+            }
             reload_document(
                 &ctx.server_notifier,
-                params.content_changes.pop().unwrap().text,
-                params.text_document.uri,
-                params.text_document.version,
+                content,
+                uri,
+                version,
                 &mut ctx.document_cache.borrow_mut(),
             )
             .await?;
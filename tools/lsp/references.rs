@@ -0,0 +1,376 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! Find the references to the components, globals and structs across all the loaded documents,
+//! and rename them.
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::object_tree::Component;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxToken,
+};
+use i_slint_compiler::typeloader::ImportedName;
+use i_slint_compiler::typeregister::TypeRegister;
+use lsp_types::{Location, TextEdit, Url, WorkspaceEdit};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// A symbol that can be used in other documents than the one that declares it
+#[derive(Clone)]
+enum Symbol {
+    Component(Rc<Component>),
+    Struct(syntax_nodes::ObjectType),
+}
+
+impl Symbol {
+    fn from_registry(tr: &TypeRegister, name: &str) -> Option<Self> {
+        match tr.lookup_element(name) {
+            Ok(ElementType::Component(c)) => Some(Self::Component(c)),
+            _ => match tr.lookup(name) {
+                Type::Struct { node: Some(node), .. } => Some(Self::Struct(node)),
+                _ => None,
+            },
+        }
+    }
+
+    /// The name with which the symbol was declared
+    fn name(&self) -> Option<String> {
+        match self {
+            Self::Component(c) => Some(c.id.clone()),
+            Self::Struct(node) => identifier_text(
+                &syntax_nodes::StructDeclaration::new(node.parent()?)?.DeclaredIdentifier(),
+            ),
+        }
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Component(a), Self::Component(b)) => Rc::ptr_eq(a, b),
+            (Self::Struct(a), Self::Struct(b)) => a.node == b.node,
+            _ => false,
+        }
+    }
+
+    /// The canonical path of the file in which the symbol is declared
+    fn source_path(&self) -> Option<PathBuf> {
+        let path = match self {
+            Self::Component(c) => c.node.as_ref()?.source_file.path().to_owned(),
+            Self::Struct(node) => node.source_file.path().to_owned(),
+        };
+        Some(dunce::canonicalize(&path).unwrap_or(path))
+    }
+
+    /// Symbols from the style or from the builtins cannot be renamed
+    fn is_builtin(&self) -> bool {
+        self.source_path().map_or(true, |path| path.starts_with("builtin:"))
+    }
+}
+
+/// Return the symbol that the token refers to, and whether the token is its declaration
+fn symbol_at(document_cache: &DocumentCache, token: &SyntaxToken) -> Option<(Symbol, bool)> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let parent = token.parent();
+    let doc = document_cache.documents.get_document(parent.source_file.path())?;
+    let tr = &doc.local_registry;
+    let name = normalize_identifier(token.text());
+    match parent.kind() {
+        SyntaxKind::QualifiedName => {
+            // Only the first identifier of `Foo.bar` can refer to a type or a global
+            if parent.child_token(SyntaxKind::Identifier)?.token != token.token {
+                return None;
+            }
+            let symbol = match parent.parent()?.kind() {
+                SyntaxKind::Element | SyntaxKind::Type => Symbol::from_registry(tr, &name)?,
+                SyntaxKind::Expression => {
+                    let qualified_name = syntax_nodes::QualifiedName::new(parent)?;
+                    match crate::goto::lookup_expression_at(document_cache, &qualified_name, token)?
+                    {
+                        LookupResult::Expression {
+                            expression: Expression::ElementReference(e),
+                            ..
+                        } => {
+                            let e = e.upgrade()?;
+                            let c = e.borrow().enclosing_component.upgrade()?;
+                            if !c.is_global() || !Rc::ptr_eq(&c.root_element, &e) {
+                                return None;
+                            }
+                            Symbol::Component(c)
+                        }
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+            Some((symbol, false))
+        }
+        SyntaxKind::DeclaredIdentifier => match parent.parent()?.kind() {
+            SyntaxKind::Component | SyntaxKind::StructDeclaration => {
+                Some((Symbol::from_registry(tr, &name)?, true))
+            }
+            _ => None,
+        },
+        SyntaxKind::ExternalName | SyntaxKind::InternalName => {
+            let imported =
+                ImportedName::from_node(syntax_nodes::ImportIdentifier::new(parent.parent()?)?);
+            Some((Symbol::from_registry(tr, &imported.internal_name)?, false))
+        }
+        SyntaxKind::ExportIdentifier => Some((Symbol::from_registry(tr, &name)?, false)),
+        _ => None,
+    }
+}
+
+/// A place in a document that refers to a symbol
+struct Reference {
+    path: PathBuf,
+    token: SyntaxToken,
+    is_declaration: bool,
+}
+
+/// Find all the references of the symbol in the document that declares it, and in the documents
+/// that import it, which are loaded first if needed.
+///
+/// The uses under another name, because of an `import { Foo as Bar }`, are also returned when
+/// `with_aliases` is set.
+async fn symbol_references(
+    document_cache: &mut DocumentCache,
+    symbol: &Symbol,
+    with_aliases: bool,
+) -> Vec<Reference> {
+    let (name, source_path) = match (symbol.name(), symbol.source_path()) {
+        (Some(name), Some(source_path)) => (name, source_path),
+        _ => return Vec::new(),
+    };
+    document_cache.load_dependents(&source_path).await;
+    let files = std::iter::once(source_path.clone()).chain(document_cache.dependents(&source_path));
+    let mut result = Vec::new();
+    for file in files {
+        let doc_node =
+            match document_cache.documents.get_document(&file).and_then(|d| d.node.as_ref()) {
+                Some(node) => node,
+                None => continue,
+            };
+        let path = doc_node.source_file.path();
+        if path.starts_with("builtin:") {
+            continue;
+        }
+
+        // The names under which the symbol can be used in this document
+        let mut names = vec![name.clone()];
+        if with_aliases {
+            names.extend(
+                doc_node
+                    .ImportSpecifier()
+                    .flat_map(|import| {
+                        ImportedName::extract_imported_names(&import).collect::<Vec<_>>()
+                    })
+                    .filter(|imported| imported.external_name == name)
+                    .map(|imported| imported.internal_name),
+            );
+        }
+
+        for token in doc_node.descendants_with_tokens().filter_map(|t| t.into_token()) {
+            if token.kind() != SyntaxKind::Identifier
+                || !names.contains(&normalize_identifier(token.text()))
+            {
+                continue;
+            }
+            let token = SyntaxToken { token, source_file: doc_node.source_file.clone() };
+            if let Some((s, is_declaration)) = symbol_at(document_cache, &token) {
+                if s.is_same(symbol) {
+                    result.push(Reference { path: path.to_owned(), token, is_declaration });
+                }
+            }
+        }
+    }
+    result
+}
+
+fn to_location(document_cache: &mut DocumentCache, reference: &Reference) -> Option<Location> {
+    let uri = Url::from_file_path(&reference.path).ok()?;
+    let range = document_cache
+        .offset_to_position_mapper(&uri)
+        .ok()?
+        .map_range(reference.token.text_range());
+    Some(Location { uri, range })
+}
+
+/// Return the locations of all the uses of the component, global or struct under the token.
+pub async fn find_references(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let (symbol, _) = symbol_at(document_cache, token)?;
+    let references = symbol_references(document_cache, &symbol, true).await;
+    Some(
+        references
+            .iter()
+            .filter(|r| include_declaration || !r.is_declaration)
+            .filter_map(|r| to_location(document_cache, r))
+            .collect(),
+    )
+}
+
+/// Return true if the token is the name of a component, global or struct that can be renamed
+pub fn can_rename(document_cache: &DocumentCache, token: &SyntaxToken) -> bool {
+    symbol_at(document_cache, token).map_or(false, |(symbol, _)| {
+        !symbol.is_builtin()
+            && symbol.name().map_or(false, |name| normalize_identifier(token.text()) == name)
+    })
+}
+
+/// Rename the component, global or struct under the token, in all the documents that use it.
+pub async fn rename(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+    new_name: &str,
+) -> Result<WorkspaceEdit, crate::Error> {
+    if !can_rename(document_cache, token) {
+        return Err("This symbol cannot be renamed".into());
+    }
+    if !is_valid_identifier(new_name) {
+        return Err(format!("'{new_name}' is not a valid identifier").into());
+    }
+    let (symbol, _) = symbol_at(document_cache, token).ok_or("This symbol cannot be renamed")?;
+    let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+    for reference in symbol_references(document_cache, &symbol, false).await {
+        if let Some(location) = to_location(document_cache, &reference) {
+            changes
+                .entry(location.uri)
+                .or_default()
+                .push(TextEdit { range: location.range, new_text: new_name.into() });
+        }
+    }
+    Ok(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(false, |c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::server_loop::token_descr;
+    use crate::test::{empty_document_cache, load_file_on_disk};
+
+    fn token_at(dc: &mut DocumentCache, url: &Url, line: u32, character: u32) -> SyntaxToken {
+        token_descr(dc, url, &lsp_types::Position { line, character }).unwrap().0
+    }
+
+    #[test]
+    fn test_references_and_rename_across_files() {
+        let mut dc = empty_document_cache("fluent");
+        let dir = tempfile::tempdir().unwrap();
+        let lib = load_file_on_disk(
+            &mut dc,
+            dir.path(),
+            "lib.slint",
+            r#"export struct Data { value: int }
+export global Settings { in-out property <Data> data; }
+export component Card inherits Rectangle {
+    in property <Data> data: Settings.data;
+}
+"#,
+        );
+        let main = load_file_on_disk(
+            &mut dc,
+            dir.path(),
+            "main.slint",
+            r#"import { Card, Settings, Data as Info } from "lib.slint";
+export component Main {
+    property <Info> info: Settings.data;
+    Card { data: info; }
+    Card { }
+}
+"#,
+        );
+
+        // `Card` in main.slint
+        let token = token_at(&mut dc, &main, 3, 5);
+        let mut references = spin_on::spin_on(find_references(&mut dc, &token, true)).unwrap();
+        references.sort_by_key(|l| (l.uri.to_string(), l.range.start));
+        let positions = references
+            .iter()
+            .map(|l| (l.uri == main, l.range.start.line, l.range.start.character))
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![(false, 2, 17), (true, 0, 9), (true, 3, 4), (true, 4, 4)]);
+        assert_eq!(spin_on::spin_on(find_references(&mut dc, &token, false)).unwrap().len(), 3);
+
+        // Rename the global from its use in lib.slint
+        let token = token_at(&mut dc, &lib, 3, 31);
+        let edit = spin_on::spin_on(rename(&mut dc, &token, "Config")).unwrap();
+        let changes = edit.changes.unwrap();
+        assert_eq!(changes[&lib].len(), 2);
+        assert_eq!(changes[&main].len(), 2);
+        assert!(changes.values().flatten().all(|e| e.new_text == "Config"));
+
+        // The struct is imported under another name: only the import is renamed in main.slint
+        let token = token_at(&mut dc, &lib, 0, 15);
+        assert_eq!(spin_on::spin_on(find_references(&mut dc, &token, true)).unwrap().len(), 6);
+        let changes =
+            spin_on::spin_on(rename(&mut dc, &token, "Payload")).unwrap().changes.unwrap();
+        assert_eq!(changes[&lib].len(), 3);
+        assert_eq!(changes[&main].len(), 1);
+        assert_eq!(changes[&main][0].range.start, lsp_types::Position::new(0, 25));
+
+        // Builtin elements cannot be renamed
+        let token = token_at(&mut dc, &lib, 2, 36);
+        assert!(!can_rename(&dc, &token));
+        assert!(spin_on::spin_on(rename(&mut dc, &token, "Foo")).is_err());
+        let token = token_at(&mut dc, &lib, 2, 20);
+        assert!(spin_on::spin_on(rename(&mut dc, &token, "not valid")).is_err());
+    }
+
+    #[test]
+    fn test_references_in_documents_not_loaded() {
+        let mut dc = empty_document_cache("fluent");
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dunce::canonicalize(dir.path()).unwrap();
+        // These files are not loaded: they are found on disk when looking for the references
+        std::fs::write(
+            dir.join("app.rs"),
+            r#"slint::slint! {
+import { Card } from "lib.slint";
+export component App { Card { } }
+}
+fn main() {}
+"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("unrelated.slint"), "export component Card { }").unwrap();
+        let lib = load_file_on_disk(
+            &mut dc,
+            &dir,
+            "lib.slint",
+            "export component Card inherits Rectangle { }\n",
+        );
+        let app = Url::from_file_path(dir.join("app.rs")).unwrap();
+
+        let token = token_at(&mut dc, &lib, 0, 18);
+        let mut references = spin_on::spin_on(find_references(&mut dc, &token, false)).unwrap();
+        references.sort_by_key(|l| l.range.start);
+        let positions = references
+            .iter()
+            .map(|l| (l.uri == app, l.range.start.line, l.range.start.character))
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![(true, 1, 9), (true, 2, 23)]);
+        assert!(dc.documents.get_document(&dir.join("unrelated.slint")).is_none());
+
+        let changes = spin_on::spin_on(rename(&mut dc, &token, "Panel")).unwrap().changes.unwrap();
+        assert_eq!(changes[&lib].len(), 1);
+        assert_eq!(changes[&app].len(), 2);
+        assert_eq!(changes.len(), 2);
+    }
+}
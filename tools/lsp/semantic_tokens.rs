@@ -55,6 +55,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::ConditionalElement => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackConnection => Some((self::FUNCTION, 0)),
+                SyntaxKind::PropertyChangedCallback => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::Function => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyAnimation => Some((self::KEYWORD, 0)),
@@ -80,6 +81,7 @@ pub fn get_semantic_tokens(
                         SyntaxKind::CallbackConnection => {
                             Some((self::PARAMETER, 1 << self::DEFINITION))
                         }
+                        SyntaxKind::PropertyChangedCallback => Some((self::PROPERTY, 0)),
                         SyntaxKind::PropertyDeclaration => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
@@ -96,6 +98,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::ReturnStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::AtImageUrl => Some((self::MACRO, 0)),
                 SyntaxKind::AtGradient => Some((self::MACRO, 0)),
+                SyntaxKind::AtTr => Some((self::MACRO, 0)),
                 SyntaxKind::ConditionalExpression => Some((self::KEYWORD, 0)),
                 SyntaxKind::ObjectMember => Some((self::PROPERTY, 1 << self::DECLARATION)),
                 SyntaxKind::States => Some((self::KEYWORD, 0)),
@@ -5,7 +5,10 @@
 
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_prelude::*;
-use crate::{completion, goto, semantic_tokens, util};
+use crate::{
+    completion, folding_range, formatting, goto, hover, inlay_hints, references, semantic_tokens,
+    signature_help, util,
+};
 use i_slint_compiler::diagnostics::{BuildDiagnostics, Spanned};
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
@@ -15,20 +18,22 @@
 use i_slint_compiler::CompilerConfiguration;
 use lsp_types::request::{
     CodeActionRequest, CodeLensRequest, ColorPresentationRequest, Completion, DocumentColor,
-    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, GotoDefinition, HoverRequest,
-    PrepareRenameRequest, Rename, SemanticTokensFullRequest,
+    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, PrepareRenameRequest,
+    RangeFormatting, References, Rename, SemanticTokensFullRequest, SignatureHelpRequest,
 };
 use lsp_types::{
     ClientCapabilities, CodeActionOrCommand, CodeActionProviderCapability, CodeLens,
     CodeLensOptions, Color, ColorInformation, ColorPresentation, Command, CompletionOptions,
-    DocumentSymbol, DocumentSymbolResponse, Hover, InitializeParams, InitializeResult, OneOf,
-    Position, PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions,
-    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities,
-    ServerInfo, TextDocumentSyncCapability, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    DocumentSymbol, DocumentSymbolResponse, InitializeParams, InitializeResult, OneOf, Position,
+    PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions, SemanticTokensFullOptions,
+    SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities, ServerInfo,
+    TextDocumentSyncCapability, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
 };
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::rc::Rc;
 
@@ -189,13 +194,205 @@ pub struct DocumentCache {
     pub(crate) documents: TypeLoader,
     newline_offsets: HashMap<Url, Rc<Vec<u32>>>,
     versions: HashMap<Url, i32>,
+    /// The text of the documents as sent by the client, to which the incremental changes apply
+    contents: HashMap<Url, String>,
+    /// For each loaded file (canonical path), the files that it imports
+    dependencies: HashMap<PathBuf, HashSet<PathBuf>>,
+    /// The folders of the workspace, in which the files that are not opened are looked up
+    pub(crate) workspace_roots: Vec<PathBuf>,
 }
 
 impl DocumentCache {
     pub fn new(config: CompilerConfiguration) -> Self {
         let documents =
             TypeLoader::new(TypeRegister::builtin(), config, &mut BuildDiagnostics::default());
-        Self { documents, newline_offsets: Default::default(), versions: Default::default() }
+        Self {
+            documents,
+            newline_offsets: Default::default(),
+            versions: Default::default(),
+            contents: Default::default(),
+            dependencies: Default::default(),
+            workspace_roots: Default::default(),
+        }
+    }
+
+    /// Apply the changes of a `textDocument/didChange` notification to the text of the document,
+    /// and return the new text.
+    pub fn apply_content_changes(
+        &mut self,
+        uri: &Url,
+        changes: Vec<lsp_types::TextDocumentContentChangeEvent>,
+    ) -> String {
+        let content = self.contents.entry(uri.clone()).or_default();
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let start = byte_offset_of_position(content, range.start);
+                    let end = byte_offset_of_position(content, range.end).max(start);
+                    content.replace_range(start..end, &change.text);
+                }
+                None => *content = change.text,
+            }
+        }
+        content.clone()
+    }
+
+    /// Recompute the imports of `path`, and of the files that were loaded for the first time.
+    fn update_dependencies(&mut self, path: &Path) {
+        let documents = &self.documents;
+        for file in documents.all_files() {
+            if file != path && self.dependencies.contains_key(file) {
+                continue;
+            }
+            let imports = documents
+                .get_document(file)
+                .and_then(|doc| doc.node.as_ref())
+                .map(|node| {
+                    node.ImportSpecifier()
+                        .filter_map(|import| import.child_token(SyntaxKind::StringLiteral))
+                        .chain(
+                            node.ExportsList()
+                                .flat_map(|exports| exports.ExportModule())
+                                .filter_map(|module| module.child_token(SyntaxKind::StringLiteral)),
+                        )
+                        .map(|token| {
+                            let file = token.text().trim_matches('\"').to_string();
+                            let (path, _) =
+                                documents.resolve_import_path(Some(&token.into()), &file);
+                            dunce::canonicalize(&path).unwrap_or(path)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            self.dependencies.insert(file.clone(), imports);
+        }
+    }
+
+    /// Load the files of the workspace that import `path`, directly or indirectly, and that were
+    /// not loaded yet. The `.rs` files are loaded if they contain a `slint!` macro.
+    ///
+    /// When the client didn't send any workspace folder, the directory of `path` is used.
+    pub async fn load_dependents(&mut self, path: &Path) {
+        let mut unloaded = self.unloaded_workspace_files(path);
+        loop {
+            // Only load the files that mention the name of one of the files they could import
+            let imported = std::iter::once(path.to_owned()).chain(self.dependents(path));
+            let names = imported
+                .filter_map(|p| Some(p.file_name()?.to_str()?.to_owned()))
+                .collect::<Vec<_>>();
+            let (to_load, rest): (Vec<_>, Vec<_>) = unloaded
+                .into_iter()
+                .partition(|(_, content)| names.iter().any(|name| content.contains(name)));
+            unloaded = rest;
+            if to_load.is_empty() {
+                break;
+            }
+            for (file, content) in to_load {
+                // The diagnostics are reported when the document is opened
+                let mut diag = BuildDiagnostics::default();
+                self.documents.load_file(&file, &file, content, false, &mut diag).await;
+                self.update_dependencies(&file);
+            }
+        }
+    }
+
+    /// The content of the `.slint` files, and of the `slint!` macros of the `.rs` files, of the
+    /// workspace that are not loaded yet.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn unloaded_workspace_files(&self, path: &Path) -> Vec<(PathBuf, String)> {
+        fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+            let entries = match std::fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(_) => return,
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                if name.starts_with('.') || name == "target" || name == "node_modules" {
+                    continue;
+                }
+                match entry.file_type() {
+                    Ok(t) if t.is_dir() => collect_files(&path, files),
+                    Ok(t) if t.is_file() => {
+                        if path.extension().map_or(false, |e| e == "slint" || e == "rs") {
+                            files.push(path);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        if self.workspace_roots.is_empty() {
+            if let Some(dir) = path.parent() {
+                collect_files(dir, &mut files);
+            }
+        }
+        for root in &self.workspace_roots {
+            collect_files(root, &mut files);
+        }
+
+        files
+            .into_iter()
+            .filter_map(|file| {
+                let file = dunce::canonicalize(&file).unwrap_or(file);
+                if self.documents.get_document(&file).is_some() {
+                    return None;
+                }
+                let content = std::fs::read_to_string(&file).ok()?;
+                let content = if file.extension().map_or(false, |e| e == "rs") {
+                    extract_rust_macro(content)?
+                } else {
+                    content
+                };
+                Some((file, content))
+            })
+            .collect()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn unloaded_workspace_files(&self, _path: &Path) -> Vec<(PathBuf, String)> {
+        Vec::new()
+    }
+
+    /// The loaded files that import `path`, directly or indirectly. They are sorted such that
+    /// each file comes after the files it imports.
+    pub(crate) fn dependents(&self, path: &Path) -> Vec<PathBuf> {
+        let mut dependents = HashSet::new();
+        let mut queue = vec![path.to_owned()];
+        while let Some(p) = queue.pop() {
+            for (file, imports) in &self.dependencies {
+                if imports.contains(&p) && dependents.insert(file.clone()) {
+                    queue.push(file.clone());
+                }
+            }
+        }
+        dependents.remove(path);
+
+        let mut sorted = Vec::with_capacity(dependents.len());
+        while !dependents.is_empty() {
+            let mut ready = dependents
+                .iter()
+                .filter(|file| {
+                    self.dependencies
+                        .get(*file)
+                        .map_or(true, |imports| imports.iter().all(|i| !dependents.contains(i)))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if ready.is_empty() {
+                // Recursive imports (which are an error): just take them all
+                ready = dependents.iter().cloned().collect();
+            }
+            ready.sort();
+            for file in ready {
+                dependents.remove(&file);
+                sorted.push(file);
+            }
+        }
+        sorted
     }
 
     fn newline_offsets_from_content(content: &str) -> Vec<u32> {
@@ -237,6 +434,22 @@ pub fn offset_to_position_mapper(
     }
 }
 
+/// Convert a position sent by the client, where the character is counted in UTF-16 code units,
+/// to a byte offset in the text.
+fn byte_offset_of_position(content: &str, position: Position) -> usize {
+    let line_start =
+        content.split_inclusive('\n').take(position.line as usize).map(str::len).sum::<usize>();
+    let line = content[line_start..].split('\n').next().unwrap_or_default();
+    let mut character = 0;
+    for (offset, c) in line.char_indices() {
+        if character >= position.character {
+            return line_start + offset;
+        }
+        character += c.len_utf16() as u32;
+    }
+    line_start + line.len()
+}
+
 pub struct OffsetToPositionMapper(Rc<Vec<u32>>);
 
 impl OffsetToPositionMapper {
@@ -282,10 +495,26 @@ pub struct Context {
     pub document_cache: RefCell<DocumentCache>,
     pub server_notifier: crate::ServerNotifier,
     pub init_param: InitializeParams,
+    /// The last version received for each document. It is kept outside of the `DocumentCache`
+    /// because that one stays borrowed while a document is compiled.
+    pub latest_versions: RefCell<HashMap<Url, i32>>,
     #[cfg(feature = "preview-api")]
     pub preview: PreviewApi,
 }
 
+impl Context {
+    /// Record that `version` is the last version of the document received from the client.
+    pub fn set_latest_version(&self, uri: &Url, version: i32) {
+        self.latest_versions.borrow_mut().insert(uri.clone(), version);
+    }
+
+    /// Returns true if a newer version of the document was received after `version`: there is
+    /// no point in compiling that one anymore.
+    pub fn is_outdated(&self, uri: &Url, version: i32) -> bool {
+        self.latest_versions.borrow().get(uri).map_or(false, |latest| *latest != version)
+    }
+}
+
 #[derive(Default)]
 pub struct RequestHandler(
     pub  HashMap<
@@ -333,8 +562,21 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 completion_item: None,
             }),
             definition_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(lsp_types::HoverProviderCapability::Simple(true)),
+            signature_help_provider: Some(lsp_types::SignatureHelpOptions {
+                trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+                retrigger_characters: None,
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(lsp_types::FoldingRangeProviderCapability::Simple(true)),
+            // The wasm build is driven by the editors' JavaScript code, which sends the full text
             text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                lsp_types::TextDocumentSyncKind::FULL,
+                if cfg!(target_arch = "wasm32") {
+                    lsp_types::TextDocumentSyncKind::FULL
+                } else {
+                    lsp_types::TextDocumentSyncKind::INCREMENTAL
+                },
             )),
             code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
             execute_command_provider: Some(lsp_types::ExecuteCommandOptions {
@@ -356,6 +598,9 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 .into(),
             ),
             document_highlight_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            document_formatting_provider: Some(OneOf::Left(true)),
+            document_range_formatting_provider: Some(OneOf::Left(true)),
             rename_provider: Some(
                 if client_cap
                     .text_document
@@ -427,18 +672,33 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         });
         Ok(result)
     });
-    rh.register::<HoverRequest, _>(|_params, _ctx| async move {
-        /*let result =
-            token_descr(document_cache, params.text_document_position_params).map(|x| Hover {
-                contents: lsp_types::HoverContents::Scalar(MarkedString::from_language_code(
-                    "text".into(),
-                    format!("{:?}", x.token),
-                )),
-                range: None,
-            });
-        let resp = Response::new_ok(id, result);
-        connection.sender.send(Message::Response(resp))?;*/
-        Ok(None::<Hover>)
+    rh.register::<HoverRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|token| hover::get_tooltip(document_cache, token.0));
+        Ok(result)
+    });
+    rh.register::<SignatureHelpRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|(token, offset)| signature_help::signature_help(document_cache, token, offset));
+        Ok(result)
+    });
+    rh.register::<InlayHintRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(inlay_hints::inlay_hints(document_cache, &params.text_document.uri, &params.range))
+    });
+    rh.register::<FoldingRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(folding_range::folding_ranges(document_cache, &params.text_document.uri))
     });
     rh.register::<CodeActionRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
@@ -539,6 +799,45 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         (ctx.preview.highlight)(&ctx, None, 0)?;
         Ok(None)
     });
+    rh.register::<Formatting, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(formatting::format_document(document_cache, &params.text_document.uri, None))
+    });
+    rh.register::<RangeFormatting, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(formatting::format_document(
+            document_cache,
+            &params.text_document.uri,
+            Some(params.range),
+        ))
+    });
+    rh.register::<References, _>(|params, ctx| async move {
+        let mut document_cache = ctx.document_cache.borrow_mut();
+        let uri = params.text_document_position.text_document.uri;
+        if let Some((tk, _off)) =
+            token_descr(&mut document_cache, &uri, &params.text_document_position.position)
+        {
+            if let Some(value) = find_element_id_for_highlight(&tk, &tk.parent()) {
+                let mapper = document_cache.offset_to_position_mapper(&uri)?;
+                return Ok(Some(
+                    value
+                        .into_iter()
+                        .map(|r| lsp_types::Location {
+                            uri: uri.clone(),
+                            range: mapper.map_range(r),
+                        })
+                        .collect(),
+                ));
+            }
+            return Ok(references::find_references(
+                &mut document_cache,
+                &tk,
+                params.context.include_declaration,
+            )
+            .await);
+        }
+        Ok(None)
+    });
     rh.register::<Rename, _>(|params, ctx| async move {
         let mut document_cache = ctx.document_cache.borrow_mut();
         let uri = params.text_document_position.text_document.uri;
@@ -559,20 +858,27 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
                     ..Default::default()
                 }));
             }
+            if references::can_rename(&document_cache, &tk) {
+                return references::rename(&mut document_cache, &tk, &params.new_name)
+                    .await
+                    .map(Some);
+            }
         };
-        Err("This symbol cannot be renamed. (Only element id can be renamed at the moment)".into())
+        Err("This symbol cannot be renamed. (Only element ids, components, globals and structs can be renamed)".into())
     });
     rh.register::<PrepareRenameRequest, _>(|params, ctx| async move {
         let mut document_cache = ctx.document_cache.borrow_mut();
         let uri = params.text_document.uri;
         if let Some((tk, _off)) = token_descr(&mut document_cache, &uri, &params.position) {
-            if let Some(_) = find_element_id_for_highlight(&tk, &tk.parent()) {
+            if find_element_id_for_highlight(&tk, &tk.parent()).is_some()
+                || references::can_rename(&document_cache, &tk)
+            {
                 return Ok(Some(PrepareRenameResponse::Range(
                     document_cache.offset_to_position_mapper(&uri)?.map_range(tk.text_range()),
                 )));
             }
         };
-        Err("This symbol cannot be renamed. (Only element id can be renamed at the moment)".into())
+        Err("This symbol cannot be renamed. (Only element ids, components, globals and structs can be renamed)".into())
     });
 }
 
@@ -874,6 +1180,7 @@ pub async fn reload_document_impl(
     document_cache: &mut DocumentCache,
 ) -> Result<HashMap<Url, Vec<lsp_types::Diagnostic>>, Error> {
     let path = uri.to_file_path().unwrap();
+    document_cache.contents.insert(uri.clone(), content.clone());
     if path.extension().map_or(false, |e| e == "rs") {
         content = match extract_rust_macro(content) {
             Some(content) => content,
@@ -891,17 +1198,40 @@ pub async fn reload_document_impl(
     crate::preview::set_contents(&path_canon, content.clone());
     let mut diag = BuildDiagnostics::default();
     document_cache.documents.load_file(&path_canon, &path, content, false, &mut diag).await;
+    let mut checked_files = vec![path];
+    let mut all_diags = vec![diag];
+
+    // The files that import this one, directly or not, need to be checked again. The other
+    // files are not affected by the change.
+    document_cache.update_dependencies(&path_canon);
+    for dependent in document_cache.dependents(&path_canon) {
+        let (source_path, source) = match document_cache
+            .documents
+            .get_document(&dependent)
+            .and_then(|doc| doc.node.as_ref())
+            .and_then(|node| {
+                Some((node.source_file.path().to_owned(), node.source_file.source()?.to_owned()))
+            }) {
+            Some(x) => x,
+            None => continue,
+        };
+        let mut diag = BuildDiagnostics::default();
+        document_cache
+            .documents
+            .load_file(&dependent, &source_path, source, false, &mut diag)
+            .await;
+        checked_files.push(source_path);
+        all_diags.push(diag);
+    }
 
     // Always provide diagnostics for all files. Empty diagnostics clear any previous ones.
-    let mut lsp_diags: HashMap<Url, Vec<lsp_types::Diagnostic>> = core::iter::once(&path)
-        .chain(diag.all_loaded_files.iter())
-        .map(|path| {
-            let uri = Url::from_file_path(path).unwrap();
-            (uri, Default::default())
-        })
+    let mut lsp_diags: HashMap<Url, Vec<lsp_types::Diagnostic>> = checked_files
+        .iter()
+        .chain(all_diags.iter().flat_map(|diag| diag.all_loaded_files.iter()))
+        .filter_map(|path| Some((Url::from_file_path(path).ok()?, Default::default())))
         .collect();
 
-    for d in diag.into_iter() {
+    for d in all_diags.into_iter().flatten() {
         #[cfg(not(target_arch = "wasm32"))]
         if d.source_file().unwrap().is_relative() {
             continue;
@@ -1074,7 +1404,7 @@ pub fn element_at_position(
 }
 
 /// return the token, and the offset within the file
-fn token_descr(
+pub fn token_descr(
     document_cache: &mut DocumentCache,
     text_document_uri: &Url,
     pos: &Position,
@@ -1416,7 +1746,7 @@ pub async fn load_configuration(ctx: &Context) -> Result<(), Error> {
 mod tests {
     use super::*;
 
-    use crate::test::{complex_document_cache, loaded_document_cache};
+    use crate::test::{complex_document_cache, load_file_on_disk, loaded_document_cache};
 
     #[test]
     fn test_reload_document_invalid_contents() {
@@ -1458,6 +1788,85 @@ fn test_reload_document_valid_contents() {
         assert!(diagnostics.is_empty());
     }
 
+    #[test]
+    fn test_apply_content_changes() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            "component Main {\n    // Größe\n    width: 10px;\n}\n".into(),
+        );
+        let change = |start: (u32, u32), end: (u32, u32), text: &str| {
+            lsp_types::TextDocumentContentChangeEvent {
+                range: Some(lsp_types::Range::new(
+                    Position::new(start.0, start.1),
+                    Position::new(end.0, end.1),
+                )),
+                range_length: None,
+                text: text.into(),
+            }
+        };
+        let content = dc.apply_content_changes(
+            &url,
+            vec![
+                change((2, 11), (2, 13), "42"),
+                change((1, 10), (1, 12), "ss"),
+                change((3, 1), (3, 1), "\n"),
+            ],
+        );
+        assert_eq!(content, "component Main {\n    // Gröss\n    width: 42px;\n}\n\n");
+
+        let content = dc.apply_content_changes(
+            &url,
+            vec![lsp_types::TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "component Foo {}".into(),
+            }],
+        );
+        assert_eq!(content, "component Foo {}");
+    }
+
+    #[test]
+    fn test_dependents_are_checked_again() {
+        let mut dc = crate::test::empty_document_cache("fluent");
+        let dir = tempfile::tempdir().unwrap();
+        let lib = load_file_on_disk(
+            &mut dc,
+            dir.path(),
+            "lib.slint",
+            "export component Foo { }\nexport component Bar { }",
+        );
+        let middle = load_file_on_disk(
+            &mut dc,
+            dir.path(),
+            "middle.slint",
+            "import { Foo } from \"lib.slint\";\nexport component Middle inherits Foo { }",
+        );
+        let main = load_file_on_disk(
+            &mut dc,
+            dir.path(),
+            "main.slint",
+            "import { Middle } from \"middle.slint\";\nimport { Bar } from \"lib.slint\";\nexport component Main { Middle { } Bar { } }",
+        );
+        let unrelated =
+            load_file_on_disk(&mut dc, dir.path(), "unrelated.slint", "export component X { }");
+        let path = |url: &Url| url.to_file_path().unwrap();
+        assert_eq!(dc.dependents(&path(&lib)), vec![path(&middle), path(&main)]);
+        assert!(dc.dependents(&path(&main)).is_empty());
+
+        // Removing `Foo` from lib.slint is an error in middle.slint
+        let diag = spin_on::spin_on(reload_document_impl(
+            "export component Bar { }".into(),
+            lib.clone(),
+            43,
+            &mut dc,
+        ))
+        .unwrap();
+        assert!(diag[&lib].is_empty());
+        assert_eq!(diag[&middle].len(), 1);
+        assert!(diag[&main].is_empty());
+        assert!(!diag.contains_key(&unrelated));
+    }
+
     #[test]
     fn test_text_document_color_no_color_set() {
         let (mut dc, url, _) = loaded_document_cache(
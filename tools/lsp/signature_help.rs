@@ -0,0 +1,232 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+//! The parameter hints shown while typing the arguments of a call to a callback or a function.
+
+use super::DocumentCache;
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+use i_slint_compiler::expression_tree::{BuiltinMacroFunction, Expression};
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::parser::{
+    doc_comment, identifier_text, syntax_nodes, SyntaxKind, SyntaxToken,
+};
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureInformation,
+};
+
+/// Return the signature of the function called around `offset`, with the parameter that is
+/// being typed as the active one.
+pub fn signature_help(
+    document_cache: &DocumentCache,
+    token: SyntaxToken,
+    offset: u32,
+) -> Option<SignatureHelp> {
+    // Find the innermost call whose parentheses contain the offset
+    let mut node = token.parent();
+    let call = loop {
+        if node.kind() == SyntaxKind::FunctionCallExpression {
+            let after_open = node
+                .child_token(SyntaxKind::LParent)
+                .map_or(false, |t| u32::from(t.text_range().end()) <= offset);
+            let before_close = node
+                .child_token(SyntaxKind::RParent)
+                .map_or(true, |t| u32::from(t.text_range().start()) >= offset);
+            if after_open && before_close {
+                break node;
+            }
+        }
+        node = node.parent()?;
+    };
+    let active_parameter = call
+        .node
+        .children_with_tokens()
+        .filter(|t| t.kind() == SyntaxKind::Comma && u32::from(t.text_range().start()) < offset)
+        .count() as u32;
+
+    let callee = syntax_nodes::FunctionCallExpression::new(call)?.Expression().next()?;
+    let qualified_name = callee.QualifiedName()?;
+    let name_token = qualified_name
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .last()?;
+    let name = name_token.text().to_string();
+
+    let expression =
+        match crate::goto::lookup_expression_at(document_cache, &qualified_name, &name_token)? {
+            LookupResult::Expression { expression, .. } => expression,
+            _ => return None,
+        };
+
+    let mut documentation = None;
+    let (parameters, return_type) = match &expression {
+        Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _) => {
+            let (declaration, builtin) =
+                crate::hover::property_declaration(&nr.element(), nr.name());
+            let arg_names = declaration
+                .clone()
+                .and_then(syntax_nodes::Function::new)
+                .map(|f| {
+                    f.ArgumentDeclaration()
+                        .map(|a| identifier_text(&a.DeclaredIdentifier()).unwrap_or_default())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            documentation = declaration
+                .as_ref()
+                .and_then(doc_comment)
+                .or_else(|| builtin.and_then(|b| b.documentation));
+            let (args, return_type) = function_type(nr.ty())?;
+            let parameters = args
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| match arg_names.get(i) {
+                    Some(arg_name) if !arg_name.is_empty() => format!("{arg_name}: {ty}"),
+                    _ => ty.to_string(),
+                })
+                .collect();
+            (parameters, return_type)
+        }
+        Expression::BuiltinFunctionReference(function, _) => {
+            let (args, return_type) = function_type(function.ty())?;
+            (args.iter().map(ToString::to_string).collect(), return_type)
+        }
+        Expression::MemberFunction { member, .. } => {
+            // The base of the member access is passed as the first argument
+            let (args, return_type) = function_type(member.ty())?;
+            (args.iter().skip(1).map(ToString::to_string).collect(), return_type)
+        }
+        Expression::BuiltinMacroReference(m, _) => {
+            let (args, return_type): (&[&str], _) = match m {
+                BuiltinMacroFunction::Min | BuiltinMacroFunction::Max => (&["T", "T", "..."], "T"),
+                BuiltinMacroFunction::Mod => (&["T", "T"], "T"),
+                BuiltinMacroFunction::CubicBezier => {
+                    (&["float", "float", "float", "float"], "easing")
+                }
+                BuiltinMacroFunction::Rgb => (&["int", "int", "int", "float"], "color"),
+                BuiltinMacroFunction::Debug => (&["..."], ""),
+            };
+            let return_type = (!return_type.is_empty()).then(|| return_type.to_string());
+            (args.iter().map(ToString::to_string).collect(), return_type)
+        }
+        _ => return None,
+    };
+
+    // The label of the parameters are their offsets (in UTF-16 code units) within the signature
+    let utf16_len = |s: &str| s.encode_utf16().count() as u32;
+    let mut label = format!("{name}(");
+    let mut parameter_information = Vec::with_capacity(parameters.len());
+    for (i, p) in parameters.iter().enumerate() {
+        if i > 0 {
+            label += ", ";
+        }
+        let start = utf16_len(&label);
+        label += p;
+        parameter_information.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, utf16_len(&label)]),
+            documentation: None,
+        });
+    }
+    label += ")";
+    if let Some(return_type) = return_type {
+        label += " -> ";
+        label += &return_type;
+    }
+
+    // Variadic macros: the last parameter stays active
+    let active_parameter = if parameters.last().map_or(false, |p| p == "...") {
+        active_parameter.min(parameters.len() as u32 - 1)
+    } else {
+        active_parameter
+    };
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: documentation.map(|value| {
+                Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value })
+            }),
+            parameters: Some(parameter_information),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// The arguments and the return type (None if void) of a callback or function type
+fn function_type(ty: Type) -> Option<(Vec<Type>, Option<String>)> {
+    match ty {
+        Type::Callback { args, return_type } => Some((args, return_type.map(|t| t.to_string()))),
+        Type::Function { args, return_type } => {
+            Some((args, (*return_type != Type::Void).then(|| return_type.to_string())))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::loaded_document_cache;
+
+    fn signature_at(
+        dc: &mut DocumentCache,
+        url: &lsp_types::Url,
+        line: u32,
+        character: u32,
+    ) -> Option<(String, Vec<[u32; 2]>, u32)> {
+        let (token, offset) =
+            crate::server_loop::token_descr(dc, url, &lsp_types::Position { line, character })?;
+        let help = signature_help(dc, token, offset)?;
+        let signature = help.signatures.into_iter().next()?;
+        let parameters = signature
+            .parameters?
+            .into_iter()
+            .map(|p| match p.label {
+                ParameterLabel::LabelOffsets(o) => o,
+                ParameterLabel::Simple(_) => panic!("expected offsets"),
+            })
+            .collect();
+        Some((signature.label, parameters, help.active_parameter?))
+    }
+
+    #[test]
+    fn test_signature_help() {
+        let (mut dc, url, _) = loaded_document_cache(
+            "fluent",
+            r#"export component Demo {
+    callback clicked(int, string) -> bool;
+    function compute(value: int, factor: float) -> int { return value; }
+    property <int> a: compute(1, 2.5);
+    property <float> b: max(1, 2, 3) + abs(-3);
+    init => { clicked(compute(1, 2), "foo"); }
+}
+"#
+            .into(),
+        );
+        assert_eq!(
+            signature_at(&mut dc, &url, 3, 33),
+            Some(("compute(value: int, factor: float) -> int".into(), vec![[8, 18], [20, 33]], 1))
+        );
+        assert_eq!(
+            signature_at(&mut dc, &url, 4, 43),
+            Some(("abs(float) -> float".into(), vec![[4, 9]], 0))
+        );
+        assert_eq!(signature_at(&mut dc, &url, 4, 33).unwrap().2, 2);
+        assert_eq!(
+            signature_at(&mut dc, &url, 5, 38),
+            Some(("clicked(int, string) -> bool".into(), vec![[8, 11], [13, 19]], 1))
+        );
+        assert_eq!(
+            signature_at(&mut dc, &url, 5, 30).unwrap().0,
+            "compute(value: int, factor: float) -> int"
+        );
+        // Outside of the parentheses
+        assert_eq!(signature_at(&mut dc, &url, 3, 22), None);
+    }
+}
@@ -6,6 +6,7 @@
 use lsp_types::{Diagnostic, Url};
 
 use std::collections::HashMap;
+use std::path::Path;
 
 use crate::server_loop::{reload_document_impl, DocumentCache};
 
@@ -35,6 +36,19 @@ pub fn loaded_document_cache(
     (dc, url, diag)
 }
 
+/// Write `content` into the file `name` of the directory `dir`, and load it into the `DocumentCache`.
+///
+/// Use this for documents that import each other, in a `tempfile::TempDir`: the imports are
+/// resolved on the file system.
+pub fn load_file_on_disk(dc: &mut DocumentCache, dir: &Path, name: &str, content: &str) -> Url {
+    let path = dunce::canonicalize(dir).unwrap().join(name);
+    std::fs::write(&path, content).unwrap();
+    let url = Url::from_file_path(path).unwrap();
+    spin_on::spin_on(reload_document_impl(content.into(), url.clone(), 42, dc))
+        .expect("reload_document_impl failed.");
+    url
+}
+
 /// Create a `DocumentCache` with one comparatively complex test document loaded into it.
 pub fn complex_document_cache(style: &str) -> (DocumentCache, Url, HashMap<Url, Vec<Diagnostic>>) {
     loaded_document_cache(style,
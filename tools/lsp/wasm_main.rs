@@ -4,11 +4,17 @@
 #![cfg(target_arch = "wasm32")]
 
 mod completion;
+mod folding_range;
+mod formatting;
 mod goto;
+mod hover;
+mod inlay_hints;
 mod lsp_ext;
 mod properties;
+mod references;
 mod semantic_tokens;
 mod server_loop;
+mod signature_help;
 mod util;
 
 use i_slint_compiler::CompilerConfiguration;
@@ -207,6 +213,7 @@ pub fn create(
             document_cache,
             init_param,
             server_notifier: ServerNotifier { send_notification, send_request },
+            latest_versions: Default::default(),
             #[cfg(feature = "preview-api")]
             preview: server_loop::PreviewApi {
                 highlight: Box::new(
@@ -241,8 +248,13 @@ pub fn reload_document(&self, content: String, uri: JsValue, version: i32) -> js
         let ctx = self.ctx.clone();
         let guard = self.reentry_guard.clone();
         wasm_bindgen_futures::future_to_promise(async move {
-            let _lock = ReentryGuard::lock(guard).await;
             let uri: lsp_types::Url = serde_wasm_bindgen::from_value(uri)?;
+            ctx.set_latest_version(&uri, version);
+            let _lock = ReentryGuard::lock(guard).await;
+            // A newer version arrived while waiting for the previous compilation to finish
+            if ctx.is_outdated(&uri, version) {
+                return Ok(JsValue::UNDEFINED);
+            }
             server_loop::reload_document(
                 &ctx.server_notifier,
                 content,
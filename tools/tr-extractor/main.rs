@@ -0,0 +1,248 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+    Extract the strings marked with `@tr(...)` in .slint files into a gettext `.pot` file.
+    ```sh
+        cargo run --bin slint-tr-extractor -- -o messages.pot some_file.slint other_file.slint
+    ```
+*/
+
+use clap::Parser;
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+use std::collections::HashMap;
+use std::io::Write;
+
+#[derive(clap::Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[arg(name = "path to .slint file(s)", action)]
+    paths: Vec<std::path::PathBuf>,
+
+    /// Write output to specified file (instead of messages.pot)
+    #[arg(short = 'o', long = "output", default_value = "messages.pot", action)]
+    output: std::path::PathBuf,
+
+    /// Set the package name in the header of the output
+    #[arg(long = "package-name", action)]
+    package_name: Option<String>,
+
+    /// Set the package version in the header of the output
+    #[arg(long = "package-version", action)]
+    package_version: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Message {
+    context: Option<String>,
+    msgid: String,
+    plural: Option<String>,
+    /// The locations where the message is used, as `file:line`
+    locations: Vec<String>,
+}
+
+#[derive(Default)]
+struct Messages {
+    messages: Vec<Message>,
+    /// The index in `messages` of the message with that context and msgid
+    index: HashMap<(Option<String>, String), usize>,
+}
+
+impl Messages {
+    fn add(
+        &mut self,
+        context: Option<String>,
+        msgid: String,
+        plural: Option<String>,
+        location: String,
+    ) {
+        match self.index.get(&(context.clone(), msgid.clone())) {
+            Some(idx) => {
+                let message = &mut self.messages[*idx];
+                if message.plural.is_none() {
+                    message.plural = plural;
+                }
+                message.locations.push(location);
+            }
+            None => {
+                self.index.insert((context.clone(), msgid.clone()), self.messages.len());
+                self.messages.push(Message { context, msgid, plural, locations: vec![location] });
+            }
+        }
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let args = Cli::parse();
+
+    let mut messages = Messages::default();
+    for path in &args.paths {
+        let source = std::fs::read_to_string(path)?;
+        let mut diag = BuildDiagnostics::default();
+        let syntax_node = i_slint_compiler::parser::parse(source.clone(), Some(path), &mut diag);
+        if diag.has_error() {
+            diag.print();
+        }
+        visit_node(&syntax_node, &source, &path.display().to_string(), &mut messages);
+    }
+
+    let output = std::fs::File::create(&args.output)?;
+    write_pot(
+        std::io::BufWriter::new(output),
+        &messages.messages,
+        args.package_name.as_deref(),
+        args.package_version.as_deref(),
+    )
+}
+
+fn visit_node(node: &SyntaxNode, source: &str, file_name: &str, messages: &mut Messages) {
+    for n in node.node.descendants().filter(|n| n.kind() == SyntaxKind::AtTr) {
+        let tr: syntax_nodes::AtTr =
+            SyntaxNode { node: n, source_file: node.source_file.clone() }.into();
+        let string = |n: &SyntaxNode| {
+            n.child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| i_slint_compiler::literals::unescape_string(&s))
+        };
+        let msgid = match string(&tr) {
+            Some(msgid) => msgid,
+            None => continue,
+        };
+        let context = tr.TrContext().and_then(|n| string(&n));
+        let plural = tr.TrPlural().and_then(|n| string(&n));
+        let offset = usize::from(tr.text_range().start()).min(source.len());
+        let line = source[..offset].matches('\n').count() + 1;
+        messages.add(context, msgid, plural, format!("{file_name}:{line}"));
+    }
+}
+
+/// Escape the string so it can be put between quotes in a .po file
+fn escape(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn write_pot(
+    mut output: impl Write,
+    messages: &[Message],
+    package_name: Option<&str>,
+    package_version: Option<&str>,
+) -> std::io::Result<()> {
+    let package = match (package_name, package_version) {
+        (Some(name), Some(version)) => format!("{name} {version}"),
+        (Some(name), None) => name.into(),
+        _ => "PACKAGE VERSION".into(),
+    };
+    write!(
+        output,
+        r#"# SOME DESCRIPTIVE TITLE.
+# Copyright (C) YEAR THE PACKAGE'S COPYRIGHT HOLDER
+# This file is distributed under the same license as the PACKAGE package.
+# FIRST AUTHOR <EMAIL@ADDRESS>, YEAR.
+#
+#, fuzzy
+msgid ""
+msgstr ""
+"Project-Id-Version: {package}\n"
+"Report-Msgid-Bugs-To: \n"
+"PO-Revision-Date: YEAR-MO-DA HO:MI+ZONE\n"
+"Last-Translator: FULL NAME <EMAIL@ADDRESS>\n"
+"Language-Team: LANGUAGE <LL@li.org>\n"
+"Language: \n"
+"MIME-Version: 1.0\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8bit\n"
+"Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\n"
+"#,
+        package = escape(&package)
+    )?;
+
+    for message in messages {
+        writeln!(output)?;
+        writeln!(output, "#: {}", message.locations.join(" "))?;
+        if let Some(context) = &message.context {
+            writeln!(output, "msgctxt \"{}\"", escape(context))?;
+        }
+        writeln!(output, "msgid \"{}\"", escape(&message.msgid))?;
+        match &message.plural {
+            Some(plural) => {
+                writeln!(output, "msgid_plural \"{}\"", escape(plural))?;
+                writeln!(output, "msgstr[0] \"\"")?;
+                writeln!(output, "msgstr[1] \"\"")?;
+            }
+            None => writeln!(output, "msgstr \"\"")?,
+        }
+    }
+    output.flush()
+}
+
+#[test]
+fn extract_messages() {
+    let source = r#"export Foo := Rectangle {
+    property <string> a: @tr("Hello");
+    property <string> b: @tr("ctx" => "Hello");
+    property <string> c: @tr("Hello");
+    property <string> d: @tr("{n} \"file\"" | "{n} files" % 42);
+}
+"#;
+    let mut diag = BuildDiagnostics::default();
+    let syntax_node = i_slint_compiler::parser::parse(source.into(), None, &mut diag);
+    assert!(!diag.has_error());
+    let mut messages = Messages::default();
+    visit_node(&syntax_node, source, "foo.slint", &mut messages);
+    assert_eq!(
+        messages.messages,
+        vec![
+            Message {
+                context: None,
+                msgid: "Hello".into(),
+                plural: None,
+                locations: vec!["foo.slint:2".into(), "foo.slint:4".into()]
+            },
+            Message {
+                context: Some("ctx".into()),
+                msgid: "Hello".into(),
+                plural: None,
+                locations: vec!["foo.slint:3".into()]
+            },
+            Message {
+                context: None,
+                msgid: "{n} \"file\"".into(),
+                plural: Some("{n} files".into()),
+                locations: vec!["foo.slint:5".into()]
+            },
+        ]
+    );
+
+    let mut output = Vec::new();
+    write_pot(&mut output, &messages.messages, Some("foo"), None).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("\"Project-Id-Version: foo\\n\"\n"));
+    assert!(output.ends_with(
+        r#"
+#: foo.slint:2 foo.slint:4
+msgid "Hello"
+msgstr ""
+
+#: foo.slint:3
+msgctxt "ctx"
+msgid "Hello"
+msgstr ""
+
+#: foo.slint:5
+msgid "{n} \"file\""
+msgid_plural "{n} files"
+msgstr[0] ""
+msgstr[1] ""
+"#
+    ));
+}